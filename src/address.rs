@@ -249,6 +249,62 @@ impl SBAddress {
     pub fn get_section(&self) -> Option<SBSection> {
         SBSection::maybe_wrap(unsafe { sys::SBAddressGetSection(self.raw) })
     }
+
+    /// The offset of this address relative to the start of its section.
+    ///
+    /// This is the same as [`SBAddress::get_offset()`].
+    ///
+    /// See also:
+    /// - [`SBAddress::section()`]
+    pub fn offset(&self) -> lldb_addr_t {
+        self.get_offset()
+    }
+
+    /// The section containing this address.
+    ///
+    /// This is the same as [`SBAddress::get_section()`].
+    ///
+    /// See also:
+    /// - [`SBAddress::offset()`]
+    pub fn section(&self) -> Option<SBSection> {
+        self.get_section()
+    }
+
+    /// Move this address forward by `offset` bytes within its section.
+    ///
+    /// Returns `false` and leaves the address unchanged if this address
+    /// is invalid or if the result would not be within the section.
+    /// Addresses which aren't in a section aren't bounds-checked.
+    ///
+    /// See also:
+    /// - [`SBAddress::offset_address()`]
+    pub fn add_offset(&mut self, offset: u64) -> bool {
+        // LLDB doesn't check that the result is still in the section.
+        if let Some(section) = self.get_section() {
+            match self.offset().checked_add(offset) {
+                Some(new_offset) if new_offset < section.byte_size() => {}
+                _ => return false,
+            }
+        }
+        unsafe { sys::SBAddressOffsetAddress(self.raw, offset) }
+    }
+
+    /// Get a new address `offset` bytes after this one, within the
+    /// same section.
+    ///
+    /// If this address is invalid or the result would not be within
+    /// the section, the returned address will be invalid. Addresses
+    /// which aren't in a section aren't bounds-checked.
+    ///
+    /// See also:
+    /// - [`SBAddress::add_offset()`]
+    pub fn offset_address(&self, offset: u64) -> SBAddress {
+        let mut address = self.clone();
+        if !address.add_offset(offset) {
+            unsafe { sys::SBAddressClear(address.raw) };
+        }
+        address
+    }
 }
 
 impl Clone for SBAddress {
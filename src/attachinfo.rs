@@ -11,7 +11,25 @@ use std::ffi::{CStr, CString};
 ///
 /// See [`SBTarget::attach()`].
 ///
+/// The options of the `process attach` command map onto this API as
+/// follows:
+///
+/// * `--pid`: [`SBAttachInfo::new_with_pid()`]
+/// * `--name` and `--waitfor`: [`SBAttachInfo::new_with_path()`]
+/// * `--include-existing`: [`SBAttachInfo::set_ignore_existing()`]
+/// * `--plugin`: [`SBAttachInfo::set_process_plugin_name()`]
+/// * `--continue`: [`SBTarget::attach_and_continue()`]
+///
+/// Whether forked children are followed and whether the debugger
+/// detaches from or kills a process when attaching fails are not part
+/// of the attach configuration in LLDB. They are controlled by the
+/// `target.process.follow-fork-mode` and `target.detach-on-error`
+/// settings, which can be changed with
+/// [`SBDebugger::execute_command()`].
+///
 /// [`SBTarget::attach()`]: crate::SBTarget::attach()
+/// [`SBTarget::attach_and_continue()`]: crate::SBTarget::attach_and_continue()
+/// [`SBDebugger::execute_command()`]: crate::SBDebugger::execute_command()
 #[derive(Debug)]
 pub struct SBAttachInfo {
     /// The underlying raw `SBAttachInfoRef`.
@@ -55,74 +73,97 @@ impl SBAttachInfo {
         SBAttachInfo { raw }
     }
 
-    #[allow(missing_docs)]
+    /// The ID of the process to attach to, as for `process attach --pid`.
     pub fn process_id(&self) -> lldb_pid_t {
         unsafe { sys::SBAttachInfoGetProcessID(self.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// Set the ID of the process to attach to, as for
+    /// `process attach --pid`.
     pub fn set_process_id(&self, pid: lldb_pid_t) {
         unsafe { sys::SBAttachInfoSetProcessID(self.raw, pid) };
     }
 
-    #[allow(missing_docs)]
+    /// Set the name or path of the executable of the process to attach
+    /// to, as for `process attach --name`.
     pub fn set_executable_path(&self, path: &str) {
         let p = CString::new(path).unwrap();
         unsafe { sys::SBAttachInfoSetExecutable(self.raw, p.as_ptr()) }
     }
 
-    #[allow(missing_docs)]
+    /// Set the executable of the process to attach to, as for
+    /// `process attach --name`.
     pub fn set_executable_filespec(&self, exe_file: SBFileSpec) {
         unsafe { sys::SBAttachInfoSetExecutable2(self.raw, exe_file.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// Will attaching wait for the next process with the
+    /// [executable name] to launch, as for `process attach --waitfor`?
+    ///
+    /// [executable name]: SBAttachInfo::set_executable_path()
     pub fn wait_for_launch(&self) -> bool {
         unsafe { sys::SBAttachInfoGetWaitForLaunch(self.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// Set whether attaching waits for the next process with the
+    /// [executable name] to launch, as for `process attach --waitfor`.
+    ///
+    /// See [`SBAttachInfo::new_with_path()`] for the meaning of
+    /// `asynchronous`.
+    ///
+    /// [executable name]: SBAttachInfo::set_executable_path()
     pub fn set_wait_for_launch(&self, wait: bool, asynchronous: bool) {
         unsafe { sys::SBAttachInfoSetWaitForLaunch2(self.raw, wait, asynchronous) };
     }
 
-    #[allow(missing_docs)]
+    /// When [waiting for launch], are processes that already exist
+    /// ignored?
+    ///
+    /// This is `true` by default and is the opposite of
+    /// `process attach --include-existing`.
+    ///
+    /// [waiting for launch]: SBAttachInfo::set_wait_for_launch()
     pub fn ignore_existing(&self) -> bool {
         unsafe { sys::SBAttachInfoGetIgnoreExisting(self.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// When [waiting for launch], set whether processes that already
+    /// exist are ignored.
+    ///
+    /// [waiting for launch]: SBAttachInfo::set_wait_for_launch()
     pub fn set_ignore_existing(&self, b: bool) {
         unsafe { sys::SBAttachInfoSetIgnoreExisting(self.raw, b) }
     }
 
-    #[allow(missing_docs)]
+    /// The number of times that the process is resumed while attaching,
+    /// such as to get past the stops of a shell or other launcher.
     pub fn resume_count(&self) -> u32 {
         unsafe { sys::SBAttachInfoGetResumeCount(self.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// Set the number of times that the process is resumed while
+    /// attaching.
     pub fn set_resume_count(&self, c: u32) {
         unsafe { sys::SBAttachInfoSetResumeCount(self.raw, c) }
     }
 
-    #[allow(missing_docs)]
+    /// The name of the process plugin used to attach, if one was set.
     pub fn process_plugin_name(&self) -> Option<&str> {
         unsafe {
-            match CStr::from_ptr(sys::SBAttachInfoGetProcessPluginName(self.raw)).to_str() {
-                Ok(s) => Some(s),
-                _ => None,
-            }
+            CStr::from_ptr(sys::SBAttachInfoGetProcessPluginName(self.raw).as_ref()?)
+                .to_str()
+                .ok()
         }
     }
 
-    #[allow(missing_docs)]
+    /// Set the name of the process plugin used to attach, such as
+    /// `"gdb-remote"`, as for `process attach --plugin`.
     pub fn set_process_plugin_name(&self, plugin: &str) {
         let plugin = CString::new(plugin).unwrap();
         unsafe { sys::SBAttachInfoSetProcessPluginName(self.raw, plugin.as_ptr()) };
     }
 
-    #[allow(missing_docs)]
+    /// Only attach to a process run by this user ID, if set.
     pub fn user_id(&self) -> Option<u32> {
         if unsafe { sys::SBAttachInfoUserIDIsValid(self.raw) } {
             Some(unsafe { sys::SBAttachInfoGetUserID(self.raw) })
@@ -131,12 +172,12 @@ impl SBAttachInfo {
         }
     }
 
-    #[allow(missing_docs)]
+    /// Only attach to a process run by this user ID.
     pub fn set_user_id(&self, uid: u32) {
         unsafe { sys::SBAttachInfoSetUserID(self.raw, uid) };
     }
 
-    #[allow(missing_docs)]
+    /// Only attach to a process run by this group ID, if set.
     pub fn group_id(&self) -> Option<u32> {
         if unsafe { sys::SBAttachInfoGroupIDIsValid(self.raw) } {
             Some(unsafe { sys::SBAttachInfoGetGroupID(self.raw) })
@@ -145,12 +186,12 @@ impl SBAttachInfo {
         }
     }
 
-    #[allow(missing_docs)]
+    /// Only attach to a process run by this group ID.
     pub fn set_group_id(&self, gid: u32) {
         unsafe { sys::SBAttachInfoSetGroupID(self.raw, gid) };
     }
 
-    #[allow(missing_docs)]
+    /// Only attach to a process with this effective user ID, if set.
     pub fn effective_user_id(&self) -> Option<u32> {
         if unsafe { sys::SBAttachInfoEffectiveUserIDIsValid(self.raw) } {
             Some(unsafe { sys::SBAttachInfoGetEffectiveUserID(self.raw) })
@@ -159,12 +200,12 @@ impl SBAttachInfo {
         }
     }
 
-    #[allow(missing_docs)]
+    /// Only attach to a process with this effective user ID.
     pub fn set_effective_user_id(&self, uid: u32) {
         unsafe { sys::SBAttachInfoSetEffectiveUserID(self.raw, uid) };
     }
 
-    #[allow(missing_docs)]
+    /// Only attach to a process with this effective group ID, if set.
     pub fn effective_group_id(&self) -> Option<u32> {
         if unsafe { sys::SBAttachInfoEffectiveGroupIDIsValid(self.raw) } {
             Some(unsafe { sys::SBAttachInfoGetEffectiveGroupID(self.raw) })
@@ -173,12 +214,12 @@ impl SBAttachInfo {
         }
     }
 
-    #[allow(missing_docs)]
+    /// Only attach to a process with this effective group ID.
     pub fn set_effective_group_id(&self, gid: u32) {
         unsafe { sys::SBAttachInfoSetEffectiveGroupID(self.raw, gid) };
     }
 
-    #[allow(missing_docs)]
+    /// Only attach to a process with this parent process ID, if set.
     pub fn parent_process_id(&self) -> Option<lldb_pid_t> {
         if unsafe { sys::SBAttachInfoParentProcessIDIsValid(self.raw) } {
             Some(unsafe { sys::SBAttachInfoGetParentProcessID(self.raw) })
@@ -187,7 +228,7 @@ impl SBAttachInfo {
         }
     }
 
-    #[allow(missing_docs)]
+    /// Only attach to a process with this parent process ID.
     pub fn set_parent_process_id(&self, ppid: lldb_pid_t) {
         unsafe { sys::SBAttachInfoSetParentProcessID(self.raw, ppid) };
     }
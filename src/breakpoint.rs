@@ -5,11 +5,16 @@
 // except according to those terms.
 
 use crate::{
-    lldb_addr_t, sys, SBBreakpointLocation, SBStream, SBStringList, SBStructuredData, SBTarget,
+    lldb_addr_t, lldb_tid_t, sys, BreakpointEventType, DescriptionLevel, SBBreakpointLocation,
+    SBError, SBEvent, SBFileSpec, SBFileSpecList, SBStream, SBStringList, SBStructuredData,
+    SBTarget, LLDB_INVALID_THREAD_ID,
 };
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::fmt;
 
+/// The prefix of the breakpoint name used to record a maximum hit count.
+const MAX_HIT_COUNT_NAME_PREFIX: &str = "lldb_rs_max_hit_count_";
+
 /// A logical breakpoint and its associated settings.
 ///
 /// # To Hit or Not
@@ -26,6 +31,10 @@ use std::fmt;
 ///   the first *ignore count* times that it is hit. This is
 ///   controlled via [`SBBreakpoint::ignore_count()`] and
 ///   [`SBBreakpoint::set_ignore_count()`].
+/// * Maximum hit count. If set, this breakpoint will be disabled
+///   once it has been hit that many times. This is controlled via
+///   [`SBBreakpoint::max_hit_count()`] and
+///   [`SBBreakpoint::set_max_hit_count()`].
 ///
 /// A count of how many times a breakpoint has been it is
 /// available via [`SBBreakpoint::hit_count()`].
@@ -119,12 +128,163 @@ impl SBBreakpoint {
         unsafe { sys::SBBreakpointSetIgnoreCount(self.raw, count) }
     }
 
-    #[allow(missing_docs)]
+    /// The condition which must be true for this breakpoint to stop,
+    /// if there is one.
+    pub fn condition(&self) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(sys::SBBreakpointGetCondition(self.raw).as_ref()?).to_str() {
+                Ok(s) if !s.is_empty() => Some(s),
+                Ok(_) => None,
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// Set the condition, an expression in the language of the code
+    /// at the breakpoint, which must be true for this breakpoint to
+    /// stop. `None` removes the condition.
+    pub fn set_condition(&self, condition: Option<&str>) {
+        let condition = CString::new(condition.unwrap_or_default()).unwrap();
+        unsafe { sys::SBBreakpointSetCondition(self.raw, condition.as_ptr()) };
+    }
+
+    /// Does the process continue automatically after this breakpoint
+    /// is hit, once its commands have run?
+    pub fn auto_continue(&self) -> bool {
+        unsafe { sys::SBBreakpointGetAutoContinue(self.raw) }
+    }
+
+    /// Set whether the process continues automatically after this
+    /// breakpoint is hit, once its commands have run.
+    pub fn set_auto_continue(&self, auto_continue: bool) {
+        unsafe { sys::SBBreakpointSetAutoContinue(self.raw, auto_continue) };
+    }
+
+    /// The ID of the only thread which this breakpoint stops, if it
+    /// is limited to one.
+    pub fn thread_id(&self) -> Option<lldb_tid_t> {
+        match unsafe { sys::SBBreakpointGetThreadID(self.raw) } {
+            LLDB_INVALID_THREAD_ID => None,
+            thread_id => Some(thread_id),
+        }
+    }
+
+    /// Limit this breakpoint to stopping the thread with the given ID.
+    /// `None` removes the limit.
+    pub fn set_thread_id(&self, thread_id: Option<lldb_tid_t>) {
+        let thread_id = thread_id.unwrap_or(LLDB_INVALID_THREAD_ID);
+        unsafe { sys::SBBreakpointSetThreadID(self.raw, thread_id) };
+    }
+
+    /// The name of the only thread which this breakpoint stops, if it
+    /// is limited to one.
+    pub fn thread_name(&self) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(sys::SBBreakpointGetThreadName(self.raw).as_ref()?).to_str() {
+                Ok(s) => Some(s),
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// Limit this breakpoint to stopping the thread with the given
+    /// name.
+    pub fn set_thread_name(&self, thread_name: &str) {
+        let thread_name = CString::new(thread_name).unwrap();
+        unsafe { sys::SBBreakpointSetThreadName(self.raw, thread_name.as_ptr()) };
+    }
+
+    /// Disable this breakpoint once it has been hit `count` times.
+    ///
+    /// LLDB has no maximum hit count of its own, so this is enforced by
+    /// a Python callback on the breakpoint which disables it when it is
+    /// hit for the last time. This replaces any commands or callback
+    /// that the breakpoint already had, and fails if LLDB was built
+    /// without Python. If the breakpoint has already been hit `count`
+    /// times, it is disabled right away.
+    ///
+    /// The count is also recorded as one of the [names] of the
+    /// breakpoint, so that it can be read back with
+    /// [`SBBreakpoint::max_hit_count()`].
+    ///
+    /// Passing `None` removes the maximum along with the callback.
+    ///
+    /// [names]: SBBreakpoint::names()
+    pub fn set_max_hit_count(&self, count: Option<u32>) -> Result<(), SBError> {
+        if let Some(count) = count {
+            let body = format!(
+                "bp = bp_loc.GetBreakpoint()\n\
+                 if bp.GetHitCount() >= {count}:\n    bp.SetEnabled(False)"
+            );
+            let body = CString::new(body).unwrap();
+            let error = SBError::wrap(unsafe {
+                sys::SBBreakpointSetScriptCallbackBody(self.raw, body.as_ptr())
+            });
+            if error.is_failure() {
+                return Err(error);
+            }
+        } else {
+            self.set_command_line_commands(&SBStringList::new());
+        }
+        if let Some(existing) = self.max_hit_count() {
+            self.remove_name(&format!("{MAX_HIT_COUNT_NAME_PREFIX}{existing}"));
+        }
+        if let Some(count) = count {
+            self.add_name(&format!("{MAX_HIT_COUNT_NAME_PREFIX}{count}"));
+            if self.hit_count() >= count {
+                self.set_enabled(false);
+            }
+        }
+        Ok(())
+    }
+
+    /// The number of hits after which this breakpoint is disabled, if
+    /// it was set via [`SBBreakpoint::set_max_hit_count()`].
+    pub fn max_hit_count(&self) -> Option<u32> {
+        self.names()
+            .iter()
+            .find_map(|name| name.strip_prefix(MAX_HIT_COUNT_NAME_PREFIX)?.parse().ok())
+    }
+
+    /// The number of hits remaining before this breakpoint is disabled
+    /// by its [maximum hit count](SBBreakpoint::set_max_hit_count()),
+    /// if it has one.
+    pub fn remaining_hits(&self) -> Option<u32> {
+        self.max_hit_count()
+            .map(|max| max.saturating_sub(self.hit_count()))
+    }
+
+    /// The LLDB commands run when this breakpoint is hit.
+    pub fn command_line_commands(&self) -> SBStringList {
+        let commands = SBStringList::new();
+        unsafe { sys::SBBreakpointGetCommandLineCommands(self.raw, commands.raw) };
+        commands
+    }
+
+    /// Set the LLDB commands run when this breakpoint is hit, replacing
+    /// any existing commands or callback.
+    pub fn set_command_line_commands(&self, commands: &SBStringList) {
+        unsafe { sys::SBBreakpointSetCommandLineCommands(self.raw, commands.raw) };
+    }
+
+    /// Add a name to this breakpoint, returning whether it was added.
+    ///
+    /// Breakpoint names may not contain spaces, `-` or `.`, or start
+    /// with a digit. See [`SBBreakpoint::add_name_checked()`] to find
+    /// out why a name was rejected.
     pub fn add_name(&self, name: &str) -> bool {
         let name = CString::new(name).unwrap();
         unsafe { sys::SBBreakpointAddName(self.raw, name.as_ptr()) }
     }
 
+    /// Add a name to this breakpoint, reporting why it was rejected
+    /// if it isn't a valid breakpoint name.
+    pub fn add_name_checked(&self, name: &str) -> Result<(), SBError> {
+        let name = CString::new(name).unwrap();
+        SBError::wrap(unsafe { sys::SBBreakpointAddNameWithErrorHandling(self.raw, name.as_ptr()) })
+            .into_result()
+    }
+
     #[allow(missing_docs)]
     pub fn remove_name(&self, name: &str) {
         let name = CString::new(name).unwrap();
@@ -171,6 +331,34 @@ impl SBBreakpoint {
         SBBreakpointLocation::maybe_wrap(unsafe { sys::SBBreakpointFindLocationByID(self.raw, id) })
     }
 
+    /// The number of locations of this breakpoint.
+    pub fn locations_len(&self) -> usize {
+        unsafe { sys::SBBreakpointGetNumLocations(self.raw) }
+    }
+
+    /// The number of locations of this breakpoint that are resolved
+    /// to an address in a loaded module.
+    pub fn resolved_locations_len(&self) -> usize {
+        unsafe { sys::SBBreakpointGetNumResolvedLocations(self.raw) }
+    }
+
+    /// Summarize the state of the locations of this breakpoint, such as
+    /// for a status line in a user interface.
+    pub fn locations_summary(&self) -> BreakpointLocationsSummary {
+        let mut summary = BreakpointLocationsSummary::default();
+        for location in self.locations() {
+            summary.total += 1;
+            if location.is_resolved() {
+                summary.resolved += 1;
+            }
+            if location.is_enabled() {
+                summary.enabled += 1;
+            }
+            summary.hit_count += u64::from(location.hit_count());
+        }
+        summary
+    }
+
     #[allow(missing_docs)]
     pub fn locations(&self) -> SBBreakpointLocationIter {
         SBBreakpointLocationIter {
@@ -188,6 +376,76 @@ impl SBBreakpoint {
     pub fn serialize_to_structured_data(&self) -> SBStructuredData {
         SBStructuredData::wrap(unsafe { sys::SBBreakpointSerializeToStructuredData(self.raw) })
     }
+
+    /// The modules that this breakpoint is limited to resolving within.
+    ///
+    /// An empty list means that the breakpoint is not limited to
+    /// specific modules.
+    pub fn module_filter(&self) -> SBFileSpecList {
+        self.search_filter_files("ModuleList")
+    }
+
+    /// The compile units that this breakpoint is limited to resolving
+    /// within.
+    ///
+    /// An empty list means that the breakpoint is not limited to
+    /// specific compile units.
+    pub fn compile_unit_filter(&self) -> SBFileSpecList {
+        self.search_filter_files("CUList")
+    }
+
+    /// Extract a list of files from the search filter options in the
+    /// serialized form of this breakpoint.
+    fn search_filter_files(&self, key: &str) -> SBFileSpecList {
+        let files = SBFileSpecList::new();
+        let list = self
+            .serialize_to_structured_data()
+            .value_for_key("Breakpoint")
+            .and_then(|bp| bp.value_for_key("SearchFilter"))
+            .and_then(|filter| filter.value_for_key("Options"))
+            .and_then(|options| options.value_for_key(key));
+        if let Some(list) = list {
+            for idx in 0..list.size() {
+                if let Some(path) = list.item_at_index(idx).and_then(|p| p.string_value()) {
+                    files.append(&SBFileSpec::from_path(path, false));
+                }
+            }
+        }
+        files
+    }
+
+    /// If the given event is a breakpoint event, return it as an
+    /// `SBBreakpointEvent`. Otherwise, return `None`.
+    ///
+    /// Breakpoint events are broadcast by the [target] with
+    /// [`SBTargetEvent::BROADCAST_BIT_BREAKPOINT_CHANGED`] when
+    /// breakpoints are added, removed or modified, including when LLDB
+    /// resolves new locations as shared libraries are loaded.
+    ///
+    /// [target]: crate::SBTarget::broadcaster()
+    /// [`SBTargetEvent::BROADCAST_BIT_BREAKPOINT_CHANGED`]: crate::SBTargetEvent::BROADCAST_BIT_BREAKPOINT_CHANGED
+    pub fn event_as_breakpoint_event(event: &SBEvent) -> Option<SBBreakpointEvent<'_>> {
+        if unsafe { sys::SBBreakpointEventIsBreakpointEvent(event.raw) } {
+            Some(SBBreakpointEvent::new(event))
+        } else {
+            None
+        }
+    }
+
+    /// Describe this breakpoint at the given level of detail.
+    ///
+    /// The locations of the breakpoint are included unless `level`
+    /// is [`DescriptionLevel::Brief`].
+    ///
+    /// This is also available via the `Display` implementation, which
+    /// uses [`DescriptionLevel::Brief`], or [`DescriptionLevel::Full`]
+    /// when formatted with `{:#}`.
+    pub fn describe(&self, level: DescriptionLevel) -> String {
+        let stream = SBStream::new();
+        let include_locations = level != DescriptionLevel::Brief;
+        unsafe { sys::SBBreakpointGetDescription2(self.raw, stream.raw, include_locations) };
+        stream.data().to_string()
+    }
 }
 
 impl Clone for SBBreakpoint {
@@ -200,9 +458,22 @@ impl Clone for SBBreakpoint {
 
 impl fmt::Debug for SBBreakpoint {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let stream = SBStream::new();
-        unsafe { sys::SBBreakpointGetDescription(self.raw, stream.raw) };
-        write!(fmt, "SBBreakpoint {{ {} }}", stream.data())
+        write!(
+            fmt,
+            "SBBreakpoint {{ {} }}",
+            self.describe(DescriptionLevel::Full)
+        )
+    }
+}
+
+impl fmt::Display for SBBreakpoint {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let level = if fmt.alternate() {
+            DescriptionLevel::Full
+        } else {
+            DescriptionLevel::Brief
+        };
+        write!(fmt, "{}", self.describe(level))
     }
 }
 
@@ -215,6 +486,107 @@ impl Drop for SBBreakpoint {
 unsafe impl Send for SBBreakpoint {}
 unsafe impl Sync for SBBreakpoint {}
 
+/// A breakpoint event.
+///
+/// ```no_run
+/// use lldb::{BreakpointEventType, SBBreakpoint, SBEvent};
+///
+/// fn handle(event: &SBEvent) {
+///     if let Some(breakpoint_event) = SBBreakpoint::event_as_breakpoint_event(event) {
+///         if breakpoint_event.event_type() == BreakpointEventType::LocationsAdded {
+///             for location in breakpoint_event.locations() {
+///                 println!("new location at {:#x}", location.load_address());
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub struct SBBreakpointEvent<'e> {
+    event: &'e SBEvent,
+}
+
+impl<'e> SBBreakpointEvent<'e> {
+    /// Construct a new `SBBreakpointEvent`.
+    pub fn new(event: &'e SBEvent) -> Self {
+        SBBreakpointEvent { event }
+    }
+
+    /// What happened to the breakpoint.
+    pub fn event_type(&self) -> BreakpointEventType {
+        unsafe { sys::SBBreakpointGetBreakpointEventTypeFromEvent(self.event.raw) }
+    }
+
+    /// The breakpoint that this event is about.
+    pub fn breakpoint(&self) -> Option<SBBreakpoint> {
+        SBBreakpoint::maybe_wrap(unsafe { sys::SBBreakpointGetBreakpointFromEvent(self.event.raw) })
+    }
+
+    /// The number of breakpoint locations that this event is about,
+    /// such as the locations which were added or resolved.
+    pub fn num_locations(&self) -> usize {
+        unsafe { sys::SBBreakpointGetNumBreakpointLocationsFromEvent(self.event.raw) as usize }
+    }
+
+    /// The breakpoint locations that this event is about.
+    pub fn locations(&self) -> SBBreakpointEventLocationIter<'_> {
+        SBBreakpointEventLocationIter {
+            event: self,
+            idx: 0,
+        }
+    }
+}
+
+/// An iterator over the [locations] referenced by an
+/// [`SBBreakpointEvent`].
+///
+/// [locations]: SBBreakpointLocation
+pub struct SBBreakpointEventLocationIter<'d> {
+    event: &'d SBBreakpointEvent<'d>,
+    idx: usize,
+}
+
+impl Iterator for SBBreakpointEventLocationIter<'_> {
+    type Item = SBBreakpointLocation;
+
+    fn next(&mut self) -> Option<SBBreakpointLocation> {
+        if self.idx < self.event.num_locations() {
+            let r = SBBreakpointLocation::maybe_wrap(unsafe {
+                sys::SBBreakpointGetBreakpointLocationAtIndexFromEvent(
+                    self.event.event.raw,
+                    self.idx as u32,
+                )
+            });
+            self.idx += 1;
+            r
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let sz = self.event.num_locations() - self.idx;
+        (sz, Some(sz))
+    }
+}
+
+impl ExactSizeIterator for SBBreakpointEventLocationIter<'_> {}
+
+/// A summary of the state of the locations of a breakpoint.
+///
+/// See [`SBBreakpoint::locations_summary()`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BreakpointLocationsSummary {
+    /// The number of locations.
+    pub total: usize,
+    /// The number of locations resolved to an address in a loaded
+    /// module.
+    pub resolved: usize,
+    /// The number of enabled locations.
+    pub enabled: usize,
+    /// The sum of the hit counts of all of the locations.
+    pub hit_count: u64,
+}
+
 /// An iterator over the [locations] in an [`SBBreakpoint`].
 ///
 /// [locations]: SBBreakpointLocation
@@ -239,8 +611,8 @@ impl Iterator for SBBreakpointLocationIter<'_> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let sz = unsafe { sys::SBBreakpointGetNumLocations(self.breakpoint.raw) };
-        (sz - self.idx, Some(sz))
+        let sz = unsafe { sys::SBBreakpointGetNumLocations(self.breakpoint.raw) } - self.idx;
+        (sz, Some(sz))
     }
 }
 
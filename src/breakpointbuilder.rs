@@ -0,0 +1,235 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{
+    lldb_addr_t, lldb_tid_t, FunctionNameType, SBBreakpoint, SBError, SBFileSpec, SBFileSpecList,
+    SBTarget,
+};
+
+/// A builder for breakpoints, created by [`SBTarget::breakpoint()`].
+///
+/// This composes the creation of a breakpoint with the settings that
+/// usually follow it:
+///
+/// ```no_run
+/// # use lldb::SBTarget;
+/// # fn set_breakpoint(target: &SBTarget) -> Result<(), lldb::SBError> {
+/// let breakpoint = target
+///     .breakpoint()
+///     .file_line("main.rs", 10)
+///     .condition("x > 3")
+///     .one_shot(true)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Exactly one of [`file_line()`], [`function()`], [`regex()`] or
+/// [`address()`] must be given to say where the breakpoint goes. If
+/// more than one is given, the last one wins.
+///
+/// [`file_line()`]: BreakpointBuilder::file_line()
+/// [`function()`]: BreakpointBuilder::function()
+/// [`regex()`]: BreakpointBuilder::regex()
+/// [`address()`]: BreakpointBuilder::address()
+#[derive(Clone, Debug)]
+pub struct BreakpointBuilder<'t> {
+    target: &'t SBTarget,
+    location: Option<Location>,
+    modules: Vec<String>,
+    condition: Option<String>,
+    ignore_count: u32,
+    one_shot: bool,
+    enabled: bool,
+    auto_continue: bool,
+    thread_id: Option<lldb_tid_t>,
+    thread_name: Option<String>,
+    names: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+enum Location {
+    FileLine(String, u32),
+    Function(String),
+    Regex(String),
+    Address(lldb_addr_t),
+}
+
+impl<'t> BreakpointBuilder<'t> {
+    pub(crate) fn new(target: &'t SBTarget) -> BreakpointBuilder<'t> {
+        BreakpointBuilder {
+            target,
+            location: None,
+            modules: Vec::new(),
+            condition: None,
+            ignore_count: 0,
+            one_shot: false,
+            enabled: true,
+            auto_continue: false,
+            thread_id: None,
+            thread_name: None,
+            names: Vec::new(),
+        }
+    }
+
+    /// Break at `line` of `file`.
+    pub fn file_line(mut self, file: &str, line: u32) -> Self {
+        self.location = Some(Location::FileLine(file.to_string(), line));
+        self
+    }
+
+    /// Break on the functions named `name`.
+    pub fn function(mut self, name: &str) -> Self {
+        self.location = Some(Location::Function(name.to_string()));
+        self
+    }
+
+    /// Break on the functions whose names match the regular expression
+    /// `pattern`.
+    pub fn regex(mut self, pattern: &str) -> Self {
+        self.location = Some(Location::Regex(pattern.to_string()));
+        self
+    }
+
+    /// Break at the load address `address`.
+    pub fn address(mut self, address: lldb_addr_t) -> Self {
+        self.location = Some(Location::Address(address));
+        self
+    }
+
+    /// Only resolve the breakpoint within the module named `module`.
+    ///
+    /// This can be given more than once to allow several modules. It
+    /// doesn't apply to breakpoints at an [address].
+    ///
+    /// [address]: BreakpointBuilder::address()
+    pub fn module(mut self, module: &str) -> Self {
+        self.modules.push(module.to_string());
+        self
+    }
+
+    /// Only stop when `condition` is true.
+    ///
+    /// See [`SBBreakpoint::set_condition()`].
+    pub fn condition(mut self, condition: &str) -> Self {
+        self.condition = Some(condition.to_string());
+        self
+    }
+
+    /// Don't stop for the first `count` hits.
+    pub fn ignore_count(mut self, count: u32) -> Self {
+        self.ignore_count = count;
+        self
+    }
+
+    /// Delete the breakpoint once it has been hit.
+    pub fn one_shot(mut self, one_shot: bool) -> Self {
+        self.one_shot = one_shot;
+        self
+    }
+
+    /// Create the breakpoint enabled or disabled. Breakpoints are
+    /// enabled by default.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Continue the process automatically after the breakpoint is hit.
+    pub fn auto_continue(mut self, auto_continue: bool) -> Self {
+        self.auto_continue = auto_continue;
+        self
+    }
+
+    /// Only stop the thread with the ID `thread_id`.
+    pub fn thread_id(mut self, thread_id: lldb_tid_t) -> Self {
+        self.thread_id = Some(thread_id);
+        self
+    }
+
+    /// Only stop the thread named `thread_name`.
+    pub fn thread_name(mut self, thread_name: &str) -> Self {
+        self.thread_name = Some(thread_name.to_string());
+        self
+    }
+
+    /// Add `name` to the breakpoint's [names].
+    ///
+    /// This can be given more than once.
+    ///
+    /// [names]: SBBreakpoint::names()
+    pub fn name(mut self, name: &str) -> Self {
+        self.names.push(name.to_string());
+        self
+    }
+
+    /// Create the breakpoint.
+    ///
+    /// This fails if no location was given, if LLDB couldn't create
+    /// the breakpoint or if one of its [names] is invalid. A breakpoint
+    /// which doesn't resolve to any locations yet is still created, as
+    /// it may resolve once more modules are loaded.
+    ///
+    /// [names]: BreakpointBuilder::name()
+    pub fn build(&self) -> Result<SBBreakpoint, SBError> {
+        let location = self
+            .location
+            .as_ref()
+            .ok_or_else(|| SBError::from_message("no breakpoint location was given"))?;
+        let modules = SBFileSpecList::new();
+        for module in &self.modules {
+            modules.append(&SBFileSpec::from_path(module, false));
+        }
+        let breakpoint = match location {
+            Location::FileLine(file, line) => self.target.breakpoint_create_by_location_in_modules(
+                &SBFileSpec::from_path(file, false),
+                *line,
+                0,
+                0,
+                &modules,
+                true,
+            ),
+            Location::Function(name) => self.target.breakpoint_create_by_name(
+                name,
+                FunctionNameType::AUTO,
+                &modules,
+                &SBFileSpecList::new(),
+            ),
+            Location::Regex(pattern) => {
+                self.target
+                    .breakpoint_create_by_regex(pattern, &modules, &SBFileSpecList::new())
+            }
+            Location::Address(address) => self.target.breakpoint_create_by_address(*address),
+        };
+        if !breakpoint.is_valid() {
+            return Err(SBError::from_message("couldn't create the breakpoint"));
+        }
+        if let Some(condition) = &self.condition {
+            breakpoint.set_condition(Some(condition));
+        }
+        if self.ignore_count > 0 {
+            breakpoint.set_ignore_count(self.ignore_count);
+        }
+        breakpoint.set_oneshot(self.one_shot);
+        breakpoint.set_enabled(self.enabled);
+        breakpoint.set_auto_continue(self.auto_continue);
+        if let Some(thread_id) = self.thread_id {
+            breakpoint.set_thread_id(Some(thread_id));
+        }
+        if let Some(thread_name) = &self.thread_name {
+            breakpoint.set_thread_name(thread_name);
+        }
+        for name in &self.names {
+            if !breakpoint.add_name(name) {
+                self.target.delete_breakpoint(breakpoint.id());
+                return Err(SBError::from_message(&format!(
+                    "invalid breakpoint name {name:?}"
+                )));
+            }
+        }
+        Ok(breakpoint)
+    }
+}
@@ -25,24 +25,35 @@ impl SBBreakpointList {
         SBBreakpointList { raw }
     }
 
-    #[allow(missing_docs)]
+    /// Find the breakpoint in this list with the given ID.
     pub fn find_breakpoint_by_id(&self, id: i32) -> Option<SBBreakpoint> {
         SBBreakpoint::maybe_wrap(unsafe { sys::SBBreakpointListFindBreakpointByID(self.raw, id) })
     }
 
-    #[allow(missing_docs)]
+    /// Add a breakpoint to the end of this list.
     pub fn append(&self, bkpt: &SBBreakpoint) {
         unsafe { sys::SBBreakpointListAppend(self.raw, bkpt.raw) };
     }
 
-    #[allow(missing_docs)]
+    /// Add the breakpoint with the given ID to the end of this list.
+    ///
+    /// The breakpoint is looked up in the target that this list
+    /// was created for.
     pub fn append_by_id(&self, bkpt_id: i32) {
         unsafe { sys::SBBreakpointListAppendByID(self.raw, bkpt_id) };
     }
 
-    #[allow(missing_docs)]
-    pub fn append_if_unique(&self, bkpt: &SBBreakpoint) {
-        unsafe { sys::SBBreakpointListAppendIfUnique(self.raw, bkpt.raw) };
+    /// Add a breakpoint to the end of this list unless it is
+    /// already present.
+    ///
+    /// Returns `true` if the breakpoint was added.
+    pub fn append_if_unique(&self, bkpt: &SBBreakpoint) -> bool {
+        unsafe { sys::SBBreakpointListAppendIfUnique(self.raw, bkpt.raw) }
+    }
+
+    /// The number of breakpoints in this list.
+    pub fn len(&self) -> usize {
+        unsafe { sys::SBBreakpointListGetSize(self.raw) }
     }
 
     /// Is this breakpoint list empty?
@@ -4,7 +4,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::{lldb_addr_t, sys, DescriptionLevel, SBAddress, SBBreakpoint, SBStream};
+use crate::{
+    lldb_addr_t, sys, DescriptionLevel, SBAddress, SBBreakpoint, SBStream, LLDB_INVALID_ADDRESS,
+};
 use std::fmt;
 
 /// One unique instance (by address) of a logical breakpoint.
@@ -52,6 +54,30 @@ impl SBBreakpointLocation {
         unsafe { sys::SBBreakpointLocationGetLoadAddress(self.raw) }
     }
 
+    /// The load address of this location, if it is resolved to an
+    /// address in a loaded module.
+    pub fn resolved_load_address(&self) -> Option<lldb_addr_t> {
+        if !self.is_resolved() {
+            return None;
+        }
+        let address = self.load_address();
+        if address == LLDB_INVALID_ADDRESS {
+            None
+        } else {
+            Some(address)
+        }
+    }
+
+    /// Is this location implemented with a hardware breakpoint rather
+    /// than by modifying the code in memory?
+    ///
+    /// This is a property of the [breakpoint] as a whole.
+    ///
+    /// [breakpoint]: SBBreakpointLocation::breakpoint()
+    pub fn is_hardware(&self) -> bool {
+        self.breakpoint().is_hardware()
+    }
+
     #[allow(missing_docs)]
     pub fn is_enabled(&self) -> bool {
         unsafe { sys::SBBreakpointLocationIsEnabled(self.raw) }
@@ -0,0 +1,209 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Preset breakpoints for common "break on error" workflows.
+//!
+//! Each preset creates a breakpoint in the given [`SBTarget`] and tags
+//! it with a breakpoint name so that it can be found again later with
+//! [`SBBreakpoint::matches_name()`].
+//!
+//! ```no_run
+//! use lldb::{breakpoints, SBDebugger};
+//!
+//! SBDebugger::initialize();
+//! let debugger = SBDebugger::create(false);
+//! if let Some(target) = debugger.create_target_simple("/usr/local/bin/servo") {
+//!     let panic = breakpoints::rust_panic(&target).unwrap();
+//!     assert!(panic.matches_name(breakpoints::RUST_PANIC_NAME));
+//! }
+//! ```
+//!
+//! Breakpoints can also be created in bulk from a list of source
+//! locations with [`import_from_file_list()`].
+
+use crate::{
+    sys, FunctionNameType, LanguageType, SBBreakpoint, SBError, SBFileSpec, SBFileSpecList,
+    SBTarget,
+};
+use std::path::PathBuf;
+
+/// The breakpoint name given to breakpoints created by [`rust_panic()`].
+pub const RUST_PANIC_NAME: &str = "rust_panic";
+
+/// The breakpoint name given to breakpoints created by [`cpp_terminate()`].
+pub const CPP_TERMINATE_NAME: &str = "cpp_terminate";
+
+/// The breakpoint name given to breakpoints created by
+/// [`objc_exception_throw()`].
+pub const OBJC_EXCEPTION_THROW_NAME: &str = "objc_exception_throw";
+
+/// Break when a Rust panic begins unwinding.
+///
+/// This is a breakpoint on `rust_panic`, the function in the standard
+/// library that is called once the panic hook has run, which is the
+/// same as `breakpoint set -n rust_panic`.
+///
+/// An error is returned if the breakpoint could not be named, in which
+/// case the breakpoint is deleted again.
+pub fn rust_panic(target: &SBTarget) -> Result<SBBreakpoint, SBError> {
+    by_name(target, "rust_panic", RUST_PANIC_NAME)
+}
+
+/// Break when `std::terminate` is called in C++.
+///
+/// This catches uncaught exceptions as well as other fatal errors
+/// such as exceptions escaping a `noexcept` function.
+///
+/// An error is returned if the breakpoint could not be named, in which
+/// case the breakpoint is deleted again.
+pub fn cpp_terminate(target: &SBTarget) -> Result<SBBreakpoint, SBError> {
+    by_name(target, "std::terminate", CPP_TERMINATE_NAME)
+}
+
+/// Break when an Objective-C exception is thrown.
+///
+/// This is the same as `breakpoint set -E objc`, which breaks
+/// in `objc_exception_throw`.
+///
+/// An error is returned if the breakpoint could not be named, in which
+/// case the breakpoint is deleted again.
+pub fn objc_exception_throw(target: &SBTarget) -> Result<SBBreakpoint, SBError> {
+    let breakpoint = SBBreakpoint::wrap(unsafe {
+        sys::SBTargetBreakpointCreateForException(target.raw, LanguageType::ObjC, false, true)
+    });
+    named(target, breakpoint, OBJC_EXCEPTION_THROW_NAME)
+}
+
+/// The breakpoint name given to breakpoints created by
+/// [`import_from_file_list()`].
+pub const IMPORTED_NAME: &str = "imported";
+
+/// The result of importing one entry with [`import_from_file_list()`].
+#[derive(Debug)]
+pub struct ImportedEntry {
+    /// The source file of the entry.
+    pub file: PathBuf,
+    /// The line of the entry.
+    pub line: u32,
+    /// The breakpoint created for the entry, if LLDB could create one.
+    pub breakpoint: Option<SBBreakpoint>,
+    /// The number of locations that the breakpoint resolved to.
+    pub num_locations: usize,
+}
+
+impl ImportedEntry {
+    /// Did the breakpoint resolve to at least one location?
+    ///
+    /// Breakpoints which are created but not yet resolved may still
+    /// resolve later, when more modules are loaded.
+    pub fn is_resolved(&self) -> bool {
+        self.num_locations > 0
+    }
+}
+
+/// A report of the breakpoints created by [`import_from_file_list()`].
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    /// The results for each entry, in the order they were given.
+    pub entries: Vec<ImportedEntry>,
+}
+
+impl ImportReport {
+    /// The entries whose breakpoints resolved to at least one location.
+    pub fn resolved(&self) -> impl Iterator<Item = &ImportedEntry> {
+        self.entries.iter().filter(|e| e.is_resolved())
+    }
+
+    /// The entries whose breakpoints were created but not resolved.
+    pub fn unresolved(&self) -> impl Iterator<Item = &ImportedEntry> {
+        self.entries
+            .iter()
+            .filter(|e| e.breakpoint.is_some() && !e.is_resolved())
+    }
+
+    /// The entries for which no breakpoint could be created.
+    pub fn failed(&self) -> impl Iterator<Item = &ImportedEntry> {
+        self.entries.iter().filter(|e| e.breakpoint.is_none())
+    }
+}
+
+/// Create breakpoints in bulk from a list of source locations, such as
+/// those in a project's metadata or a sanitizer file list.
+///
+/// Each `(file, line)` entry gets a breakpoint tagged with
+/// [`IMPORTED_NAME`], and the result for every entry is reported, in
+/// the order of `entries`.
+///
+/// LLDB resolves each breakpoint against the loaded modules on its
+/// own, so this takes about as long as setting the breakpoints one at
+/// a time.
+///
+/// ```no_run
+/// use lldb::{breakpoints, SBDebugger};
+/// use std::path::PathBuf;
+///
+/// SBDebugger::initialize();
+/// let debugger = SBDebugger::create(false);
+/// let target = debugger.create_target_simple("/usr/local/bin/servo").unwrap();
+/// let report = breakpoints::import_from_file_list(
+///     &target,
+///     vec![(PathBuf::from("main.rs"), 10), (PathBuf::from("lib.rs"), 42)],
+/// );
+/// for entry in report.failed() {
+///     eprintln!("Could not set {}:{}", entry.file.display(), entry.line);
+/// }
+/// ```
+pub fn import_from_file_list<I>(target: &SBTarget, entries: I) -> ImportReport
+where
+    I: IntoIterator<Item = (PathBuf, u32)>,
+{
+    let module_list = SBFileSpecList::new();
+    let entries = entries
+        .into_iter()
+        .map(|(file, line)| {
+            let breakpoint = target.breakpoint_create_by_location_in_modules(
+                &SBFileSpec::from_path(&file, false),
+                line,
+                0,
+                0,
+                &module_list,
+                true,
+            );
+            let breakpoint = if breakpoint.is_valid() {
+                named(target, breakpoint, IMPORTED_NAME).ok()
+            } else {
+                None
+            };
+            ImportedEntry {
+                file,
+                line,
+                num_locations: breakpoint.as_ref().map_or(0, |b| b.locations().len()),
+                breakpoint,
+            }
+        })
+        .collect();
+    ImportReport { entries }
+}
+
+fn by_name(target: &SBTarget, symbol_name: &str, name: &str) -> Result<SBBreakpoint, SBError> {
+    let breakpoint = target.breakpoint_create_by_name(
+        symbol_name,
+        FunctionNameType::AUTO,
+        &SBFileSpecList::new(),
+        &SBFileSpecList::new(),
+    );
+    named(target, breakpoint, name)
+}
+
+/// Tag `breakpoint` with `name`, deleting it if that fails so that
+/// no untagged breakpoint is left behind.
+fn named(target: &SBTarget, breakpoint: SBBreakpoint, name: &str) -> Result<SBBreakpoint, SBError> {
+    if let Err(error) = breakpoint.add_name_checked(name) {
+        target.delete_breakpoint(breakpoint.id());
+        return Err(error);
+    }
+    Ok(breakpoint)
+}
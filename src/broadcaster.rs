@@ -5,6 +5,7 @@
 // except according to those terms.
 
 use crate::{sys, SBEvent, SBListener};
+use std::ffi::{CStr, CString};
 
 /// An entity which can broadcast events.
 ///
@@ -12,6 +13,22 @@ use crate::{sys, SBEvent, SBListener};
 /// `SBProcess`, and `SBTarget`.
 ///
 /// Use an `SBListener` to listen for events.
+///
+/// Applications can inject their own events into the same event loop
+/// as LLDB's events with a [named broadcaster]:
+///
+/// ```no_run
+/// use lldb::{SBBroadcaster, SBEvent, SBListener};
+///
+/// const REFRESH: u32 = 1 << 0;
+///
+/// let listener = SBListener::new();
+/// let broadcaster = SBBroadcaster::with_name("my-app");
+/// broadcaster.add_listener(&listener, REFRESH);
+/// broadcaster.broadcast_event(&SBEvent::with_string(REFRESH, "all"), false);
+/// ```
+///
+/// [named broadcaster]: SBBroadcaster::with_name()
 #[derive(Debug)]
 pub struct SBBroadcaster {
     /// The underlying raw `SBBroadcasterRef`.
@@ -20,10 +37,23 @@ pub struct SBBroadcaster {
 
 impl SBBroadcaster {
     /// Construct a new `SBBroadcaster`.
+    ///
+    /// This broadcaster is not [valid] and broadcasting with it does
+    /// nothing. Use [`SBBroadcaster::with_name()`] to broadcast custom
+    /// events.
+    ///
+    /// [valid]: SBBroadcaster::is_valid()
     pub fn new() -> SBBroadcaster {
         SBBroadcaster::wrap(unsafe { sys::CreateSBBroadcaster() })
     }
 
+    /// Construct a new `SBBroadcaster` with the given name, for
+    /// broadcasting custom events.
+    pub fn with_name(name: &str) -> SBBroadcaster {
+        let name = CString::new(name).unwrap();
+        SBBroadcaster::wrap(unsafe { sys::CreateSBBroadcaster2(name.as_ptr()) })
+    }
+
     /// Construct a new `SBBroadcaster`.
     pub(crate) fn wrap(raw: sys::SBBroadcasterRef) -> SBBroadcaster {
         SBBroadcaster { raw }
@@ -44,12 +74,32 @@ impl SBBroadcaster {
         unsafe { sys::SBBroadcasterIsValid(self.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// The name of this broadcaster.
+    pub fn name(&self) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(sys::SBBroadcasterGetName(self.raw).as_ref()?).to_str() {
+                Ok(s) => Some(s),
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// Broadcast an event of type `event_type`, without any data.
+    ///
+    /// When `unique` is `true`, the event is not queued for a listener
+    /// which already has an event of the same type from this broadcaster
+    /// pending.
     pub fn broadcast_event_by_type(&self, event_type: u32, unique: bool) {
         unsafe { sys::SBBroadcasterBroadcastEventByType(self.raw, event_type, unique) };
     }
 
-    #[allow(missing_docs)]
+    /// Broadcast `event` to the listeners for its type.
+    ///
+    /// The event's [broadcaster] becomes this broadcaster. When `unique`
+    /// is `true`, the event is not queued for a listener which already
+    /// has an event of the same type from this broadcaster pending.
+    ///
+    /// [broadcaster]: SBEvent::broadcaster()
     pub fn broadcast_event(&self, event: &SBEvent, unique: bool) {
         unsafe { sys::SBBroadcasterBroadcastEvent(self.raw, event.raw, unique) };
     }
@@ -61,7 +111,8 @@ impl SBBroadcaster {
         };
     }
 
-    #[allow(missing_docs)]
+    /// Start sending the events of the types in `event_mask` to
+    /// `listener`, returning the event types it will receive.
     pub fn add_listener(&self, listener: &SBListener, event_mask: u32) -> u32 {
         unsafe { sys::SBBroadcasterAddListener(self.raw, listener.raw, event_mask) }
     }
@@ -4,7 +4,10 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::{sys, LanguageType, SBFileSpec, SBLineEntry, SBStream, SBTypeList, TypeClass};
+use crate::{
+    sys, LanguageType, SBFileSpec, SBFunction, SBLineEntry, SBStream, SBTypeList, TypeClass,
+};
+use std::collections::BTreeSet;
 use std::fmt;
 
 /// A compilation unit or compiled source file.
@@ -64,6 +67,39 @@ impl SBCompileUnit {
     pub fn language(&self) -> LanguageType {
         unsafe { sys::SBCompileUnitGetLanguage(self.raw) }
     }
+
+    /// The files which contributed to this compile unit, such as the
+    /// headers that it includes.
+    pub fn support_files(&self) -> impl Iterator<Item = SBFileSpec> + '_ {
+        let count = unsafe { sys::SBCompileUnitGetNumSupportFiles(self.raw) };
+        (0..count).filter_map(|idx| {
+            SBFileSpec::maybe_wrap(unsafe {
+                sys::SBCompileUnitGetSupportFileAtIndex(self.raw, idx)
+            })
+        })
+    }
+
+    /// The functions which have code in this compile unit.
+    ///
+    /// LLDB doesn't list the functions of a compile unit directly, so
+    /// these are found by way of the line table, in address order.
+    pub fn functions(&self) -> Vec<SBFunction> {
+        let mut seen = BTreeSet::new();
+        self.line_entries()
+            .filter_map(|line_entry| line_entry.start_address().function())
+            .filter(|function| seen.insert(function.start_address().file_address()))
+            .collect()
+    }
+
+    /// Was any of the code in this compile unit compiled with
+    /// optimization?
+    ///
+    /// Variables may be unavailable and stepping may jump around in
+    /// optimized code, so tools can use this to warn users. See
+    /// [`SBFunction::is_optimized()`] to check a single function.
+    pub fn is_optimized(&self) -> bool {
+        self.functions().iter().any(SBFunction::is_optimized)
+    }
 }
 
 impl Clone for SBCompileUnit {
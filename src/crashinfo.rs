@@ -0,0 +1,92 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::SBStructuredData;
+
+/// A typed view over the extended crash information of a process.
+///
+/// This is currently only provided by LLDB on Apple platforms, where it
+/// is gathered from the crash report annotations which libraries such
+/// as `libswiftCore` and `libdispatch` record before crashing.
+///
+/// See [`SBProcess::extended_crash_info()`].
+///
+/// [`SBProcess::extended_crash_info()`]: crate::SBProcess::extended_crash_info()
+#[derive(Clone, Debug)]
+pub struct ExtendedCrashInfo {
+    data: SBStructuredData,
+}
+
+impl ExtendedCrashInfo {
+    pub(crate) fn new(data: SBStructuredData) -> ExtendedCrashInfo {
+        ExtendedCrashInfo { data }
+    }
+
+    /// The raw structured data.
+    pub fn data(&self) -> &SBStructuredData {
+        &self.data
+    }
+
+    /// The crash report annotations of the images loaded in the process.
+    pub fn annotations(&self) -> Vec<CrashAnnotation> {
+        let annotations = match self.data.value_for_key("Crash-Info Annotations") {
+            Some(annotations) => annotations,
+            None => return Vec::new(),
+        };
+        (0..annotations.size())
+            .filter_map(|idx| annotations.item_at_index(idx))
+            .map(|annotation| {
+                let string = |key: &str| annotation.value_for_key(key)?.string_value();
+                CrashAnnotation {
+                    image: string("image"),
+                    uuid: string("uuid"),
+                    message: string("message"),
+                    message2: string("message2"),
+                    abort_cause: annotation
+                        .value_for_key("abort-cause")
+                        .and_then(|cause| cause.integer_value()),
+                }
+            })
+            .collect()
+    }
+
+    /// The messages of all of the [annotations], in order.
+    ///
+    /// These are usually the most useful summary of why the process
+    /// crashed, such as the message of a Swift `fatalError`.
+    ///
+    /// [annotations]: ExtendedCrashInfo::annotations()
+    pub fn messages(&self) -> Vec<String> {
+        self.annotations()
+            .into_iter()
+            .flat_map(|annotation| annotation.message.into_iter().chain(annotation.message2))
+            .filter(|message| !message.is_empty())
+            .collect()
+    }
+
+    /// The application specific information recorded in the crash
+    /// report, if any.
+    pub fn application_specific_information(&self) -> Option<SBStructuredData> {
+        self.data.value_for_key("Application Specific Information")
+    }
+}
+
+/// A crash report annotation recorded by an image loaded in a process.
+///
+/// See [`ExtendedCrashInfo::annotations()`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CrashAnnotation {
+    /// The path of the image which recorded the annotation.
+    pub image: Option<String>,
+    /// The UUID of the image which recorded the annotation.
+    pub uuid: Option<String>,
+    /// The message of the annotation.
+    pub message: Option<String>,
+    /// The secondary message of the annotation.
+    pub message2: Option<String>,
+    /// The abort cause, such as an error code, if one was recorded.
+    pub abort_cause: Option<u64>,
+}
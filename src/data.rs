@@ -33,6 +33,18 @@ impl SBData {
         unsafe { sys::SBDataIsValid(self.raw) }
     }
 
+    /// The number of bytes in this data region.
+    pub fn byte_size(&self) -> usize {
+        unsafe { sys::SBDataGetByteSize(self.raw) }
+    }
+
+    /// Copy the entire contents of this data region into a `Vec<u8>`.
+    pub fn to_vec(&self) -> Result<Vec<u8>, SBError> {
+        let mut buffer = vec![0; self.byte_size()];
+        self.read_raw_data(0, &mut buffer)?;
+        Ok(buffer)
+    }
+
     /// Get address of the specified offset in this data region
     pub fn get_address(&self, offset: sys::lldb_offset_t) -> Result<sys::lldb_addr_t, SBError> {
         let error = SBError::default();
@@ -4,14 +4,43 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use crate::session;
 use crate::{
-    sys, SBCommandInterpreter, SBError, SBListener, SBPlatform, SBStream, SBStructuredData,
-    SBTarget,
+    lldb_user_id_t, sys, DisassemblyFlavor, Progress, SBBroadcaster, SBCommandInterpreter, SBError,
+    SBEvent, SBListener, SBModuleSpec, SBPlatform, SBStream, SBStringList, SBStructuredData,
+    SBTarget, SBTypeCategory,
 };
+use std::collections::BTreeSet;
 use std::ffi::{CStr, CString};
 use std::fmt;
 use std::iter;
 use std::ptr;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// The number of live [`InitializeGuard`]s.
+static INITIALIZE_COUNT: Mutex<usize> = Mutex::new(0);
+
+/// The IDs of the debuggers created by [`SBDebugger::create()`].
+static DEBUGGER_IDS: Mutex<BTreeSet<lldb_user_id_t>> = Mutex::new(BTreeSet::new());
+
+/// Keeps LLDB initialized while it is alive.
+///
+/// This is created by [`SBDebugger::initialize_guard()`].
+#[must_use = "LLDB will be terminated when the guard is dropped"]
+pub struct InitializeGuard {
+    _private: (),
+}
+
+impl Drop for InitializeGuard {
+    fn drop(&mut self) {
+        let mut count = INITIALIZE_COUNT.lock().unwrap_or_else(|e| e.into_inner());
+        *count -= 1;
+        if *count == 0 {
+            SBDebugger::terminate();
+        }
+    }
+}
 
 /// Creates [`SBTarget`]s, provides access to them and manages
 /// the overall debugging experience.
@@ -37,6 +66,19 @@ use std::ptr;
 /// SBDebugger::terminate();
 /// ```
 ///
+/// When several parts of a program, such as tests running in parallel,
+/// each need LLDB, it is easier to use [`SBDebugger::initialize_guard()`].
+/// LLDB is initialized when the first guard is created and terminated
+/// when the last guard is dropped:
+///
+/// ```
+/// use lldb::SBDebugger;
+///
+/// let _guard = SBDebugger::initialize_guard();
+/// // Use LLDB functionality ...
+/// // LLDB is terminated when `_guard` goes out of scope.
+/// ```
+///
 /// Once you've initialized LLDB, you're ready to create an instance
 /// of `SBDebugger`:
 ///
@@ -166,16 +208,129 @@ impl SBDebugger {
         unsafe { sys::SBDebuggerTerminate() };
     }
 
+    /// Initialize LLDB, returning a guard which tears it down again
+    /// when dropped.
+    ///
+    /// Guards are reference counted across all threads: LLDB is only
+    /// initialized by the first guard and only terminated once every
+    /// guard has been dropped. This should not be mixed with calls to
+    /// [`SBDebugger::initialize()`] and [`SBDebugger::terminate()`].
+    pub fn initialize_guard() -> InitializeGuard {
+        let mut count = INITIALIZE_COUNT.lock().unwrap_or_else(|e| e.into_inner());
+        if *count == 0 {
+            SBDebugger::initialize();
+        }
+        *count += 1;
+        InitializeGuard { _private: () }
+    }
+
     /// Create a new instance of `SBDebugger`.
     ///
     /// If `source_init_files` is `true`, then `~/.lldbinit` will
     /// be processed.
     pub fn create(source_init_files: bool) -> SBDebugger {
-        SBDebugger {
+        let debugger = SBDebugger {
             raw: unsafe { sys::SBDebuggerCreate2(source_init_files) },
+        };
+        if unsafe { sys::SBDebuggerIsValid(debugger.raw) } {
+            DEBUGGER_IDS.lock().unwrap().insert(debugger.id());
+        }
+        debugger
+    }
+
+    /// All of the live debugger instances created with
+    /// [`SBDebugger::create()`], in the order that they were created.
+    ///
+    /// LLDB doesn't provide a way to list debuggers, so instances which
+    /// were created in other ways, such as by LLDB itself when running
+    /// the `lldb` driver, are not included.
+    ///
+    /// This is useful for services which embed several debuggers and
+    /// need to route callbacks, which identify the debugger by its
+    /// [ID](SBDebugger::id()), back to the right session.
+    pub fn all() -> Vec<SBDebugger> {
+        // Don't call into LLDB while holding the lock.
+        let ids: Vec<lldb_user_id_t> = DEBUGGER_IDS.lock().unwrap().iter().copied().collect();
+        let mut debuggers = Vec::with_capacity(ids.len());
+        let mut gone = Vec::new();
+        for id in ids {
+            match SBDebugger::find_debugger_with_id(id) {
+                Some(debugger) => debuggers.push(debugger),
+                None => gone.push(id),
+            }
+        }
+        if !gone.is_empty() {
+            let mut ids = DEBUGGER_IDS.lock().unwrap();
+            for id in gone {
+                ids.remove(&id);
+            }
+        }
+        debuggers
+    }
+
+    /// Tear down a debugger instance.
+    ///
+    /// This releases the resources held by the debugger, such as its
+    /// targets, without waiting for every reference to it to be dropped.
+    pub fn destroy(debugger: SBDebugger) {
+        DEBUGGER_IDS.lock().unwrap().remove(&debugger.id());
+        unsafe { sys::SBDebuggerDestroy(debugger.raw) };
+    }
+
+    /// Find a debugger instance by its [ID](SBDebugger::id()).
+    pub fn find_debugger_with_id(id: lldb_user_id_t) -> Option<SBDebugger> {
+        // LLDB takes the ID as an `int`, although it hands them out as
+        // `lldb::user_id_t`.
+        let id = i32::try_from(id).ok()?;
+        let raw = unsafe { sys::SBDebuggerFindDebuggerWithID(id) };
+        if unsafe { sys::SBDebuggerIsValid(raw) } {
+            Some(SBDebugger { raw })
+        } else {
+            unsafe { sys::DisposeSBDebugger(raw) };
+            None
         }
     }
 
+    /// The unique ID of this debugger instance.
+    pub fn id(&self) -> lldb_user_id_t {
+        unsafe { sys::SBDebuggerGetID(self.raw) }
+    }
+
+    /// Send input to the debugger as though it had been typed
+    /// at the console.
+    ///
+    /// This is how an embedding application drives the command
+    /// interpreter started by [`SBDebugger::run_command_interpreter()`].
+    /// Commands must be terminated by a newline.
+    pub fn dispatch_input(&self, input: &str) {
+        unsafe { sys::SBDebuggerDispatchInput2(self.raw, input.as_ptr() as *const _, input.len()) };
+    }
+
+    /// Send an interrupt to the debugger, as though Control-C had been
+    /// typed at the console.
+    pub fn dispatch_input_interrupt(&self) {
+        unsafe { sys::SBDebuggerDispatchInputInterrupt(self.raw) };
+    }
+
+    /// Signal the end of input to the debugger, as though Control-D
+    /// had been typed at the console.
+    pub fn dispatch_input_end_of_file(&self) {
+        unsafe { sys::SBDebuggerDispatchInputEndOfFile(self.raw) };
+    }
+
+    /// Run the interactive command interpreter, as in the `lldb`
+    /// command line tool.
+    ///
+    /// If `auto_handle_events` is `true`, process events are handled
+    /// by the interpreter, printing state changes and process output.
+    ///
+    /// If `spawn_thread` is `true`, the interpreter runs on a new thread
+    /// and this returns immediately. Otherwise, this doesn't return until
+    /// the interpreter exits.
+    pub fn run_command_interpreter(&self, auto_handle_events: bool, spawn_thread: bool) {
+        unsafe { sys::SBDebuggerRunCommandInterpreter(self.raw, auto_handle_events, spawn_thread) };
+    }
+
     /// Get whether or not the debugger is in asynchronous mode.
     ///
     /// When in asynchronous mode, the debugger returns immediately when
@@ -194,6 +349,40 @@ impl SBDebugger {
         unsafe { sys::SBDebuggerSetAsync(self.raw, asynchronous) }
     }
 
+    /// Run `f` with the debugger temporarily in synchronous mode.
+    ///
+    /// The previous mode is restored afterwards, even if `f` panics.
+    /// This is useful for tools which mostly use asynchronous mode but
+    /// occasionally need to perform a quick synchronous operation.
+    ///
+    /// ```no_run
+    /// # use lldb::SBDebugger;
+    /// # fn step(debugger: &SBDebugger) {
+    /// debugger.with_sync_mode(|debugger| {
+    ///     let _ = debugger.execute_command("thread step-over");
+    /// });
+    /// # }
+    /// ```
+    pub fn with_sync_mode<R, F: FnOnce(&SBDebugger) -> R>(&self, f: F) -> R {
+        struct RestoreMode<'d> {
+            debugger: &'d SBDebugger,
+            asynchronous: bool,
+        }
+
+        impl Drop for RestoreMode<'_> {
+            fn drop(&mut self) {
+                self.debugger.set_asynchronous(self.asynchronous);
+            }
+        }
+
+        let _restore = RestoreMode {
+            debugger: self,
+            asynchronous: self.asynchronous(),
+        };
+        self.set_asynchronous(false);
+        f(self)
+    }
+
     #[allow(missing_docs)]
     pub fn command_interpreter(&self) -> SBCommandInterpreter {
         SBCommandInterpreter::wrap(unsafe { sys::SBDebuggerGetCommandInterpreter(self.raw) })
@@ -243,6 +432,10 @@ impl SBDebugger {
     ///
     /// See invocations to `lldb_private::Log::Register` for more channels and
     /// categories.
+    ///
+    /// LLDB can also pass its logs to a callback, but lldb-sys does not
+    /// bind `SBDebugger::SetLoggingCallback` yet, so logs can only be
+    /// sent to `stderr` or, with a `log enable -f` command, to a file.
     pub fn enable_log(&self, channel: &str, categories: &[&str]) -> bool {
         let channel = CString::new(channel).unwrap();
         let categories: Vec<_> = categories
@@ -286,8 +479,8 @@ impl SBDebugger {
             sys::SBDebuggerCreateTarget(
                 self.raw,
                 executable.as_ptr(),
-                target_triple.map_or(ptr::null(), |s| s.as_ptr()),
-                platform_name.map_or(ptr::null(), |s| s.as_ptr()),
+                target_triple.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
+                platform_name.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
                 add_dependent_modules,
                 error.raw,
             )
@@ -299,6 +492,87 @@ impl SBDebugger {
         }
     }
 
+    /// Create an empty target, to which modules can be added with
+    /// [`SBTarget::add_module_spec()`].
+    ///
+    /// If `target_triple` is `None`, the architecture is determined by
+    /// the first module added.
+    pub fn create_empty_target(&self, target_triple: Option<&str>) -> Result<SBTarget, SBError> {
+        self.create_target("", target_triple, None, false)
+    }
+
+    /// Create a target from a module spec, for symbolication workflows
+    /// where the binary may not be on disk.
+    ///
+    /// The spec can identify the module by [UUID] alone, in which case
+    /// LLDB locates it and its debug information through its symbol
+    /// locators, and can name a [symbol file] to use directly. The
+    /// target's triple is taken from the spec, if it has one.
+    ///
+    /// ```no_run
+    /// use lldb::{SBDebugger, SBFileSpec, SBModuleSpec};
+    ///
+    /// fn symbolication_target(debugger: &SBDebugger) -> Option<lldb::SBTarget> {
+    ///     let spec = SBModuleSpec::new();
+    ///     spec.set_uuid_string("E621E1F8-C36C-495A-93FC-0C247A3E6E5F");
+    ///     spec.set_triple("arm64-apple-ios");
+    ///     spec.set_symbol_filespec(&SBFileSpec::from_path("MyApp.app.dSYM", false));
+    ///     debugger.create_target_from_module_spec(&spec).ok()
+    /// }
+    /// ```
+    ///
+    /// [UUID]: SBModuleSpec::set_uuid_bytes()
+    /// [symbol file]: SBModuleSpec::set_symbol_filespec()
+    pub fn create_target_from_module_spec(
+        &self,
+        module_spec: &SBModuleSpec,
+    ) -> Result<SBTarget, SBError> {
+        let target = self.create_empty_target(module_spec.triple())?;
+        if target.add_module_spec(module_spec).is_some() {
+            Ok(target)
+        } else {
+            self.delete_target(&target);
+            Err(SBError::from_message(
+                "can't find a module matching the spec",
+            ))
+        }
+    }
+
+    /// Create a target for symbolication only, without a process.
+    ///
+    /// This is intended for offline symbolication, where a binary is
+    /// only used for address math and symbol lookups. Dependent modules
+    /// are not loaded and no process is needed. If `target_triple` is
+    /// `None`, it is determined from the executable.
+    ///
+    /// The sections of the executable are loaded at their file addresses,
+    /// so load addresses and file addresses are initially the same. If
+    /// the binary was loaded elsewhere, use
+    /// [`SBTarget::set_module_load_address()`] with the slide.
+    ///
+    /// ```no_run
+    /// # use lldb::SBDebugger;
+    /// # fn symbolicate(debugger: &SBDebugger) {
+    /// let target = debugger
+    ///     .create_symbolication_target("/path/to/binary", None)
+    ///     .unwrap();
+    /// if let Some(symbolication) = target.symbolicate(0x1000) {
+    ///     println!("{}", symbolication);
+    /// }
+    /// # }
+    /// ```
+    pub fn create_symbolication_target(
+        &self,
+        executable: &str,
+        target_triple: Option<&str>,
+    ) -> Result<SBTarget, SBError> {
+        let target = self.create_target(executable, target_triple, None, false)?;
+        for module in target.modules() {
+            target.set_module_load_address(&module, 0)?;
+        }
+        Ok(target)
+    }
+
     /// Create a target from just an executable name.
     ///
     /// The executable name may be an empty string to create
@@ -312,6 +586,57 @@ impl SBDebugger {
         SBTarget::maybe_wrap(unsafe { sys::SBDebuggerCreateTarget2(self.raw, executable.as_ptr()) })
     }
 
+    /// Create a target for `executable` using the architecture named
+    /// `arch`, such as `"x86_64"` or `"arm64"`.
+    ///
+    /// This selects one slice of a universal binary. The architecture
+    /// may also be `"systemArch"` or `"systemArch64"` for those of the
+    /// host.
+    pub fn create_target_with_file_and_arch(
+        &self,
+        executable: &str,
+        arch: &str,
+    ) -> Option<SBTarget> {
+        let executable = CString::new(executable).unwrap();
+        let arch = CString::new(arch).unwrap();
+        SBTarget::maybe_wrap(unsafe {
+            sys::SBDebuggerCreateTargetWithFileAndArch(self.raw, executable.as_ptr(), arch.as_ptr())
+        })
+    }
+
+    /// Create a target for `executable` using the given target triple,
+    /// such as `"x86_64-apple-macosx"`.
+    ///
+    /// See [`SBDebugger::create_target()`] to also find out why the
+    /// target couldn't be created.
+    pub fn create_target_with_file_and_triple(
+        &self,
+        executable: &str,
+        target_triple: &str,
+    ) -> Option<SBTarget> {
+        let executable = CString::new(executable).unwrap();
+        let target_triple = CString::new(target_triple).unwrap();
+        SBTarget::maybe_wrap(unsafe {
+            sys::SBDebuggerCreateTargetWithFileAndTargetTriple(
+                self.raw,
+                executable.as_ptr(),
+                target_triple.as_ptr(),
+            )
+        })
+    }
+
+    /// Delete `target` from this debugger, returning whether it was
+    /// found.
+    ///
+    /// Targets stay in the debugger's target list until they are
+    /// deleted, even once every `SBTarget` referring to them has been
+    /// dropped, so long-lived tools should delete the targets they no
+    /// longer need. Any process of the target is killed or detached
+    /// first.
+    pub fn delete_target(&self, target: &SBTarget) -> bool {
+        unsafe { sys::SBDebuggerDeleteTarget(self.raw, target.raw) }
+    }
+
     /// Get an iterator over the [targets] known to this debugger instance.
     ///
     /// [targets]: SBTarget
@@ -327,6 +652,47 @@ impl SBDebugger {
         SBListener::wrap(unsafe { sys::SBDebuggerGetListener(self.raw) })
     }
 
+    /// The broadcaster for the debugger's own events, such as
+    /// [progress reports].
+    ///
+    /// [progress reports]: Progress
+    pub fn broadcaster(&self) -> SBBroadcaster {
+        SBBroadcaster::wrap(unsafe { sys::SBDebuggerGetBroadcaster(self.raw) })
+    }
+
+    /// The name of the broadcaster class of debuggers.
+    pub fn broadcaster_class_name() -> &'static str {
+        unsafe {
+            match CStr::from_ptr(sys::SBDebuggerGetBroadcasterClass()).to_str() {
+                Ok(s) => s,
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// Create a listener for this debugger's [progress reports].
+    ///
+    /// [progress reports]: Progress
+    pub fn progress_listener(&self) -> SBListener {
+        let listener = SBListener::new();
+        listener.start_listening_for_events(&self.broadcaster(), Self::BROADCAST_BIT_PROGRESS);
+        listener
+    }
+
+    /// The progress report carried by `event`, if it is one.
+    pub fn event_as_progress_event(event: &SBEvent) -> Option<Progress> {
+        Progress::from_event(event)
+    }
+
+    /// The event type of [progress reports].
+    ///
+    /// [progress reports]: Progress
+    pub const BROADCAST_BIT_PROGRESS: u32 = 1 << 0;
+    /// The event type of warnings reported by the debugger.
+    pub const BROADCAST_BIT_WARNING: u32 = 1 << 1;
+    /// The event type of errors reported by the debugger.
+    pub const BROADCAST_BIT_ERROR: u32 = 1 << 2;
+
     /// Get the currently selected [`SBTarget`].
     pub fn selected_target(&self) -> Option<SBTarget> {
         SBTarget::maybe_wrap(unsafe { sys::SBDebuggerGetSelectedTarget(self.raw) })
@@ -337,6 +703,40 @@ impl SBDebugger {
         unsafe { sys::SBDebuggerSetSelectedTarget(self.raw, target.raw) };
     }
 
+    /// Save the targets of this debugger, along with their breakpoints
+    /// and watchpoints, and the selected platform, so that they can be
+    /// recreated later with [`SBDebugger::restore_session()`].
+    ///
+    /// The session can be stored with [`SBStructuredData::get_as_json()`]
+    /// and read back with [`SBStructuredData::from_json()`].
+    ///
+    /// ```no_run
+    /// # use lldb::{SBDebugger, SBStructuredData};
+    /// # fn restart(old: &SBDebugger, new: &SBDebugger) {
+    /// let json = old.save_session().unwrap().get_as_json().unwrap();
+    /// std::fs::write("session.json", json.data()).unwrap();
+    ///
+    /// let json = std::fs::read_to_string("session.json").unwrap();
+    /// let session = SBStructuredData::from_json(&json).unwrap();
+    /// new.restore_session(&session).unwrap();
+    /// # }
+    /// ```
+    pub fn save_session(&self) -> Result<SBStructuredData, SBError> {
+        session::save(self)
+    }
+
+    /// Recreate the targets and breakpoints of a session saved with
+    /// [`SBDebugger::save_session()`], returning the new targets.
+    ///
+    /// The saved platform is made current, but remote platforms must
+    /// still be connected. Watchpoints can only be set in a running
+    /// process, so they are restored separately with
+    /// [`SBTarget::restore_watchpoints()`] once a process has been
+    /// launched or attached.
+    pub fn restore_session(&self, session: &SBStructuredData) -> Result<Vec<SBTarget>, SBError> {
+        session::restore(self, session)
+    }
+
     /// Get an iterator over the currently active [platforms][SBPlatform].
     ///
     /// By default, the `host` platform will be active. Additional
@@ -431,6 +831,89 @@ impl SBDebugger {
         unsafe { sys::SBDebuggerSetCurrentPlatformSDKRoot(self.raw, sysroot.as_ptr()) };
     }
 
+    /// The name of this debugger instance.
+    ///
+    /// This is used to identify the debugger when getting and setting
+    /// settings.
+    pub fn instance_name(&self) -> &str {
+        unsafe {
+            match CStr::from_ptr(sys::SBDebuggerGetInstanceName(self.raw)).to_str() {
+                Ok(s) => s,
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// Get the value of a setting, as would be shown by `settings show`.
+    ///
+    /// Settings with multiple values will have one value per line.
+    pub fn setting(&self, name: &str) -> Option<String> {
+        let name = CString::new(name).unwrap();
+        let instance_name = CString::new(self.instance_name()).unwrap();
+        let values = SBStringList::wrap(unsafe {
+            sys::SBDebuggerGetInternalVariableValue(name.as_ptr(), instance_name.as_ptr())
+        });
+        if values.is_empty() {
+            None
+        } else {
+            Some(values.iter().collect::<Vec<_>>().join("\n"))
+        }
+    }
+
+    /// Set the value of a setting, as would be done by `settings set`.
+    pub fn set_setting(&self, name: &str, value: &str) -> Result<(), SBError> {
+        let name = CString::new(name).unwrap();
+        let value = CString::new(value).unwrap();
+        let instance_name = CString::new(self.instance_name()).unwrap();
+        SBError::wrap(unsafe {
+            sys::SBDebuggerSetInternalVariable(
+                name.as_ptr(),
+                value.as_ptr(),
+                instance_name.as_ptr(),
+            )
+        })
+        .into_result()
+    }
+
+    /// How long to wait for a process to stop after it has been
+    /// interrupted.
+    ///
+    /// This is the `target.process.interrupt-timeout` setting.
+    pub fn interrupt_timeout(&self) -> Option<Duration> {
+        self.setting("target.process.interrupt-timeout")?
+            .trim()
+            .parse()
+            .ok()
+            .map(Duration::from_secs)
+    }
+
+    /// Set how long to wait for a process to stop after it has been
+    /// interrupted. This is rounded up to a whole number of seconds.
+    ///
+    /// This is the `target.process.interrupt-timeout` setting.
+    pub fn set_interrupt_timeout(&self, timeout: Duration) -> Result<(), SBError> {
+        let secs = timeout.as_secs() + u64::from(timeout.subsec_nanos() > 0);
+        self.set_setting("target.process.interrupt-timeout", &secs.to_string())
+    }
+
+    /// The default syntax used when disassembling x86 code with
+    /// [`DisassemblyFlavor::Default`].
+    ///
+    /// This is the `target.x86-disassembly-flavor` setting.
+    pub fn disassembly_flavor(&self) -> DisassemblyFlavor {
+        self.setting("target.x86-disassembly-flavor")
+            .and_then(|flavor| DisassemblyFlavor::from_name(flavor.trim()))
+            .unwrap_or(DisassemblyFlavor::Default)
+    }
+
+    /// Set the default syntax used when disassembling x86 code with
+    /// [`DisassemblyFlavor::Default`].
+    ///
+    /// This is the `target.x86-disassembly-flavor` setting.
+    pub fn set_disassembly_flavor(&self, flavor: DisassemblyFlavor) -> Result<(), SBError> {
+        self.set_setting("target.x86-disassembly-flavor", flavor.name())
+    }
+
     #[allow(missing_docs)]
     pub fn set_use_external_editor(&self, use_external_editor: bool) {
         unsafe { sys::SBDebuggerSetUseExternalEditor(self.raw, use_external_editor) };
@@ -441,16 +924,50 @@ impl SBDebugger {
         unsafe { sys::SBDebuggerGetUseExternalEditor(self.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// Set whether the debugger's output, such as command results and
+    /// the prompt, is colored with ANSI escape sequences.
     pub fn set_use_color(&self, use_color: bool) {
         unsafe { sys::SBDebuggerSetUseColor(self.raw, use_color) };
     }
 
-    #[allow(missing_docs)]
-    pub fn get_use_color(&self) -> bool {
+    /// Is the debugger's output colored with ANSI escape sequences?
+    pub fn use_color(&self) -> bool {
         unsafe { sys::SBDebuggerGetUseColor(self.raw) }
     }
 
+    /// Is the debugger's output colored with ANSI escape sequences?
+    pub fn get_use_color(&self) -> bool {
+        self.use_color()
+    }
+
+    /// The width of the terminal, in columns, which the debugger wraps
+    /// and truncates its output to fit.
+    pub fn terminal_width(&self) -> u32 {
+        unsafe { sys::SBDebuggerGetTerminalWidth(self.raw) }
+    }
+
+    /// Set the width of the terminal, in columns, which the debugger
+    /// wraps and truncates its output to fit.
+    pub fn set_terminal_width(&self, width: u32) {
+        unsafe { sys::SBDebuggerSetTerminalWidth(self.raw, width) };
+    }
+
+    /// The prompt shown by the command interpreter, such as `"(lldb) "`.
+    pub fn prompt(&self) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(sys::SBDebuggerGetPrompt(self.raw).as_ref()?).to_str() {
+                Ok(s) => Some(s),
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// Set the prompt shown by the command interpreter.
+    pub fn set_prompt(&self, prompt: &str) {
+        let prompt = CString::new(prompt).unwrap();
+        unsafe { sys::SBDebuggerSetPrompt(self.raw, prompt.as_ptr()) };
+    }
+
     #[allow(missing_docs)]
     pub fn set_use_source_cache(&self, use_source_cache: bool) {
         unsafe { sys::SBDebuggerSetUseSourceCache(self.raw, use_source_cache) };
@@ -460,8 +977,89 @@ impl SBDebugger {
     pub fn get_use_source_cache(&self) -> bool {
         unsafe { sys::SBDebuggerGetUseSourceCache(self.raw) }
     }
+
+    /// Look up the data formatter [category] with the given name.
+    ///
+    /// [category]: SBTypeCategory
+    pub fn get_category(&self, name: &str) -> Option<SBTypeCategory> {
+        let name = CString::new(name).unwrap();
+        SBTypeCategory::maybe_wrap(unsafe { sys::SBDebuggerGetCategory(self.raw, name.as_ptr()) })
+    }
+
+    /// Create a new, disabled data formatter [category] with the given name.
+    ///
+    /// If a category with that name already exists, it is returned.
+    ///
+    /// [category]: SBTypeCategory
+    pub fn create_category(&self, name: &str) -> Option<SBTypeCategory> {
+        let name = CString::new(name).unwrap();
+        SBTypeCategory::maybe_wrap(unsafe {
+            sys::SBDebuggerCreateCategory(self.raw, name.as_ptr())
+        })
+    }
+
+    /// Delete the data formatter [category] with the given name.
+    ///
+    /// Returns `false` if there was no such category.
+    ///
+    /// [category]: SBTypeCategory
+    pub fn delete_category(&self, name: &str) -> bool {
+        let name = CString::new(name).unwrap();
+        unsafe { sys::SBDebuggerDeleteCategory(self.raw, name.as_ptr()) }
+    }
+
+    /// The `default` data formatter [category], which is used by
+    /// `type summary add` and friends when no category is given.
+    ///
+    /// [category]: SBTypeCategory
+    pub fn default_category(&self) -> Option<SBTypeCategory> {
+        SBTypeCategory::maybe_wrap(unsafe { sys::SBDebuggerGetDefaultCategory(self.raw) })
+    }
+
+    /// Get an iterator over the data formatter [categories] known to
+    /// this debugger instance.
+    ///
+    /// [categories]: SBTypeCategory
+    pub fn categories(&self) -> SBDebuggerCategoryIter<'_> {
+        SBDebuggerCategoryIter {
+            debugger: self,
+            idx: 0,
+        }
+    }
+}
+
+/// Iterate over the data formatter [categories] known to a [debugger].
+///
+/// [categories]: SBTypeCategory
+/// [debugger]: SBDebugger
+pub struct SBDebuggerCategoryIter<'d> {
+    debugger: &'d SBDebugger,
+    idx: u32,
+}
+
+impl Iterator for SBDebuggerCategoryIter<'_> {
+    type Item = SBTypeCategory;
+
+    fn next(&mut self) -> Option<SBTypeCategory> {
+        if self.idx < unsafe { sys::SBDebuggerGetNumCategories(self.debugger.raw) } {
+            let r = Some(SBTypeCategory::wrap(unsafe {
+                sys::SBDebuggerGetCategoryAtIndex(self.debugger.raw, self.idx)
+            }));
+            self.idx += 1;
+            r
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let sz = unsafe { sys::SBDebuggerGetNumCategories(self.debugger.raw) } as usize;
+        (sz - self.idx as usize, Some(sz))
+    }
 }
 
+impl ExactSizeIterator for SBDebuggerCategoryIter<'_> {}
+
 /// Iterate over the [targets] known to a [debugger].
 ///
 /// [targets]: SBTarget
@@ -0,0 +1,150 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::SBDebugger;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+type Request = Box<dyn FnOnce(&SBDebugger) + Send>;
+
+/// A debugger owned by a dedicated thread, which is driven by sending
+/// it requests.
+///
+/// LLDB's API has threading constraints which aren't reflected in the
+/// `Send` and `Sync` implementations of the wrapper types in this crate,
+/// and concurrent use of a debugger from several threads can crash it.
+/// A `DebuggerHandle` avoids this by running every request on the same
+/// thread, one at a time, in the order that they were sent. The handle
+/// itself can be shared between threads, such as the UI thread and
+/// worker threads of a GUI or the connections of a server.
+///
+/// The `Send` and `Sync` implementations themselves are unchanged:
+/// auditing them type by type, and removing the ones which aren't
+/// sound, would break existing code and is left for separate work.
+/// Until then, this is the supported way to use a debugger from more
+/// than one thread.
+///
+/// Requests are closures which are given the debugger. Their results
+/// are sent back through a [`Reply`]. Results are best kept to plain
+/// data, as any LLDB objects which they contain are no longer confined
+/// to the debugger's thread.
+///
+/// ```no_run
+/// use lldb::DebuggerHandle;
+///
+/// let handle = DebuggerHandle::spawn(false).unwrap();
+/// let triple = handle.call(|debugger| {
+///     let target = debugger.create_target_simple("/bin/ls")?;
+///     target.triple().map(String::from)
+/// });
+/// println!("{:?}", triple);
+/// ```
+///
+/// The debugger is destroyed, on its thread, when the handle is
+/// dropped, after any requests that are still queued have run.
+pub struct DebuggerHandle {
+    sender: Option<mpsc::Sender<Request>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl DebuggerHandle {
+    /// Start a thread which creates and owns a new debugger.
+    ///
+    /// LLDB is initialized as needed. If `source_init_files` is `true`,
+    /// then `~/.lldbinit` will be processed. See [`SBDebugger::create()`].
+    pub fn spawn(source_init_files: bool) -> io::Result<DebuggerHandle> {
+        let (sender, receiver) = mpsc::channel::<Request>();
+        let thread = thread::Builder::new()
+            .name("lldb-debugger".into())
+            .spawn(move || {
+                let _guard = SBDebugger::initialize_guard();
+                let debugger = SBDebugger::create(source_init_files);
+                for request in receiver {
+                    // A panicking request drops its reply sender, which
+                    // the caller sees, but shouldn't take down the
+                    // debugger for everyone else.
+                    let _ = panic::catch_unwind(AssertUnwindSafe(|| request(&debugger)));
+                }
+                SBDebugger::destroy(debugger);
+            })?;
+        Ok(DebuggerHandle {
+            sender: Some(sender),
+            thread: Some(thread),
+        })
+    }
+
+    /// Queue `request` to run on the debugger's thread, returning a
+    /// [`Reply`] through which its result can be received.
+    pub fn send<F, R>(&self, request: F) -> Reply<R>
+    where
+        F: FnOnce(&SBDebugger) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let (sender, receiver) = mpsc::sync_channel(1);
+        if let Some(requests) = &self.sender {
+            let _ = requests.send(Box::new(move |debugger: &SBDebugger| {
+                let _ = sender.send(request(debugger));
+            }));
+        }
+        Reply { receiver }
+    }
+
+    /// Run `request` on the debugger's thread and wait for its result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request panicked.
+    pub fn call<F, R>(&self, request: F) -> R
+    where
+        F: FnOnce(&SBDebugger) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        self.send(request).wait()
+    }
+}
+
+impl Drop for DebuggerHandle {
+    fn drop(&mut self) {
+        // Closing the channel ends the thread's loop once the queued
+        // requests have run.
+        self.sender.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// The pending result of a request sent to a [`DebuggerHandle`].
+pub struct Reply<R> {
+    receiver: mpsc::Receiver<R>,
+}
+
+impl<R> Reply<R> {
+    /// Wait for the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the request panicked.
+    pub fn wait(self) -> R {
+        self.receiver.recv().expect("debugger request panicked")
+    }
+
+    /// Wait up to `timeout` for the result.
+    ///
+    /// Returns `None` if the request hasn't finished yet or if it
+    /// panicked.
+    pub fn wait_timeout(&self, timeout: Duration) -> Option<R> {
+        self.receiver.recv_timeout(timeout).ok()
+    }
+
+    /// Get the result if the request has finished, without waiting.
+    pub fn try_get(&self) -> Option<R> {
+        self.receiver.try_recv().ok()
+    }
+}
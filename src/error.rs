@@ -6,7 +6,10 @@
 
 use crate::{sys, ErrorType, SBStream};
 use std::fmt;
-use std::{error::Error, ffi::CStr};
+use std::{
+    error::Error,
+    ffi::{CStr, CString},
+};
 
 /// A container for holding any error code and an error message.
 ///
@@ -39,6 +42,22 @@ impl SBError {
         }
     }
 
+    /// Construct a new `SBError` representing a failure with the
+    /// given message.
+    ///
+    /// ```
+    /// # use lldb::SBError;
+    /// let e = SBError::from_message("something went wrong");
+    /// assert!(e.is_failure());
+    /// assert_eq!(e.error_string(), "something went wrong");
+    /// ```
+    pub fn from_message(message: &str) -> SBError {
+        let error = SBError::default();
+        let message = CString::new(message).unwrap();
+        unsafe { sys::SBErrorSetErrorString(error.raw, message.as_ptr()) };
+        error
+    }
+
     /// Does this error represent a success?
     ///
     /// An error starts out in the success state by default:
@@ -4,7 +4,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::{sys, SBBroadcaster, SBStream};
+use crate::{sys, SBBroadcaster, SBError, SBStream, SBStructuredData};
 use std::ffi::CStr;
 use std::fmt;
 
@@ -35,6 +35,30 @@ impl SBEvent {
         Self::wrap(unsafe { sys::CreateSBEvent() })
     }
 
+    /// Construct a new `SBEvent` of the given type carrying a string.
+    ///
+    /// The string can be read back with [`SBEvent::data_as_string()`]
+    /// after the event has been sent with
+    /// [`SBBroadcaster::broadcast_event()`].
+    pub fn with_string(event_type: u32, data: &str) -> SBEvent {
+        Self::wrap(unsafe {
+            sys::CreateSBEvent2(event_type, data.as_ptr() as *const _, data.len() as u32)
+        })
+    }
+
+    /// Construct a new `SBEvent` of the given type carrying structured
+    /// data.
+    ///
+    /// LLDB events can only carry strings, so the data is sent as JSON.
+    /// It can be read back with [`SBEvent::data_as_structured_data()`].
+    pub fn with_structured_data(
+        event_type: u32,
+        data: &SBStructuredData,
+    ) -> Result<SBEvent, SBError> {
+        let json = data.get_as_json()?;
+        Ok(SBEvent::with_string(event_type, json.data()))
+    }
+
     /// Check whether or not this is a valid `SBEvent` value.
     pub fn is_valid(&self) -> bool {
         unsafe { sys::SBEventIsValid(self.raw) }
@@ -50,12 +74,32 @@ impl SBEvent {
         }
     }
 
-    #[allow(missing_docs)]
+    /// The string carried by this event, if it has one.
+    ///
+    /// This is set for events created with [`SBEvent::with_string()`]
+    /// as well as some events sent by LLDB itself.
+    pub fn data_as_string(&self) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(sys::SBEventGetCStringFromEvent(self.raw).as_ref()?).to_str() {
+                Ok(s) => Some(s),
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// The structured data carried by this event, if it was created
+    /// with [`SBEvent::with_structured_data()`].
+    pub fn data_as_structured_data(&self) -> Option<SBStructuredData> {
+        SBStructuredData::from_json(self.data_as_string()?).ok()
+    }
+
+    /// The type of this event, which is a bit in the event mask of its
+    /// broadcaster.
     pub fn event_type(&self) -> u32 {
         unsafe { sys::SBEventGetType(self.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// The broadcaster which sent this event.
     pub fn broadcaster(&self) -> SBBroadcaster {
         SBBroadcaster::wrap(unsafe { sys::SBEventGetBroadcaster(self.raw) })
     }
@@ -4,7 +4,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::sys;
+use crate::{sys, LanguageType};
+use std::time::Duration;
 
 #[allow(missing_docs)]
 #[derive(Debug)]
@@ -43,6 +44,133 @@ impl SBExpressionOptions {
     pub fn set_ignore_breakpoints(&self, ignore: bool) {
         unsafe { sys::SBExpressionOptionsSetIgnoreBreakpoints(self.raw, ignore) };
     }
+
+    /// How long to let an expression run before giving up.
+    ///
+    /// `None` means that the expression may run forever.
+    pub fn timeout(&self) -> Option<Duration> {
+        micros_to_timeout(unsafe { sys::SBExpressionOptionsGetTimeoutInMicroSeconds(self.raw) })
+    }
+
+    /// How long to let an expression run before giving up.
+    ///
+    /// `None` means that the expression may run forever. Timeouts are
+    /// limited to a little over an hour.
+    ///
+    /// To cancel an evaluation on demand instead, see
+    /// [`SBProcess::send_async_interrupt()`].
+    ///
+    /// [`SBProcess::send_async_interrupt()`]: crate::SBProcess::send_async_interrupt()
+    pub fn set_timeout(&self, timeout: Option<Duration>) {
+        unsafe {
+            sys::SBExpressionOptionsSetTimeoutInMicroSeconds(self.raw, timeout_to_micros(timeout))
+        };
+    }
+
+    /// How long to let an expression run on only the current thread
+    /// before also running the other threads.
+    ///
+    /// This only applies when [`try_all_threads`] is set. `None` means
+    /// that a default is chosen based on the overall [`timeout`].
+    ///
+    /// [`try_all_threads`]: SBExpressionOptions::try_all_threads()
+    /// [`timeout`]: SBExpressionOptions::timeout()
+    pub fn one_thread_timeout(&self) -> Option<Duration> {
+        micros_to_timeout(unsafe {
+            sys::SBExpressionOptionsGetOneThreadTimeoutInMicroSeconds(self.raw)
+        })
+    }
+
+    /// How long to let an expression run on only the current thread
+    /// before also running the other threads.
+    ///
+    /// See [`SBExpressionOptions::one_thread_timeout()`].
+    pub fn set_one_thread_timeout(&self, timeout: Option<Duration>) {
+        unsafe {
+            sys::SBExpressionOptionsSetOneThreadTimeoutInMicroSeconds(
+                self.raw,
+                timeout_to_micros(timeout),
+            )
+        };
+    }
+
+    /// Whether to run the other threads if the expression doesn't
+    /// complete on the current thread alone.
+    ///
+    /// This helps avoid deadlocks when the expression needs a lock
+    /// held by another thread.
+    pub fn try_all_threads(&self) -> bool {
+        unsafe { sys::SBExpressionOptionsGetTryAllThreads(self.raw) }
+    }
+
+    /// Whether to run the other threads if the expression doesn't
+    /// complete on the current thread alone.
+    pub fn set_try_all_threads(&self, run_others: bool) {
+        unsafe { sys::SBExpressionOptionsSetTryAllThreads(self.raw, run_others) };
+    }
+
+    /// Set the language used to parse the expression.
+    ///
+    /// By default, the language of the frame that the expression is
+    /// evaluated in is used.
+    pub fn set_language(&self, language: LanguageType) {
+        unsafe { sys::SBExpressionOptionsSetLanguage(self.raw, language) };
+    }
+
+    /// Whether Objective-C object results are given the type `id`.
+    pub fn coerce_result_to_id(&self) -> bool {
+        unsafe { sys::SBExpressionOptionsGetCoerceResultToId(self.raw) }
+    }
+
+    /// Whether Objective-C object results are given the type `id`.
+    pub fn set_coerce_result_to_id(&self, coerce: bool) {
+        unsafe { sys::SBExpressionOptionsSetCoerceResultToId(self.raw, coerce) };
+    }
+
+    /// Whether to skip saving the result in a persistent variable,
+    /// such as `$0`.
+    pub fn suppress_persistent_result(&self) -> bool {
+        unsafe { sys::SBExpressionOptionsGetSuppressPersistentResult(self.raw) }
+    }
+
+    /// Whether to skip saving the result in a persistent variable,
+    /// such as `$0`.
+    ///
+    /// Tools that evaluate many expressions on behalf of the user, such
+    /// as to display watched values, should usually suppress these so
+    /// that the user's own results aren't renumbered.
+    pub fn set_suppress_persistent_result(&self, suppress: bool) {
+        unsafe { sys::SBExpressionOptionsSetSuppressPersistentResult(self.raw, suppress) };
+    }
+
+    /// Whether to generate debug information for the expression, so
+    /// that it can be stepped through.
+    pub fn generate_debug_info(&self) -> bool {
+        unsafe { sys::SBExpressionOptionsGetGenerateDebugInfo(self.raw) }
+    }
+
+    /// Whether to generate debug information for the expression, so
+    /// that it can be stepped through.
+    pub fn set_generate_debug_info(&self, generate: bool) {
+        unsafe { sys::SBExpressionOptionsSetGenerateDebugInfo(self.raw, generate) };
+    }
+}
+
+fn micros_to_timeout(micros: u32) -> Option<Duration> {
+    if micros == 0 {
+        None
+    } else {
+        Some(Duration::from_micros(u64::from(micros)))
+    }
+}
+
+fn timeout_to_micros(timeout: Option<Duration>) -> u32 {
+    match timeout {
+        // A zero timeout would mean no timeout, so use the shortest
+        // possible one instead.
+        Some(timeout) => timeout.as_micros().clamp(1, u128::from(u32::MAX)) as u32,
+        None => 0,
+    }
 }
 
 impl Clone for SBExpressionOptions {
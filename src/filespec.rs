@@ -70,6 +70,26 @@ impl SBFileSpec {
             }
         }
     }
+
+    /// The full path, combining the directory and file name.
+    pub fn path(&self) -> String {
+        let mut buffer = vec![0u8; 1024];
+        loop {
+            let len = unsafe {
+                sys::SBFileSpecGetPath(self.raw, buffer.as_mut_ptr() as *mut _, buffer.len())
+            } as usize;
+            // The path is truncated to fit the buffer, so try again with
+            // a larger buffer if it was filled.
+            if len + 1 < buffer.len() {
+                buffer.truncate(len);
+                return match String::from_utf8(buffer) {
+                    Ok(s) => s,
+                    _ => panic!("Invalid string?"),
+                };
+            }
+            buffer.resize(buffer.len() * 2, 0);
+        }
+    }
 }
 
 impl Clone for SBFileSpec {
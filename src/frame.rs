@@ -4,10 +4,12 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use crate::framematcher::glob_match;
 use crate::{
-    lldb_addr_t, sys, SBAddress, SBBlock, SBCompileUnit, SBExpressionOptions, SBFunction,
-    SBLineEntry, SBModule, SBStream, SBSymbol, SBSymbolContext, SBThread, SBValue, SBValueList,
-    SBVariablesOptions,
+    lldb_addr_t, registers, sys, DisassemblyFlavor, FrameMatcher, LanguageType, SBAddress, SBBlock,
+    SBCompileUnit, SBError, SBExpressionOptions, SBFunction, SBInstructionList, SBLineEntry,
+    SBModule, SBStream, SBSymbol, SBSymbolContext, SBThread, SBValue, SBValueList,
+    SBVariablesOptions, ValueType, VariablesOptionsBuilder, LLDB_INVALID_ADDRESS,
 };
 use std::ffi::{CStr, CString};
 use std::fmt;
@@ -53,7 +55,7 @@ impl SBFrame {
     /// lifetime of the function.
     pub fn cfa(&self) -> Option<lldb_addr_t> {
         let cfa = unsafe { sys::SBFrameGetCFA(self.raw) };
-        if cfa != u64::MAX {
+        if cfa != LLDB_INVALID_ADDRESS {
             Some(cfa)
         } else {
             None
@@ -156,12 +158,99 @@ impl SBFrame {
         }
     }
 
+    /// Is this the [selected frame] of its thread?
+    ///
+    /// This doesn't consider whether the thread itself is selected;
+    /// see [`SBThread::is_selected()`] for that.
+    ///
+    /// [selected frame]: SBThread::selected_frame()
+    pub fn is_selected(&self) -> bool {
+        self.is_valid()
+            && unsafe { sys::SBFrameIsEqual(self.raw, self.thread().selected_frame().raw) }
+    }
+
     /// Return `true` if this frame represents an inlined function.
     pub fn is_inlined(&self) -> bool {
         unsafe { sys::SBFrameIsInlined(self.raw) }
     }
 
+    /// Return `true` if this frame was synthesized by LLDB rather than
+    /// unwound from the stack, such as a frame for a tail call.
+    ///
+    /// Backtrace presentations will often want to hide these frames.
+    pub fn is_artificial(&self) -> bool {
+        unsafe { sys::SBFrameIsArtificial(self.raw) }
+    }
+
+    /// The language of the compile unit for this frame, if there is
+    /// debug information for it.
+    ///
+    /// See also [`SBFrame::guess_language()`].
+    pub fn language(&self) -> Option<LanguageType> {
+        let compile_unit = self.compile_unit();
+        if compile_unit.is_valid() {
+            match compile_unit.language() {
+                LanguageType::Unknown => None,
+                language => Some(language),
+            }
+        } else {
+            None
+        }
+    }
+
+    /// The language of this frame, as guessed by LLDB.
+    ///
+    /// Unlike [`SBFrame::language()`], this also considers the function
+    /// and symbol names, so it can identify the language of frames
+    /// without debug information, such as those in the C++ standard
+    /// library.
+    pub fn guess_language(&self) -> LanguageType {
+        unsafe { sys::SBFrameGuessLanguage(self.raw) }
+    }
+
+    /// Return `true` if this frame is executing code in `module`.
+    pub fn is_in_module(&self, module: &SBModule) -> bool {
+        let frame_module = self.module();
+        if !frame_module.is_valid() || !module.is_valid() {
+            return false;
+        }
+        let uuid = |m: &SBModule| unsafe {
+            sys::SBModuleGetUUIDString(m.raw)
+                .as_ref()
+                .map(|p| CStr::from_ptr(p).to_bytes().to_vec())
+        };
+        frame_module.filespec().path() == module.filespec().path()
+            && uuid(&frame_module) == uuid(module)
+    }
+
+    /// Return `true` if the function for this frame has a name matching
+    /// the shell-style glob `pattern`.
+    ///
+    /// Both the [full function name] and the [display name] are tried,
+    /// so `"main"` and `"std::*"` match as expected for C++ and Rust
+    /// functions whose full names include argument lists or hashes.
+    ///
+    /// [full function name]: SBFrame::function_name()
+    /// [display name]: SBFrame::display_function_name()
+    pub fn is_in_function_named(&self, pattern: &str) -> bool {
+        self.function_name()
+            .into_iter()
+            .chain(self.display_function_name())
+            .any(|name| glob_match(pattern, name))
+    }
+
+    /// Return `true` if this frame satisfies `matcher`.
+    pub fn matches(&self, matcher: &FrameMatcher) -> bool {
+        matcher.matches(self)
+    }
+
     /// Evaluate an expression within the context of this frame.
+    ///
+    /// Evaluations that run code in the target can be limited with
+    /// [`SBExpressionOptions::set_timeout()`] or cancelled from another
+    /// thread with [`SBProcess::send_async_interrupt()`].
+    ///
+    /// [`SBProcess::send_async_interrupt()`]: crate::SBProcess::send_async_interrupt()
     pub fn evaluate_expression(&self, expression: &str, options: &SBExpressionOptions) -> SBValue {
         let expression = CString::new(expression).unwrap();
         SBValue::wrap(unsafe {
@@ -196,57 +285,180 @@ impl SBFrame {
     }
 
     /// The disassembly of this function, presented as a string.
-    pub fn disassemble(&self) -> &str {
+    ///
+    /// This will be `None` when there is nothing to disassemble, such as
+    /// when the frame is not within a known function or symbol.
+    ///
+    /// See also:
+    /// - [`SBFrame::disassemble_with_flavor()`]
+    /// - [`SBFrame::instructions()`]
+    pub fn disassemble(&self) -> Option<&str> {
         unsafe {
-            match CStr::from_ptr(sys::SBFrameDisassemble(self.raw)).to_str() {
-                Ok(s) => s,
+            match CStr::from_ptr(sys::SBFrameDisassemble(self.raw).as_ref()?).to_str() {
+                Ok(s) => Some(s),
                 _ => panic!("Invalid string?"),
             }
         }
     }
 
+    /// The disassembly of this function using the given flavor,
+    /// presented as a string.
+    ///
+    /// This will be `None` when there is nothing to disassemble, such as
+    /// when the frame is not within a known function or symbol.
+    ///
+    /// See also:
+    /// - [`SBFrame::disassemble()`]
+    /// - [`SBFrame::instructions()`]
+    pub fn disassemble_with_flavor(&self, flavor: DisassemblyFlavor) -> Option<String> {
+        let instructions = self.instructions(flavor)?;
+        let stream = SBStream::new();
+        unsafe { sys::SBInstructionListGetDescription(instructions.raw, stream.raw) };
+        Some(stream.data().to_string())
+    }
+
+    /// The instructions of this function, for frontends which render
+    /// the disassembly themselves.
+    ///
+    /// If there is no debug information for the function, the
+    /// instructions of the symbol containing the frame are used instead.
+    ///
+    /// This will be `None` when there is nothing to disassemble, such as
+    /// when the frame is not within a known function or symbol.
+    pub fn instructions(&self, flavor: DisassemblyFlavor) -> Option<SBInstructionList> {
+        let target = self.thread().process().target()?;
+        let function = self.function();
+        let instructions = if function.is_valid() {
+            function.get_instructions(&target, flavor)
+        } else {
+            let symbol = self.symbol();
+            if !symbol.is_valid() {
+                return None;
+            }
+            symbol.get_instructions(&target, flavor)
+        };
+        if instructions.is_valid() && !instructions.is_empty() {
+            Some(instructions)
+        } else {
+            None
+        }
+    }
+
+    /// A window of instructions around the pc of this frame, for the
+    /// instruction view of a debugger frontend.
+    ///
+    /// The window holds up to `before_pc` instructions before the one
+    /// at the pc, that instruction and up to `after_pc` instructions
+    /// after it. It is cut short at the bounds of the function or
+    /// symbol containing the frame.
+    ///
+    /// Instructions before the pc can only be found when the frame is
+    /// within a known function or symbol, since x86 instructions can't
+    /// be decoded backwards. Otherwise, the window starts at the pc.
+    ///
+    /// This will be `None` when there is nothing to disassemble.
+    pub fn disassemble_range(
+        &self,
+        before_pc: u32,
+        after_pc: u32,
+        flavor: DisassemblyFlavor,
+    ) -> Option<SBInstructionList> {
+        let target = self.thread().process().target()?;
+        let pc = self.pc();
+        let window = SBInstructionList::new();
+        let instructions = self
+            .instructions(flavor)
+            .map(|instructions| instructions.iter().collect::<Vec<_>>());
+        let at_pc = instructions.as_ref().and_then(|instructions| {
+            instructions.iter().position(|instruction| {
+                let start = instruction.address().load_address(&target);
+                start != LLDB_INVALID_ADDRESS
+                    && start <= pc
+                    && pc - start < instruction.byte_size() as u64
+            })
+        });
+        match (instructions, at_pc) {
+            (Some(instructions), Some(at_pc)) => {
+                let start = at_pc.saturating_sub(before_pc as usize);
+                let end = instructions
+                    .len()
+                    .min(at_pc.saturating_add(after_pc as usize).saturating_add(1));
+                for instruction in instructions.into_iter().take(end).skip(start) {
+                    window.append_instruction(instruction);
+                }
+            }
+            _ => {
+                let instructions = target.read_instructions(
+                    &self.pc_address(),
+                    after_pc.saturating_add(1),
+                    flavor,
+                );
+                for instruction in instructions.iter() {
+                    window.append_instruction(instruction);
+                }
+            }
+        }
+        if window.is_empty() {
+            None
+        } else {
+            Some(window)
+        }
+    }
+
     /// The values for variables matching the specified options.
     pub fn variables(&self, options: &SBVariablesOptions) -> SBValueList {
         SBValueList::wrap(unsafe { sys::SBFrameGetVariables(self.raw, options.raw) })
     }
 
+    /// The variables matching the specified options, along with any
+    /// error encountered while reading each one.
+    ///
+    /// When debug information is incomplete, such as in optimized code,
+    /// some variables may have no location or may have been optimized
+    /// out. Unlike [`SBFrame::variables()`], this surfaces those
+    /// problems so that they can be shown alongside the variable.
+    pub fn variables_with_errors(&self, options: &SBVariablesOptions) -> Vec<FrameVariable> {
+        self.variables(options)
+            .iter()
+            .map(|value| FrameVariable {
+                name: value.name().map(str::to_string),
+                type_name: value.display_type_name().map(str::to_string),
+                value: value.value().map(str::to_string),
+                error: value
+                    .error()
+                    .filter(SBError::is_failure)
+                    .map(|e| e.error_string().to_string()),
+                sbvalue: value,
+            })
+            .collect()
+    }
+
     /// The values for all variables in this stack frame.
     pub fn all_variables(&self) -> SBValueList {
-        let options = SBVariablesOptions::new();
-        options.set_include_arguments(true);
-        options.set_include_locals(true);
-        options.set_include_statics(true);
-        options.set_in_scope_only(true);
+        let options = VariablesOptionsBuilder::new()
+            .arguments(true)
+            .locals(true)
+            .statics(true)
+            .in_scope_only(true)
+            .build();
         self.variables(&options)
     }
 
     /// The values for the argument variables in this stack frame.
     pub fn arguments(&self) -> SBValueList {
-        let options = SBVariablesOptions::new();
-        options.set_include_arguments(true);
-        options.set_include_locals(false);
-        options.set_include_statics(false);
-        options.set_in_scope_only(false);
+        let options = VariablesOptionsBuilder::new().arguments(true).build();
         self.variables(&options)
     }
 
     /// The values for the local variables in this stack frame.
     pub fn locals(&self) -> SBValueList {
-        let options = SBVariablesOptions::new();
-        options.set_include_arguments(false);
-        options.set_include_locals(true);
-        options.set_include_statics(false);
-        options.set_in_scope_only(false);
+        let options = VariablesOptionsBuilder::new().locals(true).build();
         self.variables(&options)
     }
 
     /// The values for the static variables in this stack frame.
     pub fn statics(&self) -> SBValueList {
-        let options = SBVariablesOptions::new();
-        options.set_include_arguments(false);
-        options.set_include_locals(false);
-        options.set_include_statics(true);
-        options.set_in_scope_only(false);
+        let options = VariablesOptionsBuilder::new().statics(true).build();
         self.variables(&options)
     }
 
@@ -255,12 +467,81 @@ impl SBFrame {
         SBValueList::wrap(unsafe { sys::SBFrameGetRegisters(self.raw) })
     }
 
+    /// The CPU registers for this stack frame, grouped by register set.
+    ///
+    /// Each entry has the name of the register set, such as
+    /// `"General Purpose Registers"`, along with the registers in it.
+    pub fn registers_by_set(&self) -> Vec<(String, SBValueList)> {
+        self.registers()
+            .iter()
+            .map(|set| {
+                let registers = SBValueList::new();
+                for register in set.children() {
+                    registers.append(&register);
+                }
+                (set.name().unwrap_or_default().to_string(), registers)
+            })
+            .collect()
+    }
+
+    /// The raw bytes of a particular register, if present.
+    ///
+    /// Unlike reading the register value as an integer, this retrieves
+    /// registers larger than 64 bits, such as SIMD and vector registers,
+    /// intact. The bytes are in the target's byte order.
+    pub fn read_register_bytes(&self, name: &str) -> Option<Vec<u8>> {
+        self.find_register(name)?.data()?.to_vec().ok()
+    }
+
+    /// The status flags for this stack frame, decoded into their names,
+    /// such as `ZF` and `CF` on x86-64 or `N`, `Z`, `C` and `V` on ARM.
+    ///
+    /// Returns `None` if the architecture is not supported by
+    /// [`registers::decode_flags()`] or the flags register can not
+    /// be read.
+    ///
+    /// [`registers::decode_flags()`]: crate::registers::decode_flags
+    pub fn flags(&self) -> Option<Vec<(&'static str, bool)>> {
+        let target = self.thread().process().target()?;
+        let triple = target.triple()?;
+        let register = self.find_register(registers::flags_register_name(triple)?)?;
+        let value = register.get_as_unsigned().ok()?;
+        Some(registers::decode_flags(triple, value))
+    }
+
     /// The value for a particular register, if present.
     pub fn find_register(&self, name: &str) -> Option<SBValue> {
         let name = CString::new(name).unwrap();
         SBValue::maybe_wrap(unsafe { sys::SBFrameFindRegister(self.raw, name.as_ptr()) })
     }
 
+    /// The value for a variable visible from this frame, if present.
+    pub fn find_variable(&self, name: &str) -> Option<SBValue> {
+        let name = CString::new(name).unwrap();
+        SBValue::maybe_wrap(unsafe { sys::SBFrameFindVariable(self.raw, name.as_ptr()) })
+    }
+
+    /// The value named `name` of the given kind, if present.
+    ///
+    /// This can look specifically for a register with
+    /// [`ValueType::Register`], a register set with
+    /// [`ValueType::RegisterSet`], the result of an earlier expression
+    /// such as `$0` with [`ValueType::ConstResult`], or for a
+    /// particular kind of variable, such as [`ValueType::VariableArgument`].
+    pub fn find_value(&self, name: &str, value_type: ValueType) -> Option<SBValue> {
+        let name = CString::new(name).unwrap();
+        SBValue::maybe_wrap(unsafe { sys::SBFrameFindValue(self.raw, name.as_ptr(), value_type) })
+    }
+
+    /// The value for a variable path such as `foo.bar[3]` or `*ptr`,
+    /// evaluated without running an expression in the target.
+    pub fn value_for_variable_path(&self, path: &str) -> Option<SBValue> {
+        let path = CString::new(path).unwrap();
+        SBValue::maybe_wrap(unsafe {
+            sys::SBFrameGetValueForVariablePath2(self.raw, path.as_ptr())
+        })
+    }
+
     /// The parent frame that invoked this frame, if available.
     pub fn parent_frame(&self) -> Option<SBFrame> {
         let thread = self.thread();
@@ -271,6 +552,37 @@ impl SBFrame {
             None
         }
     }
+
+    /// Describe this frame.
+    ///
+    /// LLDB only provides a single description of a frame, so unlike
+    /// the `describe` methods of some other objects, this doesn't take
+    /// a [`DescriptionLevel`](crate::DescriptionLevel).
+    ///
+    /// This is also available via the `Display` implementation.
+    pub fn describe(&self) -> String {
+        let stream = SBStream::new();
+        unsafe { sys::SBFrameGetDescription(self.raw, stream.raw) };
+        stream.data().to_string()
+    }
+}
+
+/// A variable in a frame along with any error from reading it.
+///
+/// This is returned by [`SBFrame::variables_with_errors()`].
+#[derive(Clone, Debug)]
+pub struct FrameVariable {
+    /// The name of the variable.
+    pub name: Option<String>,
+    /// The display name of the variable's type.
+    pub type_name: Option<String>,
+    /// The value of the variable, if it could be read.
+    pub value: Option<String>,
+    /// The reason that the value could not be read, such as the
+    /// variable having been optimized out.
+    pub error: Option<String>,
+    /// The underlying value.
+    pub sbvalue: SBValue,
 }
 
 impl Clone for SBFrame {
@@ -283,9 +595,13 @@ impl Clone for SBFrame {
 
 impl fmt::Debug for SBFrame {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let stream = SBStream::new();
-        unsafe { sys::SBFrameGetDescription(self.raw, stream.raw) };
-        write!(fmt, "SBFrame {{ {} }}", stream.data())
+        write!(fmt, "SBFrame {{ {} }}", self.describe())
+    }
+}
+
+impl fmt::Display for SBFrame {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}", self.describe())
     }
 }
 
@@ -362,6 +678,10 @@ impl SBFrame {
         self.is_inlined()
     }
 
+    fn is_artificial() -> bool {
+        self.is_artificial()
+    }
+
     fn frame_block() -> SBBlock {
         self.frame_block()
     }
@@ -0,0 +1,182 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::SBFrame;
+
+/// A declarative description of the stack frames that some policy,
+/// such as skipping frames or deciding where to stop, applies to.
+///
+/// Each criterion that is set must match for the matcher to match a
+/// frame; a matcher with no criteria matches every frame. Patterns
+/// are shell-style globs, where `*` matches any run of characters and
+/// `?` matches a single character.
+///
+/// ```no_run
+/// use lldb::{FrameMatcher, SBThread};
+///
+/// fn first_user_frame(thread: &SBThread) -> Option<u32> {
+///     let std_frames = FrameMatcher::new().function("std::*");
+///     let panic_frames = FrameMatcher::new().function("*panic*");
+///     thread
+///         .frames()
+///         .find(|frame| !frame.matches(&std_frames) && !frame.matches(&panic_frames))
+///         .map(|frame| frame.frame_id())
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FrameMatcher {
+    module: Option<String>,
+    function: Option<String>,
+    file: Option<String>,
+    inlined: Option<bool>,
+    artificial: Option<bool>,
+}
+
+impl FrameMatcher {
+    /// Construct a new `FrameMatcher` which matches every frame.
+    pub fn new() -> FrameMatcher {
+        FrameMatcher::default()
+    }
+
+    /// Only match frames whose module file name matches `pattern`.
+    pub fn module(mut self, pattern: &str) -> FrameMatcher {
+        self.module = Some(pattern.to_string());
+        self
+    }
+
+    /// Only match frames whose function name matches `pattern`.
+    ///
+    /// See [`SBFrame::is_in_function_named()`] for how names are matched.
+    pub fn function(mut self, pattern: &str) -> FrameMatcher {
+        self.function = Some(pattern.to_string());
+        self
+    }
+
+    /// Only match frames whose source file matches `pattern`.
+    ///
+    /// Patterns containing a `/` are matched against the full path,
+    /// others against the file name only. Frames without line
+    /// information never match.
+    pub fn file(mut self, pattern: &str) -> FrameMatcher {
+        self.file = Some(pattern.to_string());
+        self
+    }
+
+    /// Only match frames which are (or are not) for inlined functions.
+    pub fn inlined(mut self, inlined: bool) -> FrameMatcher {
+        self.inlined = Some(inlined);
+        self
+    }
+
+    /// Only match frames which are (or are not) [artificial].
+    ///
+    /// [artificial]: SBFrame::is_artificial()
+    pub fn artificial(mut self, artificial: bool) -> FrameMatcher {
+        self.artificial = Some(artificial);
+        self
+    }
+
+    /// Does `frame` satisfy every criterion of this matcher?
+    pub fn matches(&self, frame: &SBFrame) -> bool {
+        if let Some(inlined) = self.inlined {
+            if frame.is_inlined() != inlined {
+                return false;
+            }
+        }
+        if let Some(artificial) = self.artificial {
+            if frame.is_artificial() != artificial {
+                return false;
+            }
+        }
+        if let Some(ref pattern) = self.function {
+            if !frame.is_in_function_named(pattern) {
+                return false;
+            }
+        }
+        if let Some(ref pattern) = self.module {
+            let module = frame.module();
+            if !module.is_valid() || !glob_match(pattern, module.filespec().filename()) {
+                return false;
+            }
+        }
+        if let Some(ref pattern) = self.file {
+            let filespec = match frame.line_entry() {
+                Some(line_entry) => line_entry.filespec(),
+                None => return false,
+            };
+            let matched = if pattern.contains('/') {
+                glob_match(pattern, &filespec.path())
+            } else {
+                glob_match(pattern, filespec.filename())
+            };
+            if !matched {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Match `text` against a shell-style glob `pattern` supporting
+/// `*` and `?`.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // The position of the last `*` seen and the text position it
+    // is currently assumed to match up to, for backtracking.
+    let mut backtrack: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, t));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::glob_match;
+
+    #[test]
+    fn test_glob_literal() {
+        assert!(glob_match("main.rs", "main.rs"));
+        assert!(!glob_match("main.rs", "main.c"));
+        assert!(!glob_match("main", "main.rs"));
+        assert!(glob_match("", ""));
+        assert!(!glob_match("", "a"));
+    }
+
+    #[test]
+    fn test_glob_wildcards() {
+        assert!(glob_match("*", ""));
+        assert!(glob_match("*", "anything"));
+        assert!(glob_match("*.rs", "lib.rs"));
+        assert!(!glob_match("*.rs", "lib.rc"));
+        assert!(glob_match("std::*::fmt", "std::io::fmt"));
+        assert!(glob_match("?ain.rs", "main.rs"));
+        assert!(!glob_match("?ain.rs", "ain.rs"));
+        assert!(glob_match("a*b*c", "aXbYbZc"));
+        assert!(!glob_match("a*b*c", "aXbYbZ"));
+        assert!(glob_match("/src/*/mod.rs", "/src/net/mod.rs"));
+        assert!(glob_match("**", "x"));
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
     sys, DisassemblyFlavor, LanguageType, SBAddress, SBBlock, SBInstructionList, SBStream,
     SBTarget, SBType,
 };
-use std::ffi::{CStr, CString};
+use std::ffi::CStr;
 use std::fmt;
 use std::os::raw::c_char;
 use std::ptr;
@@ -70,16 +70,12 @@ impl SBFunction {
         target: &SBTarget,
         flavor: DisassemblyFlavor,
     ) -> SBInstructionList {
-        let flavor = match flavor {
-            DisassemblyFlavor::ATT => CString::new("att").ok(),
-            DisassemblyFlavor::Default => None,
-            DisassemblyFlavor::Intel => CString::new("intel").ok(),
-        };
+        let flavor = flavor.to_cstring();
         SBInstructionList::wrap(unsafe {
             sys::SBFunctionGetInstructions2(
                 self.raw,
                 target.raw,
-                flavor.map_or(ptr::null(), |s| s.as_ptr()),
+                flavor.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
             )
         })
     }
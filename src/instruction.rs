@@ -4,8 +4,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::{sys, SBAddress, SBData, SBStream, SBTarget};
-use std::ffi::CStr;
+use crate::{sys, EmulateInstructionOptions, SBAddress, SBData, SBFrame, SBStream, SBTarget};
+use std::ffi::{CStr, CString};
 use std::fmt;
 
 /// A machine instruction.
@@ -89,6 +89,35 @@ impl SBInstruction {
     pub fn has_delay_slot(&self) -> bool {
         unsafe { sys::SBInstructionHasDelaySlot(self.raw) }
     }
+
+    /// Emulate this instruction against the registers and memory of
+    /// `frame`, without running the process.
+    ///
+    /// The emulated results are written back to `frame`: registers are
+    /// updated, memory is written and, with
+    /// [`EmulateInstructionOptions::AUTO_ADVANCE_PC`], the pc moves to
+    /// the next instruction or the branch target. To predict where a
+    /// branch goes without changing the process, save the registers
+    /// that the instruction affects and restore them afterwards.
+    ///
+    /// With [`EmulateInstructionOptions::IGNORE_CONDITIONS`], conditional
+    /// instructions are emulated as though their conditions were met.
+    ///
+    /// Returns `false` if the instruction couldn't be emulated, such as
+    /// when LLDB has no emulator for the architecture. Emulators exist
+    /// for ARM, ARM64, MIPS, POWER and RISC-V, but not for x86.
+    pub fn emulate_with_frame(&self, frame: &SBFrame, options: EmulateInstructionOptions) -> bool {
+        unsafe { sys::SBInstructionEmulateWithFrame(self.raw, frame.raw, options.bits()) }
+    }
+
+    /// Print the steps of emulating this instruction for the
+    /// architecture of `triple` to stdout, without a frame.
+    ///
+    /// This is intended for debugging LLDB's instruction emulators.
+    pub fn dump_emulation(&self, triple: &str) -> bool {
+        let triple = CString::new(triple).unwrap();
+        unsafe { sys::SBInstructionDumpEmulation(self.raw, triple.as_ptr()) }
+    }
 }
 
 impl Clone for SBInstruction {
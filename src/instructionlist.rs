@@ -5,6 +5,7 @@
 // except according to those terms.
 
 use crate::{sys, SBInstruction, SBStream};
+use std::ffi::CString;
 use std::fmt;
 
 /// A list of [machine instructions].
@@ -16,6 +17,11 @@ pub struct SBInstructionList {
 }
 
 impl SBInstructionList {
+    /// Construct a new, empty `SBInstructionList`.
+    pub fn new() -> SBInstructionList {
+        SBInstructionList::wrap(unsafe { sys::CreateSBInstructionList() })
+    }
+
     /// Construct a new `SBInstructionList`.
     pub(crate) fn wrap(raw: sys::SBInstructionListRef) -> SBInstructionList {
         SBInstructionList { raw }
@@ -36,9 +42,14 @@ impl SBInstructionList {
         unsafe { sys::SBInstructionListIsValid(self.raw) }
     }
 
+    /// The number of instructions in this list.
+    pub fn len(&self) -> usize {
+        unsafe { sys::SBInstructionListGetSize(self.raw) }
+    }
+
     /// Is this instruction list empty?
     pub fn is_empty(&self) -> bool {
-        unsafe { sys::SBInstructionListGetSize(self.raw) == 0 }
+        self.len() == 0
     }
 
     /// Clear this instruction list.
@@ -51,6 +62,15 @@ impl SBInstructionList {
         unsafe { sys::SBInstructionListAppendInstruction(self.raw, instruction.raw) };
     }
 
+    /// Print the steps of emulating each instruction in this list for
+    /// the architecture of `triple` to stdout.
+    ///
+    /// See [`SBInstruction::dump_emulation()`].
+    pub fn dump_emulation_for_all_instructions(&self, triple: &str) -> bool {
+        let triple = CString::new(triple).unwrap();
+        unsafe { sys::SBInstructionListDumpEmulationForAllInstructions(self.raw, triple.as_ptr()) }
+    }
+
     /// Iterate over this instruction list.
     pub fn iter(&self) -> SBInstructionListIter {
         SBInstructionListIter {
@@ -76,6 +96,12 @@ impl fmt::Debug for SBInstructionList {
     }
 }
 
+impl Default for SBInstructionList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Drop for SBInstructionList {
     fn drop(&mut self) {
         unsafe { sys::DisposeSBInstructionList(self.raw) };
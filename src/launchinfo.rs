@@ -4,7 +4,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::{lldb_pid_t, sys, LaunchFlags, SBFileSpec, SBListener};
+use crate::{lldb_pid_t, sys, LaunchFlags, SBError, SBFileSpec, SBListener, SBStructuredData};
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 use std::ptr;
@@ -255,6 +255,40 @@ impl SBLaunchInfo {
     pub fn set_detach_on_error(&self, detach: bool) {
         unsafe { sys::SBLaunchInfoSetDetachOnError(self.raw, detach) };
     }
+
+    /// The name of the Python class implementing a scripted process,
+    /// if this launches one.
+    pub fn scripted_process_class_name(&self) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(sys::SBLaunchInfoGetScriptedProcessClassName(self.raw).as_ref()?)
+                .to_str()
+            {
+                Ok("") => None,
+                Ok(s) => Some(s),
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// Launch a scripted process implemented by the named Python class
+    /// instead of a real process.
+    ///
+    /// The process plugin should also be set to `"ScriptedProcess"` with
+    /// [`SBLaunchInfo::set_process_plugin_name()`].
+    pub fn set_scripted_process_class_name(&self, class_name: &str) {
+        let class_name = CString::new(class_name).unwrap();
+        unsafe { sys::SBLaunchInfoSetScriptedProcessClassName(self.raw, class_name.as_ptr()) };
+    }
+
+    /// The configuration dictionary passed to a scripted process.
+    pub fn scripted_process_dictionary(&self) -> SBStructuredData {
+        SBStructuredData::wrap(unsafe { sys::SBLaunchInfoGetScriptedProcessDictionary(self.raw) })
+    }
+
+    /// Set the configuration dictionary passed to a scripted process.
+    pub fn set_scripted_process_dictionary(&self, dict: &SBStructuredData) {
+        unsafe { sys::SBLaunchInfoSetScriptedProcessDictionary(self.raw, dict.raw) };
+    }
 }
 
 impl Clone for SBLaunchInfo {
@@ -304,3 +338,253 @@ impl<'d> Iterator for SBLaunchInfoArgumentsIter<'d> {
 }
 
 impl ExactSizeIterator for SBLaunchInfoArgumentsIter<'_> {}
+
+/// A builder for [`SBLaunchInfo`] which checks that the requested
+/// launch options make sense together.
+///
+/// Options which conflict, such as closing a TTY without launching
+/// in one or using a listener without stopping at the entry point,
+/// are reported as an error by [`build()`] rather than
+/// being silently ignored at launch time.
+///
+/// ```no_run
+/// use lldb::{LaunchInfoBuilder, SBListener, SBTarget};
+///
+/// fn launch(target: &SBTarget, listener: &SBListener) {
+///     let launch_info = LaunchInfoBuilder::new()
+///         .arguments(["--verbose", "input.txt"])
+///         .listener(listener)
+///         .stop_at_entry()
+///         .disable_aslr()
+///         .build()
+///         .unwrap();
+///     let process = target.launch(launch_info).unwrap();
+/// }
+/// ```
+///
+/// [`build()`]: LaunchInfoBuilder::build()
+#[derive(Clone, Debug)]
+pub struct LaunchInfoBuilder {
+    flags: LaunchFlags,
+    arguments: Vec<String>,
+    shell: Option<String>,
+    listener: Option<SBListener>,
+}
+
+impl LaunchInfoBuilder {
+    /// Construct a new `LaunchInfoBuilder` with LLDB's default options.
+    pub fn new() -> LaunchInfoBuilder {
+        LaunchInfoBuilder {
+            flags: LaunchFlags::empty(),
+            arguments: Vec::new(),
+            shell: None,
+            listener: None,
+        }
+    }
+
+    /// Add command line arguments, after any that were already added.
+    pub fn arguments<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.arguments.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Listen for the events of the process with `listener` rather than
+    /// with the debugger's listener.
+    ///
+    /// This requires [`stop_at_entry()`], so that no events are missed
+    /// before the listener is in use.
+    ///
+    /// See [`SBLaunchInfo::set_listener()`].
+    ///
+    /// [`stop_at_entry()`]: LaunchInfoBuilder::stop_at_entry()
+    pub fn listener(mut self, listener: &SBListener) -> Self {
+        self.listener = Some(listener.clone());
+        self
+    }
+
+    /// Stop at the entry point of the program rather than running
+    /// it straight away.
+    ///
+    /// This gives a chance to set breakpoints or to start listening for
+    /// events before any code in the program runs.
+    pub fn stop_at_entry(mut self) -> Self {
+        self.flags |= LaunchFlags::STOP_AT_ENTRY;
+        self
+    }
+
+    /// Disable address space layout randomization, so that addresses
+    /// are the same from one run to the next.
+    pub fn disable_aslr(mut self) -> Self {
+        self.flags |= LaunchFlags::DISABLE_ASLR;
+        self
+    }
+
+    /// Launch the process through `shell`, such as `/bin/sh`, so that
+    /// the arguments are subject to shell expansion.
+    pub fn launch_in_shell(mut self, shell: &str) -> Self {
+        self.flags |= LaunchFlags::LAUNCH_IN_SHELL;
+        self.shell = Some(shell.to_string());
+        self
+    }
+
+    /// Perform shell-style expansion of the arguments, such as of
+    /// globs, without otherwise launching through a shell.
+    pub fn shell_expand_arguments(mut self) -> Self {
+        self.flags |= LaunchFlags::SHELL_EXPAND_ARGUMENTS;
+        self
+    }
+
+    /// Launch the process in a new TTY, if the host supports it.
+    pub fn launch_in_tty(mut self) -> Self {
+        self.flags |= LaunchFlags::LAUNCH_IN_TTY;
+        self
+    }
+
+    /// Close the TTY that the process was launched in when it exits.
+    ///
+    /// This requires [`launch_in_tty()`].
+    ///
+    /// [`launch_in_tty()`]: LaunchInfoBuilder::launch_in_tty()
+    pub fn close_tty(mut self) -> Self {
+        self.flags |= LaunchFlags::CLOSE_TTY_ON_EXIT;
+        self
+    }
+
+    /// Don't give the process any standard input or output, as is
+    /// common for GUI applications.
+    pub fn disable_stdio(mut self) -> Self {
+        self.flags |= LaunchFlags::DISABLE_STDIO;
+        self
+    }
+
+    /// Launch the process in a separate process group.
+    pub fn separate_process_group(mut self) -> Self {
+        self.flags |= LaunchFlags::LAUNCH_IN_SEPARATE_PROCESS_GROUP;
+        self
+    }
+
+    /// Detach from the process rather than killing it if the debug
+    /// server encounters an error.
+    pub fn detach_on_error(mut self) -> Self {
+        self.flags |= LaunchFlags::DETACH_ON_ERROR;
+        self
+    }
+
+    /// Check the options and construct the `SBLaunchInfo`.
+    pub fn build(&self) -> Result<SBLaunchInfo, SBError> {
+        self.validate().map_err(SBError::from_message)?;
+
+        let launch_info = SBLaunchInfo::new();
+        // Keep LLDB's default flags, such as `DEBUG`.
+        launch_info.set_launch_flags(launch_info.launch_flags() | self.flags);
+        if !self.arguments.is_empty() {
+            launch_info.set_arguments(self.arguments.iter().map(String::as_str), false);
+        }
+        if let Some(shell) = &self.shell {
+            launch_info.set_shell(shell);
+        }
+        if let Some(listener) = &self.listener {
+            launch_info.set_listener(listener);
+        }
+        Ok(launch_info)
+    }
+
+    /// Check that the options can be used together.
+    fn validate(&self) -> Result<(), &'static str> {
+        if self.flags.contains(LaunchFlags::CLOSE_TTY_ON_EXIT)
+            && !self.flags.contains(LaunchFlags::LAUNCH_IN_TTY)
+        {
+            return Err("close_tty() requires launch_in_tty()");
+        }
+        if self.flags.contains(LaunchFlags::LAUNCH_IN_TTY)
+            && self.flags.contains(LaunchFlags::DISABLE_STDIO)
+        {
+            return Err("launch_in_tty() and disable_stdio() can't be used together");
+        }
+        if self.shell.as_ref().is_some_and(|shell| shell.is_empty()) {
+            return Err("launch_in_shell() requires a shell");
+        }
+        if self.listener.is_some() && !self.flags.contains(LaunchFlags::STOP_AT_ENTRY) {
+            // Otherwise the process can run, and even exit, before
+            // anything has been done with the listener.
+            return Err("listener() requires stop_at_entry()");
+        }
+        Ok(())
+    }
+}
+
+impl Default for LaunchInfoBuilder {
+    fn default() -> LaunchInfoBuilder {
+        LaunchInfoBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LaunchInfoBuilder;
+    use crate::SBListener;
+
+    #[test]
+    fn test_validate_defaults() {
+        assert_eq!(LaunchInfoBuilder::new().validate(), Ok(()));
+        assert_eq!(
+            LaunchInfoBuilder::new()
+                .arguments(["-v"])
+                .stop_at_entry()
+                .disable_aslr()
+                .validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_validate_tty() {
+        assert_eq!(
+            LaunchInfoBuilder::new()
+                .launch_in_tty()
+                .close_tty()
+                .validate(),
+            Ok(())
+        );
+        assert!(LaunchInfoBuilder::new().close_tty().validate().is_err());
+        assert!(LaunchInfoBuilder::new()
+            .launch_in_tty()
+            .disable_stdio()
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_validate_shell() {
+        assert_eq!(
+            LaunchInfoBuilder::new()
+                .launch_in_shell("/bin/sh")
+                .validate(),
+            Ok(())
+        );
+        assert!(LaunchInfoBuilder::new()
+            .launch_in_shell("")
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_validate_listener() {
+        let listener = SBListener::new();
+        assert_eq!(
+            LaunchInfoBuilder::new()
+                .listener(&listener)
+                .stop_at_entry()
+                .validate(),
+            Ok(())
+        );
+        assert!(LaunchInfoBuilder::new()
+            .listener(&listener)
+            .validate()
+            .is_err());
+    }
+}
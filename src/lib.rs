@@ -81,6 +81,35 @@
 //!   instructions and a source file location. [`SBCompileUnit`] contains
 //!   [`SBLineEntry`]s.
 //!
+//! ## Invalid Objects and Sentinel Values
+//!
+//! Many LLDB objects can be invalid, for example an [`SBProcess`] for
+//! a target that hasn't been launched yet. Calling methods on an invalid
+//! object is safe, but LLDB will return a sentinel value such as
+//! [`LLDB_INVALID_PROCESS_ID`] or [`LLDB_INVALID_ADDRESS`] rather than
+//! reporting an error.
+//!
+//! Where this is a common source of confusion, a checked accessor with
+//! a `try_` prefix is provided which returns `None` instead of the
+//! sentinel value, such as [`SBProcess::try_process_id()`] and
+//! [`SBProcess::try_exit_status()`].
+//!
+//! ## Raw Handles
+//!
+//! Every wrapper type exposes its underlying `lldb-sys` handle via its
+//! `raw` field. With the `unsafe-raw` feature enabled, the `AsRaw`
+//! trait is also available. It can be used to call functions in
+//! [`sys`] which are not yet wrapped by this crate and to take
+//! ownership of the handles that they return.
+//!
+//! ## Testing
+//!
+//! The `testing` feature enables the `testing` module of test helpers.
+//! It builds small fixture programs at test time and launches them
+//! under a debugger, with assertions about breakpoints and variables.
+//! It can also spawn `lldb-server` or `debugserver` and connect to
+//! them, for end-to-end tests of remote debugging.
+//!
 //! ## Support and Maintenance
 //!
 //! I am developing this library largely on my own so far. I am able
@@ -121,17 +150,49 @@ pub use crate::sys::{
     WatchpointKind,
 };
 
+/// The sentinel value for an invalid address.
+pub const LLDB_INVALID_ADDRESS: lldb_addr_t = u64::MAX;
+
+/// The sentinel value for an invalid process ID.
+pub const LLDB_INVALID_PROCESS_ID: lldb_pid_t = 0;
+
+/// The sentinel value for an invalid thread ID.
+pub const LLDB_INVALID_THREAD_ID: lldb_tid_t = 0;
+
+/// The sentinel value for an invalid breakpoint or watchpoint ID.
+pub const LLDB_INVALID_BREAK_ID: i32 = 0;
+
+/// The sentinel value for an invalid user ID.
+pub const LLDB_INVALID_UID: lldb_user_id_t = u64::MAX;
+
+/// The sentinel value for an invalid 32 bit index.
+pub const LLDB_INVALID_INDEX32: u32 = u32::MAX;
+
+/// The sentinel value for an invalid line number.
+pub const LLDB_INVALID_LINE_NUMBER: u32 = u32::MAX;
+
+/// The sentinel value for an invalid signal number.
+pub const LLDB_INVALID_SIGNAL_NUMBER: i32 = i32::MAX;
+
+pub mod breakpoints;
+pub mod registers;
+#[cfg(feature = "testing")]
+pub mod testing;
+
 mod address;
 mod attachinfo;
 mod block;
 mod breakpoint;
+mod breakpointbuilder;
 mod breakpointlist;
 mod breakpointlocation;
 mod broadcaster;
 mod commandinterpreter;
 mod compileunit;
+mod crashinfo;
 mod data;
 mod debugger;
+mod debuggerhandle;
 mod error;
 mod event;
 mod expressionoptions;
@@ -139,6 +200,7 @@ mod file;
 mod filespec;
 mod filespeclist;
 mod frame;
+mod framematcher;
 mod function;
 mod instruction;
 mod instructionlist;
@@ -148,89 +210,140 @@ mod listener;
 mod memoryregioninfo;
 mod memoryregioninfolist;
 mod module;
+mod modulelayout;
 mod modulespec;
 mod platform;
+mod platformconnectoptions;
 mod process;
 mod processinfo;
+mod progress;
 mod queue;
 mod queueitem;
+mod queuesampler;
+#[cfg(feature = "unsafe-raw")]
+mod raw;
+mod sanitizerreport;
 mod section;
+mod session;
+mod stophook;
 mod stream;
 mod stringlist;
 mod structureddata;
 mod symbol;
 mod symbolcontext;
 mod symbolcontextlist;
+mod symbolication;
+mod symbolizedlocation;
 mod target;
 mod thread;
+mod typecategory;
+mod typefilter;
+mod typeformat;
 mod typelist;
+mod typenamespecifier;
 mod types;
+mod typesummary;
+mod typesynthetic;
+mod unixsignals;
 mod value;
 mod valuelist;
+mod valuetracker;
+mod variablecache;
 mod variablesoptions;
 mod watchpoint;
 
 pub use self::address::SBAddress;
 pub use self::attachinfo::SBAttachInfo;
 pub use self::block::SBBlock;
-pub use self::breakpoint::{SBBreakpoint, SBBreakpointLocationIter};
+pub use self::breakpoint::{
+    BreakpointLocationsSummary, SBBreakpoint, SBBreakpointEvent, SBBreakpointEventLocationIter,
+    SBBreakpointLocationIter,
+};
+pub use self::breakpointbuilder::BreakpointBuilder;
 pub use self::breakpointlist::{SBBreakpointList, SBBreakpointListIter};
 pub use self::breakpointlocation::SBBreakpointLocation;
 pub use self::broadcaster::SBBroadcaster;
 pub use self::commandinterpreter::SBCommandInterpreter;
 pub use self::compileunit::SBCompileUnit;
+pub use self::crashinfo::{CrashAnnotation, ExtendedCrashInfo};
 pub use self::data::SBData;
-pub use self::debugger::{SBDebugger, SBDebuggerTargetIter};
+pub use self::debugger::{
+    InitializeGuard, SBDebugger, SBDebuggerCategoryIter, SBDebuggerTargetIter,
+};
+pub use self::debuggerhandle::{DebuggerHandle, Reply};
 pub use self::error::SBError;
 pub use self::event::SBEvent;
 pub use self::expressionoptions::SBExpressionOptions;
 pub use self::file::SBFile;
 pub use self::filespec::SBFileSpec;
 pub use self::filespeclist::{SBFileSpecList, SBFileSpecListIter};
-pub use self::frame::SBFrame;
+pub use self::frame::{FrameVariable, SBFrame};
+pub use self::framematcher::FrameMatcher;
 pub use self::function::SBFunction;
 pub use self::instruction::SBInstruction;
 pub use self::instructionlist::{SBInstructionList, SBInstructionListIter};
-pub use self::launchinfo::SBLaunchInfo;
+pub use self::launchinfo::{LaunchInfoBuilder, SBLaunchInfo};
 pub use self::lineentry::SBLineEntry;
-pub use self::listener::SBListener;
+pub use self::listener::{SBListener, SBListenerEventIter};
 pub use self::memoryregioninfo::SBMemoryRegionInfo;
 pub use self::memoryregioninfolist::{SBMemoryRegionInfoList, SBMemoryRegionInfoListIter};
 pub use self::module::{SBModule, SBModuleSectionIter, SBModuleSymbolsIter};
+pub use self::modulelayout::{ObjectFormat, SectionLayout, SegmentLayout};
 pub use self::modulespec::SBModuleSpec;
 pub use self::platform::SBPlatform;
+pub use self::platformconnectoptions::{ConnectErrorKind, SBPlatformConnectOptions};
 pub use self::process::{
-    ImageToken, SBProcess, SBProcessEvent, SBProcessEventRestartedReasonIter, SBProcessQueueIter,
-    SBProcessThreadIter,
+    ImageToken, OutputChunk, OutputStream, ProcessEventKind, ProcessEvents, ProcessKind,
+    ProcessOutputReader, SBProcess, SBProcessEvent, SBProcessEventRestartedReasonIter,
+    SBProcessOwnedThreadIter, SBProcessQueueIter, SBProcessThreadIter,
 };
 pub use self::processinfo::SBProcessInfo;
+pub use self::progress::Progress;
 pub use self::queue::{SBQueue, SBQueueQueueItemIter, SBQueueThreadIter};
 pub use self::queueitem::SBQueueItem;
+pub use self::queuesampler::{QueueCounts, QueueSample, QueueSampler};
+#[cfg(feature = "unsafe-raw")]
+pub use self::raw::AsRaw;
+pub use self::sanitizerreport::SanitizerReport;
 pub use self::section::{SBSection, SBSectionSubSectionIter};
+pub use self::stophook::{StopHookId, StopHooks};
 pub use self::stream::SBStream;
 pub use self::stringlist::{SBStringList, SBStringListIter};
 pub use self::structureddata::SBStructuredData;
 pub use self::symbol::SBSymbol;
 pub use self::symbolcontext::SBSymbolContext;
 pub use self::symbolcontextlist::SBSymbolContextList;
+pub use self::symbolication::Symbolication;
+pub use self::symbolizedlocation::{SymbolizedFrame, SymbolizedLocation};
 pub use self::target::{
     SBTarget, SBTargetBreakpointIter, SBTargetEvent, SBTargetEventModuleIter, SBTargetModuleIter,
-    SBTargetWatchpointIter,
+    SBTargetOwnedBreakpointIter, SBTargetOwnedModuleIter, SBTargetWatchpointIter,
 };
-pub use self::thread::{SBThread, SBThreadEvent, SBThreadFrameIter};
+pub use self::thread::{
+    SBThread, SBThreadEvent, SBThreadFrameIter, SBThreadOwnedFrameIter, ThreadInfo,
+};
+pub use self::typecategory::SBTypeCategory;
+pub use self::typefilter::{SBTypeFilter, SBTypeFilterExpressionPathIter};
+pub use self::typeformat::SBTypeFormat;
 pub use self::typelist::{SBTypeList, SBTypeListIter};
+pub use self::typenamespecifier::SBTypeNameSpecifier;
 pub use self::types::SBType;
-pub use self::value::SBValue;
-pub use self::valuelist::{SBValueList, SBValueListIter};
-pub use self::variablesoptions::SBVariablesOptions;
-pub use self::watchpoint::SBWatchpoint;
+pub use self::typesummary::SBTypeSummary;
+pub use self::typesynthetic::SBTypeSynthetic;
+pub use self::unixsignals::{SBUnixSignals, Signal};
+pub use self::value::{SBValue, ValueLocation};
+pub use self::valuelist::{FormatOptions, SBValueList, SBValueListIter};
+pub use self::valuetracker::{TrackedValue, ValueChange, ValueTracker};
+pub use self::variablecache::{CachedVariable, VariableCache, VariableDelta};
+pub use self::variablesoptions::{SBVariablesOptions, VariablesOptionsBuilder};
+pub use self::watchpoint::{SBWatchpoint, SBWatchpointEvent};
 
 /// Which syntax should be used in disassembly?
 ///
 /// On x86, there are 2 syntaxes used for disassembly. Other
 /// architectures need not be concerned by this and can just
 /// use `DisassemblyFlavor::Default` all the time.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum DisassemblyFlavor {
     /// The primary syntax used by the GNU Assembler and in the
     /// Linux world.
@@ -271,8 +384,64 @@ pub enum DisassemblyFlavor {
     Intel,
 }
 
+impl DisassemblyFlavor {
+    /// The name of this flavor, as used by the
+    /// `target.x86-disassembly-flavor` setting.
+    pub fn name(self) -> &'static str {
+        match self {
+            DisassemblyFlavor::ATT => "att",
+            DisassemblyFlavor::Default => "default",
+            DisassemblyFlavor::Intel => "intel",
+        }
+    }
+
+    /// The flavor with the given name, as used by the
+    /// `target.x86-disassembly-flavor` setting.
+    pub fn from_name(name: &str) -> Option<DisassemblyFlavor> {
+        match name {
+            "att" => Some(DisassemblyFlavor::ATT),
+            "default" => Some(DisassemblyFlavor::Default),
+            "intel" => Some(DisassemblyFlavor::Intel),
+            _ => None,
+        }
+    }
+
+    /// The flavor string to pass to LLDB. The default flavor is passed
+    /// as a null pointer so that the `target.x86-disassembly-flavor`
+    /// setting is used.
+    pub(crate) fn to_cstring(self) -> Option<std::ffi::CString> {
+        match self {
+            DisassemblyFlavor::Default => None,
+            flavor => std::ffi::CString::new(flavor.name()).ok(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::DisassemblyFlavor;
+
     #[test]
     fn it_works() {}
+
+    #[test]
+    fn test_disassembly_flavor_names() {
+        for flavor in [
+            DisassemblyFlavor::ATT,
+            DisassemblyFlavor::Default,
+            DisassemblyFlavor::Intel,
+        ] {
+            assert_eq!(DisassemblyFlavor::from_name(flavor.name()), Some(flavor));
+        }
+        assert_eq!(DisassemblyFlavor::ATT.name(), "att");
+        assert_eq!(DisassemblyFlavor::from_name("Intel"), None);
+        assert_eq!(DisassemblyFlavor::from_name(""), None);
+    }
+
+    #[test]
+    fn test_disassembly_flavor_cstring() {
+        assert_eq!(DisassemblyFlavor::Default.to_cstring(), None);
+        let intel = DisassemblyFlavor::Intel.to_cstring().unwrap();
+        assert_eq!(intel.to_str(), Ok("intel"));
+    }
 }
@@ -6,6 +6,7 @@
 
 use crate::{sys, SBBroadcaster, SBDebugger, SBEvent};
 use std::ffi::CString;
+use std::time::Duration;
 
 /// Listen for debugger events.
 #[derive(Debug)]
@@ -85,11 +86,57 @@ impl SBListener {
         unsafe { sys::SBListenerStopListeningForEvents(self.raw, broadcaster.raw, event_mask) }
     }
 
-    #[allow(missing_docs)]
+    /// Wait up to `num_seconds` for an event, storing it in `event`.
+    ///
+    /// Returns `false` if no event arrived in time. `u32::MAX` waits
+    /// forever.
+    ///
+    /// See also [`SBListener::wait()`].
     pub fn wait_for_event(&self, num_seconds: u32, event: &SBEvent) -> bool {
         unsafe { sys::SBListenerWaitForEvent(self.raw, num_seconds, event.raw) }
     }
 
+    /// Wait up to `timeout` for an event.
+    ///
+    /// LLDB waits in whole seconds, so `timeout` is rounded up to a
+    /// whole number of seconds.
+    pub fn wait(&self, timeout: Duration) -> Option<SBEvent> {
+        let secs = timeout.as_secs() + u64::from(timeout.subsec_nanos() > 0);
+        // u32::MAX would wait forever.
+        let secs = secs.min(u64::from(u32::MAX - 1)) as u32;
+        let event = SBEvent::new();
+        if self.wait_for_event(secs, &event) {
+            Some(event)
+        } else {
+            None
+        }
+    }
+
+    /// Take the next event if one is already queued, without waiting.
+    pub fn try_next(&self) -> Option<SBEvent> {
+        let event = SBEvent::new();
+        if self.get_next_event(&event) {
+            Some(event)
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over the events received by this listener, waiting as
+    /// long as it takes for each one.
+    ///
+    /// ```no_run
+    /// # use lldb::SBListener;
+    /// # fn handle(listener: &SBListener) {
+    /// for event in listener.events() {
+    ///     println!("{:?}", event);
+    /// }
+    /// # }
+    /// ```
+    pub fn events(&self) -> SBListenerEventIter<'_> {
+        SBListenerEventIter { listener: self }
+    }
+
     #[allow(missing_docs)]
     pub fn wait_for_event_for_broadcaster(
         &self,
@@ -218,3 +265,25 @@ impl Drop for SBListener {
 
 unsafe impl Send for SBListener {}
 unsafe impl Sync for SBListener {}
+
+/// An iterator over the [events] received by an [`SBListener`].
+///
+/// This is returned by [`SBListener::events()`].
+///
+/// [events]: SBEvent
+pub struct SBListenerEventIter<'d> {
+    listener: &'d SBListener,
+}
+
+impl Iterator for SBListenerEventIter<'_> {
+    type Item = SBEvent;
+
+    fn next(&mut self) -> Option<SBEvent> {
+        let event = SBEvent::new();
+        if self.listener.wait_for_event(u32::MAX, &event) {
+            Some(event)
+        } else {
+            None
+        }
+    }
+}
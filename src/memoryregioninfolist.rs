@@ -1,5 +1,5 @@
 use crate::sys;
-use crate::SBMemoryRegionInfo;
+use crate::{lldb_addr_t, SBMemoryRegionInfo};
 
 /// A list of [memory regions].
 ///
@@ -29,9 +29,80 @@ impl SBMemoryRegionInfoList {
         unsafe { sys::SBMemoryRegionInfoListAppendList(self.raw, region_list.raw) };
     }
 
+    /// The number of memory regions in this list.
+    pub fn len(&self) -> usize {
+        unsafe { sys::SBMemoryRegionInfoListGetSize(self.raw) as usize }
+    }
+
     /// Is this memory region list empty?
     pub fn is_empty(&self) -> bool {
-        unsafe { sys::SBMemoryRegionInfoListGetSize(self.raw) == 0 }
+        self.len() == 0
+    }
+
+    /// Get the memory region at `idx`, if `idx` is within bounds.
+    pub fn get(&self, idx: usize) -> Option<SBMemoryRegionInfo> {
+        let info = SBMemoryRegionInfo::default();
+        if unsafe {
+            sys::SBMemoryRegionInfoListGetMemoryRegionAtIndex(self.raw, idx as u32, info.raw)
+        } {
+            Some(info)
+        } else {
+            None
+        }
+    }
+
+    /// Find the memory region in this list which contains `addr`.
+    pub fn region_containing(&self, addr: lldb_addr_t) -> Option<SBMemoryRegionInfo> {
+        let info = SBMemoryRegionInfo::default();
+        if unsafe {
+            sys::SBMemoryRegionInfoListGetMemoryRegionContainingAddress(self.raw, addr, info.raw)
+        } {
+            Some(info)
+        } else {
+            None
+        }
+    }
+
+    /// Call `f` with each memory region in this list, in order.
+    ///
+    /// Unlike [`iter()`], this reuses a single [`SBMemoryRegionInfo`]
+    /// for every region, which makes repeated scans of the regions of
+    /// a large process cheaper. Iteration stops early if `f` returns
+    /// `false`.
+    ///
+    /// [`iter()`]: SBMemoryRegionInfoList::iter()
+    pub fn for_each_region<F>(&self, mut f: F)
+    where
+        F: FnMut(&SBMemoryRegionInfo) -> bool,
+    {
+        let info = SBMemoryRegionInfo::default();
+        for idx in 0..self.len() as u32 {
+            if unsafe { sys::SBMemoryRegionInfoListGetMemoryRegionAtIndex(self.raw, idx, info.raw) }
+                && !f(&info)
+            {
+                break;
+            }
+        }
+    }
+
+    /// Find the first memory region in this list which matches
+    /// `predicate`.
+    ///
+    /// Only the matching region is copied out of the list.
+    pub fn find_region<P>(&self, mut predicate: P) -> Option<SBMemoryRegionInfo>
+    where
+        P: FnMut(&SBMemoryRegionInfo) -> bool,
+    {
+        let mut found = None;
+        self.for_each_region(|info| {
+            if predicate(info) {
+                found = Some(info.clone());
+                false
+            } else {
+                true
+            }
+        });
+        found
     }
 
     /// Clear this memory region list.
@@ -99,8 +170,8 @@ impl Iterator for SBMemoryRegionInfoListIter<'_> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let sz = unsafe { sys::SBMemoryRegionInfoListGetSize(self.list.raw) } as usize;
-        (sz - self.idx as usize, Some(sz))
+        let sz = self.list.len() - self.idx as usize;
+        (sz, Some(sz))
     }
 }
 
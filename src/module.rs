@@ -5,10 +5,11 @@
 // except according to those terms.
 
 use crate::{
-    sys, SBFileSpec, SBSection, SBStream, SBSymbol, SBSymbolContextList, SBTypeList, SymbolType,
-    TypeClass,
+    lldb_addr_t, sys, ObjectFormat, SBAddress, SBFileSpec, SBSection, SBStream, SBSymbol,
+    SBSymbolContext, SBSymbolContextList, SBTarget, SBTypeList, SBValue, SBValueList,
+    SegmentLayout, SymbolContextItem, SymbolType, TypeClass,
 };
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::fmt;
 
 /// An executable image and its associated object and symbol files.
@@ -45,6 +46,15 @@ impl SBModule {
         SBFileSpec::wrap(unsafe { sys::SBModuleGetFileSpec(self.raw) })
     }
 
+    /// The file from which the debug information for the module was
+    /// loaded, such as a `.dSYM` bundle or a separate DWARF file.
+    ///
+    /// This is the module's own file when the debug information is
+    /// embedded in it.
+    pub fn symbol_filespec(&self) -> Option<SBFileSpec> {
+        SBFileSpec::maybe_wrap(unsafe { sys::SBModuleGetSymbolFileSpec(self.raw) })
+    }
+
     /// The file for the module as it is known on the remote system on
     /// which it is being debugged.
     ///
@@ -74,6 +84,43 @@ impl SBModule {
         }
     }
 
+    /// Resolve a file address into a section offset address within this
+    /// module.
+    ///
+    /// This does not need a target or a running process, so it is
+    /// useful for tools which only work with object files.
+    pub fn resolve_file_address(&self, file_addr: lldb_addr_t) -> Option<SBAddress> {
+        SBAddress::maybe_wrap(unsafe { sys::SBModuleResolveFileAddress(self.raw, file_addr) })
+    }
+
+    /// The [section] of this module which contains the file address
+    /// `file_addr`.
+    ///
+    /// [section]: SBSection
+    pub fn section_containing_file_address(&self, file_addr: lldb_addr_t) -> Option<SBSection> {
+        self.resolve_file_address(file_addr)?.section()
+    }
+
+    /// Does a section of this module contain the file address
+    /// `file_addr`?
+    pub fn contains_file_address(&self, file_addr: lldb_addr_t) -> bool {
+        self.section_containing_file_address(file_addr).is_some()
+    }
+
+    /// Resolve the symbol context for an address within this module.
+    ///
+    /// `resolve_scope` determines which parts of the symbol context
+    /// are looked up.
+    pub fn resolve_symbol_context_for_address(
+        &self,
+        address: &SBAddress,
+        resolve_scope: SymbolContextItem,
+    ) -> SBSymbolContext {
+        SBSymbolContext::wrap(unsafe {
+            sys::SBModuleResolveSymbolContextForAddress(self.raw, address.raw, resolve_scope.bits())
+        })
+    }
+
     #[allow(missing_docs)]
     pub fn find_functions(&self, name: &str, name_type_mask: u32) -> SBSymbolContextList {
         let name = CString::new(name).unwrap();
@@ -82,7 +129,9 @@ impl SBModule {
         })
     }
 
-    #[allow(missing_docs)]
+    /// Find the symbols named `name`, of type `symbol_type`.
+    ///
+    /// Use [`SymbolType::Any`] to find symbols of any type.
     pub fn find_symbols(&self, name: &str, symbol_type: SymbolType) -> SBSymbolContextList {
         let name = CString::new(name).unwrap();
         SBSymbolContextList::wrap(unsafe {
@@ -90,6 +139,31 @@ impl SBModule {
         })
     }
 
+    /// Find up to `max_matches` global variables named `name` in this
+    /// module.
+    ///
+    /// `target` is used to read their values, which can be done without
+    /// evaluating an expression, as long as they are in memory.
+    pub fn find_global_variables(
+        &self,
+        target: &SBTarget,
+        name: &str,
+        max_matches: u32,
+    ) -> SBValueList {
+        let name = CString::new(name).unwrap();
+        SBValueList::wrap(unsafe {
+            sys::SBModuleFindGlobalVariables(self.raw, target.raw, name.as_ptr(), max_matches)
+        })
+    }
+
+    /// Find the first global variable named `name` in this module.
+    pub fn find_first_global_variable(&self, target: &SBTarget, name: &str) -> Option<SBValue> {
+        let name = CString::new(name).unwrap();
+        SBValue::maybe_wrap(unsafe {
+            sys::SBModuleFindFirstGlobalVariable(self.raw, target.raw, name.as_ptr())
+        })
+    }
+
     /// Get all types matching `type_mask` from the debug info in this
     /// module.
     ///
@@ -101,6 +175,46 @@ impl SBModule {
         SBTypeList::wrap(unsafe { sys::SBModuleGetTypes(self.raw, type_mask.bits()) })
     }
 
+    /// The target triple of the module, such as `x86_64-apple-macosx`.
+    pub fn triple(&self) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(sys::SBModuleGetTriple(self.raw).as_ref()?).to_str() {
+                Ok("") => None,
+                Ok(s) => Some(s),
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// The object file format of the module.
+    ///
+    /// LLDB doesn't report this, so it is worked out from the module's
+    /// [triple](SBModule::triple()), or failing that from the names of
+    /// its segments. The module's file is not read, as it may not be
+    /// available to this host. This is [`ObjectFormat::Unknown`] if
+    /// neither gives the format away.
+    pub fn object_format(&self) -> ObjectFormat {
+        let from_triple = self
+            .triple()
+            .map_or(ObjectFormat::Unknown, ObjectFormat::from_triple);
+        if from_triple != ObjectFormat::Unknown {
+            return from_triple;
+        }
+        self.sections()
+            .map(|segment| ObjectFormat::from_segment_name(segment.name()))
+            .find(|&format| format != ObjectFormat::Unknown)
+            .unwrap_or(ObjectFormat::Unknown)
+    }
+
+    /// The layout of the module as LLDB sees it: its segments, with
+    /// the sections that they contain, and their addresses, file
+    /// offsets and sizes.
+    pub fn export_layout(&self) -> Vec<SegmentLayout> {
+        self.sections()
+            .map(|segment| SegmentLayout::from_section(&segment))
+            .collect()
+    }
+
     /// Get a list of all symbols in the module
     pub fn symbols(&self) -> SBModuleSymbolsIter {
         SBModuleSymbolsIter {
@@ -108,6 +222,49 @@ impl SBModule {
             index: 0,
         }
     }
+
+    /// The number of symbols in the symbol table of this module.
+    pub fn num_symbols(&self) -> usize {
+        unsafe { sys::SBModuleGetNumSymbols(self.raw) }
+    }
+
+    /// Get the symbol at `idx` in the symbol table of this module.
+    pub fn symbol_at_index(&self, idx: usize) -> Option<SBSymbol> {
+        if idx < self.num_symbols() {
+            SBSymbol::maybe_wrap(unsafe { sys::SBModuleGetSymbolAtIndex(self.raw, idx) })
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over the symbols of type `symbol_type` whose names are
+    /// accepted by `matches`.
+    ///
+    /// Use [`SymbolType::Any`] to accept symbols of any type. The type
+    /// is checked before the name, so `matches` is only called for
+    /// symbols of the requested type. `matches` is a predicate rather
+    /// than a pattern so that callers can use whichever matching they
+    /// like, such as `|name| re.is_match(name)` with a regular
+    /// expression from the `regex` crate.
+    ///
+    /// LLDB has no lookup of module symbols by pattern, so this visits
+    /// every symbol in the module, just as filtering
+    /// [`SBModule::symbols()`] would. To find symbols with an exact
+    /// name, [`SBModule::find_symbols()`] uses LLDB's name index
+    /// instead and is much faster.
+    pub fn symbols_matching<'a, F>(
+        &'a self,
+        mut matches: F,
+        symbol_type: SymbolType,
+    ) -> impl Iterator<Item = SBSymbol> + 'a
+    where
+        F: FnMut(&str) -> bool + 'a,
+    {
+        self.symbols().filter(move |symbol| {
+            (symbol_type == SymbolType::Any || symbol.symbol_type() == symbol_type)
+                && matches(symbol.name())
+        })
+    }
 }
 
 /// Iterate over the [sections] in a [module].
@@ -159,20 +316,19 @@ impl Iterator for SBModuleSymbolsIter<'_> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let size = unsafe { sys::SBModuleGetNumSections(self.module.raw) };
-        let len = size - self.index;
+        let len = self.module.num_symbols().saturating_sub(self.index);
         (len, Some(len))
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        let size = unsafe { sys::SBModuleGetNumSections(self.module.raw) };
+        let size = self.module.num_symbols();
         let index = n + self.index;
         if index < size {
             let symbol = unsafe { sys::SBModuleGetSymbolAtIndex(self.module.raw, index) };
             self.index = index + 1;
             Some(SBSymbol { raw: symbol })
         } else {
-            self.index = self.len();
+            self.index = size;
             None
         }
     }
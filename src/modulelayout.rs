@@ -0,0 +1,174 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{Permissions, SBSection, SectionType};
+
+/// The object file format of a [module](crate::SBModule).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ObjectFormat {
+    /// Mach-O, as used on macOS, iOS and other Apple platforms.
+    MachO,
+    /// ELF, as used on Linux, the BSDs and most other systems.
+    Elf,
+    /// PE/COFF, as used on Windows.
+    PeCoff,
+    /// The format could not be determined.
+    Unknown,
+}
+
+impl ObjectFormat {
+    /// Identify the format used by the platform of a target triple,
+    /// such as `x86_64-unknown-linux-gnu`.
+    pub(crate) fn from_triple(triple: &str) -> ObjectFormat {
+        let mut parts = triple.split('-').skip(1);
+        let vendor = parts.next().unwrap_or_default();
+        let os = parts.next().unwrap_or_default();
+        let environment = parts.next().unwrap_or_default();
+        if os.starts_with("windows") || os.starts_with("uefi") {
+            ObjectFormat::PeCoff
+        } else if vendor == "apple"
+            || [
+                "darwin", "macos", "macosx", "ios", "tvos", "watchos", "xros", "bridgeos",
+            ]
+            .iter()
+            .any(|name| os.starts_with(name))
+        {
+            ObjectFormat::MachO
+        } else if environment.starts_with("elf")
+            || [
+                "linux",
+                "android",
+                "freebsd",
+                "netbsd",
+                "openbsd",
+                "dragonfly",
+                "solaris",
+                "fuchsia",
+                "haiku",
+                "hurd",
+            ]
+            .iter()
+            .any(|name| os.starts_with(name))
+        {
+            ObjectFormat::Elf
+        } else {
+            ObjectFormat::Unknown
+        }
+    }
+
+    /// Identify the format from the names that LLDB gives to the top
+    /// level sections of a module.
+    pub(crate) fn from_segment_name(name: &str) -> ObjectFormat {
+        if name.starts_with("__") {
+            ObjectFormat::MachO
+        } else if name.starts_with("PT_") {
+            ObjectFormat::Elf
+        } else {
+            ObjectFormat::Unknown
+        }
+    }
+}
+
+/// A top level section of a module, such as a Mach-O segment or an
+/// ELF program header, along with the sections that it contains.
+///
+/// See [`SBModule::export_layout()`](crate::SBModule::export_layout).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SegmentLayout {
+    /// The name of the segment, such as `__TEXT` or `PT_LOAD[0]`.
+    pub name: String,
+    /// The address of the segment in the object file, before it
+    /// is loaded.
+    pub file_address: u64,
+    /// The size of the segment in memory.
+    pub byte_size: u64,
+    /// The offset of the segment's data in the object file.
+    pub file_offset: u64,
+    /// The size of the segment's data in the object file.
+    pub file_byte_size: u64,
+    /// The memory permissions of the segment, if known.
+    pub permissions: Option<Permissions>,
+    /// The sections within this segment.
+    pub sections: Vec<SectionLayout>,
+}
+
+/// A section within a [`SegmentLayout`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SectionLayout {
+    /// The name of the section, such as `__text` or `.text`.
+    pub name: String,
+    /// The kind of section.
+    pub section_type: SectionType,
+    /// The address of the section in the object file, before it
+    /// is loaded.
+    pub file_address: u64,
+    /// The size of the section in memory.
+    pub byte_size: u64,
+    /// The offset of the section's data in the object file.
+    pub file_offset: u64,
+    /// The size of the section's data in the object file.
+    pub file_byte_size: u64,
+}
+
+impl SegmentLayout {
+    pub(crate) fn from_section(segment: &SBSection) -> SegmentLayout {
+        SegmentLayout {
+            name: segment.name().to_string(),
+            file_address: segment.file_address(),
+            byte_size: segment.byte_size(),
+            file_offset: segment.file_offset(),
+            file_byte_size: segment.file_byte_size(),
+            permissions: segment.permissions(),
+            sections: segment
+                .subsections()
+                .map(|section| SectionLayout {
+                    name: section.name().to_string(),
+                    section_type: section.section_type(),
+                    file_address: section.file_address(),
+                    byte_size: section.byte_size(),
+                    file_offset: section.file_offset(),
+                    file_byte_size: section.file_byte_size(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ObjectFormat;
+
+    #[test]
+    fn test_from_triple() {
+        let format = ObjectFormat::from_triple;
+        assert_eq!(format("x86_64-unknown-linux-gnu"), ObjectFormat::Elf);
+        assert_eq!(format("aarch64-unknown-linux-android"), ObjectFormat::Elf);
+        assert_eq!(format("x86_64-unknown-freebsd13.0"), ObjectFormat::Elf);
+        assert_eq!(format("thumbv7em-none-unknown-elf"), ObjectFormat::Elf);
+        assert_eq!(format("arm64-apple-macosx14.0.0"), ObjectFormat::MachO);
+        assert_eq!(format("arm64-apple-ios17.0"), ObjectFormat::MachO);
+        assert_eq!(format("x86_64-pc-windows-msvc"), ObjectFormat::PeCoff);
+        assert_eq!(format("x86_64-pc-windows-gnu"), ObjectFormat::PeCoff);
+        assert_eq!(format("x86_64-unknown-unknown"), ObjectFormat::Unknown);
+        assert_eq!(format(""), ObjectFormat::Unknown);
+    }
+
+    #[test]
+    fn test_from_segment_name() {
+        assert_eq!(
+            ObjectFormat::from_segment_name("__TEXT"),
+            ObjectFormat::MachO
+        );
+        assert_eq!(
+            ObjectFormat::from_segment_name("PT_LOAD[0]"),
+            ObjectFormat::Elf
+        );
+        assert_eq!(
+            ObjectFormat::from_segment_name(".text"),
+            ObjectFormat::Unknown
+        );
+    }
+}
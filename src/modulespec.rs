@@ -5,7 +5,9 @@
 // except according to those terms.
 
 use crate::{sys, SBFileSpec, SBStream};
+use std::ffi::{CStr, CString};
 use std::fmt;
+use std::slice;
 
 /// A description of an `SBModule`.
 pub struct SBModuleSpec {
@@ -71,44 +73,97 @@ impl SBModuleSpec {
         unsafe { sys::SBModuleSpecSetPlatformFileSpec(self.raw, filespec.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// The separate file containing the debug information for the
+    /// module, such as a `.dSYM` bundle or a `.debug` file.
     pub fn symbol_filespec(&self) -> Option<SBFileSpec> {
         SBFileSpec::maybe_wrap(unsafe { sys::SBModuleSpecGetSymbolFileSpec(self.raw) })
     }
 
-    #[allow(missing_docs)]
+    /// Set the separate file containing the debug information for the
+    /// module.
     pub fn set_symbol_filespec(&self, filespec: &SBFileSpec) {
         unsafe { sys::SBModuleSpecSetSymbolFileSpec(self.raw, filespec.raw) }
     }
 
-    #[allow(missing_docs)]
-    pub fn object_name(&self) -> &str {
-        unimplemented!();
+    /// The name of the object within the file, for modules which are
+    /// members of static archives.
+    pub fn object_name(&self) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(sys::SBModuleSpecGetObjectName(self.raw).as_ref()?).to_str() {
+                Ok(s) => Some(s),
+                _ => panic!("Invalid string?"),
+            }
+        }
     }
 
-    #[allow(missing_docs)]
-    pub fn set_object_name(&self, _object_name: &str) {
-        unimplemented!();
+    /// Set the name of the object within the file, for modules which
+    /// are members of static archives.
+    pub fn set_object_name(&self, object_name: &str) {
+        let object_name = CString::new(object_name).unwrap();
+        unsafe { sys::SBModuleSpecSetObjectName(self.raw, object_name.as_ptr()) };
     }
 
-    #[allow(missing_docs)]
-    pub fn triple(&self) -> &str {
-        unimplemented!();
+    /// The target triple of the module, such as `arm64-apple-ios`.
+    pub fn triple(&self) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(sys::SBModuleSpecGetTriple(self.raw).as_ref()?).to_str() {
+                Ok(s) if !s.is_empty() => Some(s),
+                Ok(_) => None,
+                _ => panic!("Invalid string?"),
+            }
+        }
     }
 
-    #[allow(missing_docs)]
-    pub fn set_triple(&self, _object_name: &str) {
-        unimplemented!();
+    /// Set the target triple of the module.
+    pub fn set_triple(&self, triple: &str) {
+        let triple = CString::new(triple).unwrap();
+        unsafe { sys::SBModuleSpecSetTriple(self.raw, triple.as_ptr()) };
     }
 
-    #[allow(missing_docs)]
-    pub fn uuid_bytes(&self) -> &str {
-        unimplemented!();
+    /// The UUID of the module, such as its Mach-O `LC_UUID` or ELF
+    /// build ID.
+    pub fn uuid_bytes(&self) -> &[u8] {
+        unsafe {
+            let len = sys::SBModuleSpecGetUUIDLength(self.raw);
+            let bytes = sys::SBModuleSpecGetUUIDBytes(self.raw);
+            if len == 0 || bytes.is_null() {
+                &[]
+            } else {
+                slice::from_raw_parts(bytes, len)
+            }
+        }
     }
 
-    #[allow(missing_docs)]
-    pub fn set_uuid_bytes(&self, _object_name: &str) {
-        unimplemented!();
+    /// Set the UUID of the module.
+    ///
+    /// A module can be found by its UUID alone, such as in a symbol
+    /// store or by `dsymForUUID`, without knowing its path.
+    pub fn set_uuid_bytes(&self, uuid: &[u8]) -> bool {
+        unsafe { sys::SBModuleSpecSetUUIDBytes(self.raw, uuid.as_ptr(), uuid.len()) }
+    }
+
+    /// Set the UUID of the module from its textual form, such as
+    /// `"E621E1F8-C36C-495A-93FC-0C247A3E6E5F"` or a hexadecimal ELF
+    /// build ID.
+    ///
+    /// Returns `false` if `uuid` isn't made of pairs of hexadecimal
+    /// digits, optionally separated by dashes.
+    pub fn set_uuid_string(&self, uuid: &str) -> bool {
+        let digits: Vec<u8> = uuid.bytes().filter(|&b| b != b'-').collect();
+        let pairs = digits.chunks_exact(2);
+        if digits.is_empty() || !pairs.remainder().is_empty() {
+            return false;
+        }
+        let bytes: Option<Vec<u8>> = pairs
+            .map(|pair| {
+                let pair = std::str::from_utf8(pair).ok()?;
+                u8::from_str_radix(pair, 16).ok()
+            })
+            .collect();
+        match bytes {
+            Some(bytes) => self.set_uuid_bytes(&bytes),
+            None => false,
+        }
     }
 }
 
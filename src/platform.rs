@@ -4,8 +4,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::{lldb_pid_t, sys, SBError, SBLaunchInfo};
-use std::ffi::CStr;
+use crate::{lldb_pid_t, sys, SBDebugger, SBError, SBLaunchInfo, SBPlatformConnectOptions};
+use std::ffi::{CStr, CString};
 
 /// A platform that can represent the current host or a
 /// remote host debug platform.
@@ -29,7 +29,32 @@ use std::ffi::CStr;
 /// and executable type. If the architecture or executable type do not match,
 /// a suitable platform will be found automatically.
 ///
+/// # Remote Debugging
+///
+/// A remote platform is [connected] to a platform server, such as
+/// `lldb-server platform`, running on the remote machine. Once it is
+/// [selected] in the debugger, targets created afterwards use it, and
+/// launching or attaching to a process asks the platform server to start
+/// a debug server (`lldb-server gdbserver` or `debugserver`) for it.
+/// LLDB then connects to that debug server automatically, including
+/// forwarding its port when the platform requires it, as with `adb`.
+///
+/// ```no_run
+/// use lldb::{SBAttachInfo, SBDebugger, SBPlatform};
+///
+/// SBDebugger::initialize();
+/// let debugger = SBDebugger::create(false);
+/// let platform = SBPlatform::new("remote-linux");
+/// platform.connect_remote("connect://192.168.1.2:1234").unwrap();
+/// debugger.set_selected_platform(&platform);
+///
+/// let target = debugger.create_target_simple("/usr/local/bin/servo").unwrap();
+/// let process = target.attach(SBAttachInfo::new_with_pid(4321)).unwrap();
+/// ```
+///
 /// [`SBTarget`]: crate::SBTarget
+/// [connected]: SBPlatform::connect_remote()
+/// [selected]: crate::SBDebugger::set_selected_platform()
 /// [currently selected]: crate::SBDebugger::selected_platform
 #[derive(Debug)]
 pub struct SBPlatform {
@@ -44,7 +69,6 @@ impl SBPlatform {
     }
 
     /// Construct a new `Some(SBPlatform)` or `None`.
-    #[allow(dead_code)]
     pub(crate) fn maybe_wrap(raw: sys::SBPlatformRef) -> Option<SBPlatform> {
         if unsafe { sys::SBPlatformIsValid(raw) } {
             Some(SBPlatform { raw })
@@ -58,6 +82,89 @@ impl SBPlatform {
         unsafe { sys::SBPlatformIsValid(self.raw) }
     }
 
+    /// Construct a new `SBPlatform` for the named platform plugin,
+    /// such as `remote-linux` or `remote-macosx`.
+    ///
+    /// Remote platforms must be [connected] before they can be used.
+    ///
+    /// [connected]: SBPlatform::connect_remote()
+    pub fn new(platform_name: &str) -> SBPlatform {
+        let platform_name = CString::new(platform_name).unwrap();
+        SBPlatform::wrap(unsafe { sys::CreateSBPlatform2(platform_name.as_ptr()) })
+    }
+
+    /// Select the `remote-ios` platform in `debugger`, for debugging on
+    /// a physical iOS device attached over USB.
+    ///
+    /// This doesn't need to be connected. Returns `None` if this build of
+    /// LLDB doesn't support the platform, as is the case outside of Apple
+    /// platforms, in which case the selected platform is left unchanged.
+    pub fn remote_ios(debugger: &SBDebugger) -> Option<SBPlatform> {
+        SBPlatform::select("remote-ios", debugger, None).ok()
+    }
+
+    /// Select the `ios-simulator` platform in `debugger`, for debugging
+    /// processes running in the iOS simulator.
+    ///
+    /// This doesn't need to be connected. Returns `None` if this build of
+    /// LLDB doesn't support the platform, as is the case outside of Apple
+    /// platforms, in which case the selected platform is left unchanged.
+    pub fn ios_simulator(debugger: &SBDebugger) -> Option<SBPlatform> {
+        SBPlatform::select("ios-simulator", debugger, None).ok()
+    }
+
+    /// Select the `remote-linux` platform in `debugger`, first connecting
+    /// it to the platform server listening at `url` if one is given.
+    ///
+    /// The URL is typically of the form `connect://host:port`. Without
+    /// one, the platform can be [connected] later. If connecting fails,
+    /// the selected platform is left unchanged.
+    ///
+    /// ```no_run
+    /// use lldb::{SBDebugger, SBPlatform};
+    ///
+    /// SBDebugger::initialize();
+    /// let debugger = SBDebugger::create(false);
+    /// let platform =
+    ///     SBPlatform::remote_linux(&debugger, Some("connect://192.168.1.2:1234")).unwrap();
+    /// ```
+    ///
+    /// [connected]: SBPlatform::connect_remote()
+    pub fn remote_linux(debugger: &SBDebugger, url: Option<&str>) -> Result<SBPlatform, SBError> {
+        SBPlatform::select("remote-linux", debugger, url)
+    }
+
+    fn select(
+        platform_name: &str,
+        debugger: &SBDebugger,
+        url: Option<&str>,
+    ) -> Result<SBPlatform, SBError> {
+        let platform = match url {
+            Some(url) => SBPlatform::new_connected(platform_name, url)?,
+            None => SBPlatform::maybe_new(platform_name).ok_or_else(|| {
+                SBError::from_message(&format!("unknown platform {platform_name}"))
+            })?,
+        };
+        debugger.set_selected_platform(&platform);
+        Ok(platform)
+    }
+
+    /// Construct the named platform plugin and connect it to the platform
+    /// server listening at `url`.
+    ///
+    /// See [`SBPlatform::new()`] and [`SBPlatform::connect_remote()`].
+    pub fn new_connected(platform_name: &str, url: &str) -> Result<SBPlatform, SBError> {
+        let platform = SBPlatform::maybe_new(platform_name)
+            .ok_or_else(|| SBError::from_message(&format!("unknown platform {platform_name}")))?;
+        platform.connect_remote(url)?;
+        Ok(platform)
+    }
+
+    fn maybe_new(platform_name: &str) -> Option<SBPlatform> {
+        let platform_name = CString::new(platform_name).unwrap();
+        SBPlatform::maybe_wrap(unsafe { sys::CreateSBPlatform2(platform_name.as_ptr()) })
+    }
+
     #[allow(missing_docs)]
     pub fn get_host_platform() -> SBPlatform {
         SBPlatform::wrap(unsafe { sys::SBPlatformGetHostPlatform() })
@@ -161,6 +268,60 @@ impl SBPlatform {
         }
     }
 
+    /// Connect to a remote platform server, such as `lldb-server platform`,
+    /// listening at `url`.
+    ///
+    /// The URL is typically of the form `connect://host:port`.
+    ///
+    /// See [`connect()`] to also configure file transfer and caching.
+    ///
+    /// [`connect()`]: SBPlatform::connect()
+    pub fn connect_remote(&self, url: &str) -> Result<(), SBError> {
+        self.connect(&SBPlatformConnectOptions::new(url))
+    }
+
+    /// Connect to a remote platform server using the given `options`.
+    ///
+    /// On failure, [`ConnectErrorKind::classify()`] can be used to tell
+    /// an authentication failure from an unreachable host or a protocol
+    /// version mismatch.
+    ///
+    /// [`ConnectErrorKind::classify()`]: crate::ConnectErrorKind::classify()
+    ///
+    /// ```no_run
+    /// use lldb::{ConnectErrorKind, SBPlatform, SBPlatformConnectOptions};
+    ///
+    /// let platform = SBPlatform::new("remote-linux");
+    /// let options = SBPlatformConnectOptions::ssh("build-box", 1234, Some("dev"), None);
+    /// if let Err(error) = platform.connect(&options) {
+    ///     match ConnectErrorKind::classify(&error) {
+    ///         ConnectErrorKind::AuthenticationFailed => println!("check your ssh credentials"),
+    ///         ConnectErrorKind::Unreachable => println!("is the platform server running?"),
+    ///         kind => println!("{}: {}", kind, error),
+    ///     }
+    /// }
+    /// ```
+    pub fn connect(&self, options: &SBPlatformConnectOptions) -> Result<(), SBError> {
+        let error = SBError::wrap(unsafe { sys::SBPlatformConnectRemote(self.raw, options.raw) });
+        if error.is_success() {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+
+    /// Disconnect from the remote platform server.
+    pub fn disconnect_remote(&self) {
+        unsafe { sys::SBPlatformDisconnectRemote(self.raw) };
+    }
+
+    /// Is this platform connected to a remote platform server?
+    ///
+    /// The host platform is always connected.
+    pub fn is_connected(&self) -> bool {
+        unsafe { sys::SBPlatformIsConnected(self.raw) }
+    }
+
     /// Kill a process.
     pub fn kill(&self, pid: lldb_pid_t) -> Result<(), SBError> {
         let error = SBError::wrap(unsafe { sys::SBPlatformKill(self.raw, pid) });
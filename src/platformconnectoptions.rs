@@ -0,0 +1,329 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::target::double_quote;
+use crate::{sys, SBError};
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::ptr;
+
+/// Options for [connecting] an [`SBPlatform`] to a remote platform server.
+///
+/// The typed constructors cover the URL schemes in common use:
+///
+/// * [`connect()`] for `lldb-server platform` or `debugserver` listening
+///   on a TCP port (`connect://host:port`).
+/// * [`adb()`] for an Android device reached through `adb`
+///   (`adb://serial`).
+/// * [`ssh()`] for a `remote-linux` platform whose files are transferred
+///   with `rsync` over `ssh`.
+///
+/// [connecting]: crate::SBPlatform::connect()
+/// [`SBPlatform`]: crate::SBPlatform
+/// [`connect()`]: SBPlatformConnectOptions::connect()
+/// [`adb()`]: SBPlatformConnectOptions::adb()
+/// [`ssh()`]: SBPlatformConnectOptions::ssh()
+#[derive(Debug)]
+pub struct SBPlatformConnectOptions {
+    /// The underlying raw `SBPlatformConnectOptionsRef`.
+    pub raw: sys::SBPlatformConnectOptionsRef,
+}
+
+impl SBPlatformConnectOptions {
+    /// Construct a new `SBPlatformConnectOptions` for an arbitrary URL.
+    pub fn new(url: &str) -> SBPlatformConnectOptions {
+        let url = CString::new(url).unwrap();
+        SBPlatformConnectOptions::wrap(unsafe { sys::CreateSBPlatformConnectOptions(url.as_ptr()) })
+    }
+
+    /// Construct a new `SBPlatformConnectOptions`.
+    pub(crate) fn wrap(raw: sys::SBPlatformConnectOptionsRef) -> SBPlatformConnectOptions {
+        SBPlatformConnectOptions { raw }
+    }
+
+    /// Options for a platform server listening on `host:port`.
+    pub fn connect(host: &str, port: u16) -> SBPlatformConnectOptions {
+        SBPlatformConnectOptions::new(&format!("connect://{}:{}", host, port))
+    }
+
+    /// Options for the Android device with the given `adb` serial number.
+    ///
+    /// This should be used with the `remote-android` platform.
+    pub fn adb(serial: &str) -> SBPlatformConnectOptions {
+        SBPlatformConnectOptions::new(&format!("adb://{}", serial))
+    }
+
+    /// Options for a `remote-linux` platform server on `host:port` whose
+    /// files are transferred with `rsync` over `ssh`.
+    ///
+    /// LLDB itself does not authenticate; the credentials are passed to
+    /// `ssh` by `rsync`. When `user` is given, it is used as the remote
+    /// login and when `identity_file` is given, it is used as the private
+    /// key. Otherwise, the usual `ssh` configuration applies.
+    pub fn ssh(
+        host: &str,
+        port: u16,
+        user: Option<&str>,
+        identity_file: Option<&str>,
+    ) -> SBPlatformConnectOptions {
+        let options = SBPlatformConnectOptions::connect(host, port);
+        options.enable_rsync(&rsync_ssh_option(user, identity_file), None, false);
+        options
+    }
+
+    /// The URL of the remote platform server.
+    pub fn url(&self) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(sys::SBPlatformConnectOptionsGetURL(self.raw).as_ref()?).to_str() {
+                Ok(s) => Some(s),
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// Set the URL of the remote platform server.
+    pub fn set_url(&self, url: &str) {
+        let url = CString::new(url).unwrap();
+        unsafe { sys::SBPlatformConnectOptionsSetURL(self.raw, url.as_ptr()) };
+    }
+
+    /// Is `rsync` used to transfer files from the remote platform?
+    pub fn rsync_enabled(&self) -> bool {
+        unsafe { sys::SBPlatformConnectOptionsGetRsyncEnabled(self.raw) }
+    }
+
+    /// Use `rsync` to transfer files from the remote platform.
+    ///
+    /// `options` are passed to `rsync`. Remote paths are prefixed with
+    /// `remote_path_prefix`, if given. When `omit_remote_hostname` is
+    /// `true`, remote paths are not of the form `host:path`.
+    pub fn enable_rsync(
+        &self,
+        options: &str,
+        remote_path_prefix: Option<&str>,
+        omit_remote_hostname: bool,
+    ) {
+        let options = CString::new(options).unwrap();
+        let remote_path_prefix = remote_path_prefix.map(|p| CString::new(p).unwrap());
+        unsafe {
+            sys::SBPlatformConnectOptionsEnableRsync(
+                self.raw,
+                options.as_ptr(),
+                remote_path_prefix
+                    .as_ref()
+                    .map_or(ptr::null(), |p| p.as_ptr()),
+                omit_remote_hostname,
+            )
+        };
+    }
+
+    /// Stop using `rsync` to transfer files from the remote platform.
+    pub fn disable_rsync(&self) {
+        unsafe { sys::SBPlatformConnectOptionsDisableRsync(self.raw) };
+    }
+
+    /// The local directory in which files from the remote platform are
+    /// cached.
+    pub fn local_cache_directory(&self) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(
+                sys::SBPlatformConnectOptionsGetLocalCacheDirectory(self.raw).as_ref()?,
+            )
+            .to_str()
+            {
+                Ok(s) => Some(s),
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// Set the local directory in which files from the remote platform
+    /// are cached.
+    pub fn set_local_cache_directory(&self, path: &str) {
+        let path = CString::new(path).unwrap();
+        unsafe { sys::SBPlatformConnectOptionsSetLocalCacheDirectory(self.raw, path.as_ptr()) };
+    }
+}
+
+impl Clone for SBPlatformConnectOptions {
+    fn clone(&self) -> SBPlatformConnectOptions {
+        SBPlatformConnectOptions {
+            raw: unsafe { sys::CloneSBPlatformConnectOptions(self.raw) },
+        }
+    }
+}
+
+impl Drop for SBPlatformConnectOptions {
+    fn drop(&mut self) {
+        unsafe { sys::DisposeSBPlatformConnectOptions(self.raw) };
+    }
+}
+
+unsafe impl Send for SBPlatformConnectOptions {}
+unsafe impl Sync for SBPlatformConnectOptions {}
+
+/// Build the `rsync` option which makes it use `ssh` with the given
+/// login and private key.
+///
+/// The `ssh` command is split into words by `rsync`, which honors
+/// double quotes, and the whole option is then passed through the
+/// shell by LLDB, so each value is quoted for both.
+fn rsync_ssh_option(user: Option<&str>, identity_file: Option<&str>) -> String {
+    // Unlike the command interpreter, `rsync` gives backticks no
+    // special meaning, so they are left alone.
+    fn rsync_quote(argument: &str) -> String {
+        double_quote(argument, &['"', '\\'])
+    }
+
+    let mut ssh = String::from("ssh");
+    if let Some(user) = user {
+        ssh.push_str(" -l ");
+        ssh.push_str(&rsync_quote(user));
+    }
+    if let Some(identity_file) = identity_file {
+        ssh.push_str(" -i ");
+        ssh.push_str(&rsync_quote(identity_file));
+    }
+    format!("-e '{}'", ssh.replace('\'', "'\\''"))
+}
+
+/// The broad reason that [connecting to a remote platform] failed.
+///
+/// LLDB only reports these failures as messages, so this is a best
+/// effort classification of the error text, intended to let a user
+/// interface give more targeted feedback.
+///
+/// [connecting to a remote platform]: crate::SBPlatform::connect()
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnectErrorKind {
+    /// The remote end refused the credentials, or the device has not
+    /// authorized this host.
+    AuthenticationFailed,
+    /// The remote host or device could not be reached.
+    Unreachable,
+    /// The remote server speaks an incompatible protocol version.
+    VersionMismatch,
+    /// Any other failure.
+    Other,
+}
+
+impl ConnectErrorKind {
+    /// Classify a connection error.
+    pub fn classify(error: &SBError) -> ConnectErrorKind {
+        ConnectErrorKind::classify_message(error.error_string())
+    }
+
+    /// Classify a connection error by the messages that LLDB, the
+    /// system, `ssh` and `adb` produce for each kind of failure.
+    fn classify_message(message: &str) -> ConnectErrorKind {
+        let message = message.to_ascii_lowercase();
+        let any = |needles: &[&str]| needles.iter().any(|n| message.contains(n));
+        if any(&[
+            // ssh, from `rsync`.
+            "permission denied (",
+            "host key verification failed",
+            // adb, before the device has accepted this host.
+            "device unauthorized",
+        ]) {
+            ConnectErrorKind::AuthenticationFailed
+        } else if any(&[
+            // adb, when the server and client differ.
+            "doesn't match this client",
+            "unsupported protocol version",
+        ]) {
+            ConnectErrorKind::VersionMismatch
+        } else if any(&[
+            // `strerror()` of a failed `connect()`.
+            "connection refused",
+            "connection timed out",
+            "no route to host",
+            "network is unreachable",
+            "host is down",
+            // `gai_strerror()` of a failed host lookup.
+            "name or service not known",
+            "nodename nor servname provided",
+            // LLDB's sockets.
+            "failed to connect port",
+            // adb, for an unknown serial number.
+            "no devices/emulators found",
+        ]) || (message.starts_with("device '") && message.ends_with("' not found"))
+        {
+            ConnectErrorKind::Unreachable
+        } else {
+            ConnectErrorKind::Other
+        }
+    }
+}
+
+impl fmt::Display for ConnectErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ConnectErrorKind::AuthenticationFailed => "authentication failed",
+            ConnectErrorKind::Unreachable => "remote unreachable",
+            ConnectErrorKind::VersionMismatch => "protocol version mismatch",
+            ConnectErrorKind::Other => "connection failed",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rsync_ssh_option, ConnectErrorKind};
+
+    #[test]
+    fn test_rsync_ssh_option() {
+        assert_eq!(rsync_ssh_option(None, None), "-e 'ssh'");
+        assert_eq!(
+            rsync_ssh_option(Some("dev"), Some("/keys/id rsa")),
+            r#"-e 'ssh -l "dev" -i "/keys/id rsa"'"#
+        );
+        assert_eq!(
+            rsync_ssh_option(Some(r#"a'b"c"#), None),
+            r#"-e 'ssh -l "a'\''b\"c"'"#
+        );
+    }
+
+    #[test]
+    fn test_classify() {
+        let classify = ConnectErrorKind::classify_message;
+        assert_eq!(
+            classify("dev@host: Permission denied (publickey,password)."),
+            ConnectErrorKind::AuthenticationFailed
+        );
+        assert_eq!(
+            classify("Host key verification failed."),
+            ConnectErrorKind::AuthenticationFailed
+        );
+        assert_eq!(
+            classify("device unauthorized."),
+            ConnectErrorKind::AuthenticationFailed
+        );
+        assert_eq!(
+            classify("adb server version (40) doesn't match this client (41)"),
+            ConnectErrorKind::VersionMismatch
+        );
+        assert_eq!(
+            classify("Failed to connect port"),
+            ConnectErrorKind::Unreachable
+        );
+        assert_eq!(
+            classify("Connection refused"),
+            ConnectErrorKind::Unreachable
+        );
+        assert_eq!(
+            classify("device 'emulator-5554' not found"),
+            ConnectErrorKind::Unreachable
+        );
+        assert_eq!(
+            classify("invalid platform version string"),
+            ConnectErrorKind::Other
+        );
+        assert_eq!(
+            classify("platform plugin not found"),
+            ConnectErrorKind::Other
+        );
+    }
+}
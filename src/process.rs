@@ -5,12 +5,346 @@
 // except according to those terms.
 
 use crate::{
-    lldb_addr_t, lldb_pid_t, lldb_tid_t, sys, Permissions, SBBroadcaster, SBError, SBEvent,
-    SBFileSpec, SBMemoryRegionInfo, SBMemoryRegionInfoList, SBProcessInfo, SBQueue, SBStream,
-    SBStructuredData, SBTarget, SBThread, StateType,
+    lldb_addr_t, lldb_pid_t, lldb_tid_t, sys, ExtendedCrashInfo, InstrumentationRuntimeType,
+    Permissions, SBBroadcaster, SBError, SBEvent, SBFileSpec, SBFrame, SBListener,
+    SBMemoryRegionInfo, SBMemoryRegionInfoList, SBProcessInfo, SBQueue, SBStream, SBStructuredData,
+    SBTarget, SBThread, SBUnixSignals, SanitizerReport, Signal, StateType, StopHooks, StopReason,
+    ThreadInfo, LLDB_INVALID_PROCESS_ID,
 };
+use std::borrow::Cow;
+use std::collections::VecDeque;
 use std::ffi::{CStr, CString};
 use std::fmt;
+use std::io;
+use std::ops::Range;
+use std::os::raw::c_char;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// What is backing a process, as returned by [`SBProcess::kind()`].
+///
+/// Only live processes can really be resumed or have their memory
+/// and registers modified.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProcessKind {
+    /// A running process, local or remote.
+    Live,
+    /// A core file or minidump.
+    Core,
+    /// A scripted process, implemented in Python.
+    Scripted,
+}
+
+/// Which standard stream of a process some output came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OutputStream {
+    /// The standard output stream.
+    Stdout,
+    /// The standard error stream.
+    Stderr,
+}
+
+/// A chunk of output from a process, tagged with the stream it came
+/// from and when it was read.
+///
+/// The timestamps are monotonic, so chunks from both streams can be
+/// interleaved correctly with each other and with other events, such
+/// as process state changes, when logging or replaying a session.
+///
+/// See [`SBProcess::read_output()`] and [`SBProcessEvent::output()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutputChunk {
+    /// The stream that the output came from.
+    pub stream: OutputStream,
+    /// The output. This is not necessarily valid UTF-8 and may end
+    /// part way through a multi-byte character.
+    pub bytes: Vec<u8>,
+    /// When the output was read from the process.
+    pub at: Instant,
+}
+
+impl OutputChunk {
+    /// The output as a string, replacing invalid UTF-8 sequences
+    /// with `U+FFFD REPLACEMENT CHARACTER`.
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.bytes)
+    }
+}
+
+/// A blocking [`io::Read`] adapter for one of the standard streams
+/// of a process.
+///
+/// Reads wait for output to become available and only return `0`, the
+/// end of the stream, once the process has exited and all of its output
+/// has been read. The output is passed through as bytes, so wrapping
+/// the reader in a [`BufReader`] and reading lines decodes UTF-8
+/// correctly even when a character is split between chunks of output.
+///
+/// ```no_run
+/// use lldb::SBProcess;
+/// use std::io::{BufRead, BufReader};
+///
+/// fn print_output(process: &SBProcess) {
+///     for line in BufReader::new(process.stdout_reader()).lines() {
+///         println!("> {}", line.unwrap());
+///     }
+/// }
+/// ```
+///
+/// Waiting relies on the process broadcasting its output and state
+/// changes, so this should be used with a process that is running
+/// [asynchronously].
+///
+/// A stopped process produces no output, so while the process is
+/// stopped, such as at a breakpoint, reads block until it is resumed
+/// and writes something or exits. Read on a thread that doesn't need
+/// to respond to stops, or use [`SBProcess::read_output_timeout()`]
+/// to wait with a timeout instead.
+///
+/// [`BufReader`]: std::io::BufReader
+/// [asynchronously]: crate::SBDebugger::set_asynchronous()
+pub struct ProcessOutputReader {
+    process: SBProcess,
+    stream: OutputStream,
+    listener: SBListener,
+}
+
+impl ProcessOutputReader {
+    fn new(process: SBProcess, stream: OutputStream) -> ProcessOutputReader {
+        let listener = SBListener::new();
+        listener.start_listening_for_events(&process.broadcaster(), Self::event_mask(stream));
+        ProcessOutputReader {
+            process,
+            stream,
+            listener,
+        }
+    }
+
+    fn event_mask(stream: OutputStream) -> u32 {
+        SBProcessEvent::BROADCAST_BIT_STATE_CHANGED
+            | match stream {
+                OutputStream::Stdout => SBProcessEvent::BROADCAST_BIT_STDOUT,
+                OutputStream::Stderr => SBProcessEvent::BROADCAST_BIT_STDERR,
+            }
+    }
+
+    /// The process whose output is read.
+    pub fn process(&self) -> &SBProcess {
+        &self.process
+    }
+
+    /// The stream which is read.
+    pub fn stream(&self) -> OutputStream {
+        self.stream
+    }
+}
+
+impl io::Read for ProcessOutputReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let event = SBEvent::new();
+        loop {
+            // Output can arrive before the event announcing it, so check
+            // before each wait.
+            let len = self.process.read_output_into(self.stream, buf);
+            if len > 0 || !self.process.is_alive() {
+                return Ok(len);
+            }
+            self.listener.wait_for_event(1, &event);
+        }
+    }
+}
+
+impl Drop for ProcessOutputReader {
+    fn drop(&mut self) {
+        self.listener
+            .stop_listening_for_events(&self.process.broadcaster(), Self::event_mask(self.stream));
+    }
+}
+
+/// A typed process event, from [`SBProcess::events()`].
+#[derive(Debug)]
+pub enum ProcessEventKind {
+    /// The process changed state.
+    StateChanged(StateType),
+    /// The process wrote to its stdout. This is not necessarily valid
+    /// UTF-8 and may end part way through a multi-byte character.
+    Stdout(Vec<u8>),
+    /// The process wrote to its stderr. This is not necessarily valid
+    /// UTF-8 and may end part way through a multi-byte character.
+    Stderr(Vec<u8>),
+    /// The process was interrupted.
+    Interrupted,
+    /// A structured data plugin, such as `DarwinLog`, sent data.
+    StructuredData(SBStructuredData),
+}
+
+/// An iterator over the typed events of a process.
+///
+/// This is returned by [`SBProcess::events()`]. Each call to `next()`
+/// waits for the process to broadcast an event. The iterator ends
+/// after the process exits or is detached, once its remaining output
+/// has been returned.
+///
+/// Stops that LLDB immediately restarted from, such as those for
+/// breakpoints whose conditions weren't met, are skipped.
+///
+/// ```no_run
+/// use lldb::{ProcessEventKind, SBListener, SBProcess, StateType};
+/// use std::io::Write;
+///
+/// fn run_until_exit(process: &SBProcess) {
+///     let listener = SBListener::new();
+///     for event in process.events(&listener) {
+///         match event {
+///             ProcessEventKind::Stdout(bytes) => std::io::stdout().write_all(&bytes).unwrap(),
+///             ProcessEventKind::Stderr(bytes) => std::io::stderr().write_all(&bytes).unwrap(),
+///             ProcessEventKind::StateChanged(StateType::Stopped) => {
+///                 let _ = process.continue_execution();
+///             }
+///             _ => {}
+///         }
+///     }
+/// }
+/// ```
+///
+/// [Stop hooks](StopHooks) can be run for each stop with
+/// [`ProcessEvents::with_stop_hooks()`].
+///
+/// Waiting relies on the process broadcasting its events, so this
+/// should be used with a process that is running [asynchronously].
+///
+/// [asynchronously]: crate::SBDebugger::set_asynchronous()
+pub struct ProcessEvents<'d> {
+    process: SBProcess,
+    listener: &'d SBListener,
+    stop_hooks: Option<&'d mut StopHooks>,
+    pending: VecDeque<ProcessEventKind>,
+    last_state: Option<StateType>,
+    finished: bool,
+}
+
+impl<'d> ProcessEvents<'d> {
+    const EVENT_MASK: u32 = SBProcessEvent::BROADCAST_BIT_STATE_CHANGED
+        | SBProcessEvent::BROADCAST_BIT_INTERRUPT
+        | SBProcessEvent::BROADCAST_BIT_STDOUT
+        | SBProcessEvent::BROADCAST_BIT_STDERR
+        | SBProcessEvent::BROADCAST_BIT_STRUCTURED_DATA;
+
+    fn new(process: SBProcess, listener: &'d SBListener) -> ProcessEvents<'d> {
+        listener.start_listening_for_events(&process.broadcaster(), Self::EVENT_MASK);
+        ProcessEvents {
+            process,
+            listener,
+            stop_hooks: None,
+            pending: VecDeque::new(),
+            last_state: None,
+            finished: false,
+        }
+    }
+
+    /// Run `hooks` each time the process stops, before the stop is
+    /// returned from the iterator.
+    pub fn with_stop_hooks(mut self, hooks: &'d mut StopHooks) -> ProcessEvents<'d> {
+        self.stop_hooks = Some(hooks);
+        self
+    }
+
+    fn queue_output(&mut self) {
+        for stream in [OutputStream::Stdout, OutputStream::Stderr] {
+            if let Some(chunk) = self.process.read_output(stream) {
+                self.pending.push_back(match stream {
+                    OutputStream::Stdout => ProcessEventKind::Stdout(chunk.bytes),
+                    OutputStream::Stderr => ProcessEventKind::Stderr(chunk.bytes),
+                });
+            }
+        }
+    }
+
+    fn queue_state(&mut self, state: StateType) {
+        if matches!(
+            state,
+            StateType::Exited | StateType::Detached | StateType::Invalid
+        ) {
+            // Output can arrive after the event announcing the exit.
+            self.queue_output();
+            self.finished = true;
+        }
+        self.last_state = Some(state);
+        self.pending
+            .push_back(ProcessEventKind::StateChanged(state));
+    }
+
+    fn queue_event(&mut self, event: &SBEvent) {
+        let Some(process_event) = SBProcess::event_as_process_event(event) else {
+            return;
+        };
+        let event_type = event.event_type();
+        if event_type & SBProcessEvent::BROADCAST_BIT_STATE_CHANGED != 0 {
+            let state = process_event.process_state();
+            if state == StateType::Stopped && process_event.restarted() {
+                return;
+            }
+            if process_event.interrupted() {
+                self.pending.push_back(ProcessEventKind::Interrupted);
+            }
+            if let Some(hooks) = self.stop_hooks.as_deref_mut() {
+                process_event.run_stop_hooks(hooks);
+            }
+            self.queue_state(state);
+        } else if event_type & SBProcessEvent::BROADCAST_BIT_INTERRUPT != 0 {
+            self.pending.push_back(ProcessEventKind::Interrupted);
+        } else if event_type
+            & (SBProcessEvent::BROADCAST_BIT_STDOUT | SBProcessEvent::BROADCAST_BIT_STDERR)
+            != 0
+        {
+            self.queue_output();
+        } else if unsafe { sys::SBProcessEventIsStructuredDataEvent(event.raw) } {
+            if let Some(data) = SBStructuredData::maybe_wrap(unsafe {
+                sys::SBProcessGetStructuredDataFromEvent(event.raw)
+            }) {
+                self.pending
+                    .push_back(ProcessEventKind::StructuredData(data));
+            }
+        }
+    }
+}
+
+impl Iterator for ProcessEvents<'_> {
+    type Item = ProcessEventKind;
+
+    fn next(&mut self) -> Option<ProcessEventKind> {
+        let event = SBEvent::new();
+        loop {
+            if let Some(kind) = self.pending.pop_front() {
+                return Some(kind);
+            }
+            if self.finished {
+                return None;
+            }
+            if self.listener.wait_for_event(1, &event) {
+                self.queue_event(&event);
+            } else if !self.process.is_alive() {
+                // The exit was broadcast before we started listening.
+                // Other states in which the process isn't alive, such
+                // as `Connected`, are only reported once.
+                let state = self.process.state();
+                if self.last_state != Some(state) {
+                    self.queue_state(state);
+                }
+            }
+        }
+    }
+}
+
+impl Drop for ProcessEvents<'_> {
+    fn drop(&mut self) {
+        self.listener
+            .stop_listening_for_events(&self.process.broadcaster(), Self::EVENT_MASK);
+    }
+}
 
 /// The process associated with the target program.
 ///
@@ -211,6 +545,21 @@ impl SBProcess {
         unsafe { sys::SBProcessGetExitStatus(self.raw) }
     }
 
+    /// The exit status of the process, or `None` if the process
+    /// has not exited.
+    ///
+    /// See also:
+    ///
+    /// - [`SBProcess::exit_status()`]
+    /// - [`SBProcess::state()`]
+    pub fn try_exit_status(&self) -> Option<i32> {
+        if self.state() == StateType::Exited {
+            Some(self.exit_status())
+        } else {
+            None
+        }
+    }
+
     /// The exit description of the process when the process state
     /// is `Exited`.
     ///
@@ -229,10 +578,22 @@ impl SBProcess {
     }
 
     /// Returns the process ID of the process.
+    ///
+    /// If this process is not valid, this will be
+    /// [`LLDB_INVALID_PROCESS_ID`]. See also [`SBProcess::try_process_id()`].
     pub fn process_id(&self) -> lldb_pid_t {
         unsafe { sys::SBProcessGetProcessID(self.raw) }
     }
 
+    /// Returns the process ID of the process, or `None` if this
+    /// process is not valid.
+    pub fn try_process_id(&self) -> Option<lldb_pid_t> {
+        match self.process_id() {
+            LLDB_INVALID_PROCESS_ID => None,
+            pid => Some(pid),
+        }
+    }
+
     /// Returns an integer ID that is guaranteed to be unique across all
     /// process instances. This is not the process ID, just a unique
     /// integer for comparison and caching purposes.
@@ -240,11 +601,47 @@ impl SBProcess {
         unsafe { sys::SBProcessGetUniqueID(self.raw) }
     }
 
+    /// Returns the number of times that this process has stopped.
+    ///
+    /// This can be used to tell whether cached information about the
+    /// process, such as variable values, is still current. If
+    /// `include_expression_stops` is `true`, stops caused by running
+    /// expressions are included in the count.
+    pub fn stop_id(&self, include_expression_stops: bool) -> u32 {
+        unsafe { sys::SBProcessGetStopID(self.raw, include_expression_stops) }
+    }
+
     /// Get the size, in bytes, of an address.
     pub fn address_byte_size(&self) -> u32 {
         unsafe { sys::SBProcessGetAddressByteSize(self.raw) }
     }
 
+    /// The name of the process plugin, such as `gdb-remote` when
+    /// connected to a remote debug server.
+    pub fn plugin_name(&self) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(sys::SBProcessGetPluginName(self.raw).as_ref()?).to_str() {
+                Ok(s) => Some(s),
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// What kind of implementation is backing this process, based
+    /// upon its [plugin name](SBProcess::plugin_name()).
+    pub fn kind(&self) -> ProcessKind {
+        match self.plugin_name() {
+            Some("ScriptedProcess") => ProcessKind::Scripted,
+            Some("elf-core") | Some("mach-o-core") | Some("minidump") => ProcessKind::Core,
+            _ => ProcessKind::Live,
+        }
+    }
+
+    /// Is this a scripted process, implemented in Python?
+    pub fn is_scripted(&self) -> bool {
+        self.kind() == ProcessKind::Scripted
+    }
+
     /// Kills the process and shuts down all threads that were spawned to
     /// track and monitor the process.
     pub fn destroy(&self) -> Result<(), SBError> {
@@ -266,6 +663,90 @@ impl SBProcess {
         }
     }
 
+    /// Continue the process and wait for it to stop again, returning
+    /// the state that it stopped in.
+    ///
+    /// This issues [`continue_execution()`] and then waits on `listener`
+    /// for the state-changed event that ends the run. Events for a stop
+    /// that was immediately restarted, such as one for a breakpoint whose
+    /// condition wasn't met, are ignored, as are the intermediate running
+    /// states. The returned state is one of the stopped states, or
+    /// [`StateType::Exited`], [`StateType::Detached`] or [`StateType::Crashed`].
+    ///
+    /// In asynchronous mode, `listener` is made to listen for state
+    /// changes of this process; other events that it receives while
+    /// waiting are consumed. State changes of this process which were
+    /// already queued on `listener` before the process was continued
+    /// are discarded.
+    ///
+    /// In synchronous mode, LLDB waits for the process to stop itself
+    /// and doesn't deliver the events to `listener`, so `listener` and
+    /// `timeout` are not used and the state is returned once
+    /// [`continue_execution()`] does.
+    ///
+    /// An error is returned if the process could not be continued or if
+    /// it did not stop within `timeout`. In the latter case, the process
+    /// is still running.
+    ///
+    /// [`continue_execution()`]: SBProcess::continue_execution()
+    pub fn continue_and_wait(
+        &self,
+        listener: &SBListener,
+        timeout: Duration,
+    ) -> Result<StateType, SBError> {
+        let synchronous = self
+            .target()
+            .is_some_and(|target| !target.debugger().asynchronous());
+        if synchronous {
+            self.continue_execution()?;
+            return Ok(self.state());
+        }
+        let broadcaster = self.broadcaster();
+        listener
+            .start_listening_for_events(&broadcaster, SBProcessEvent::BROADCAST_BIT_STATE_CHANGED);
+        // Discard state changes from before this run, such as the stop
+        // being continued from, so that they aren't mistaken for its end.
+        let stale = SBEvent::new();
+        while listener.get_next_event_for_broadcaster_with_type(
+            &broadcaster,
+            SBProcessEvent::BROADCAST_BIT_STATE_CHANGED,
+            &stale,
+        ) {}
+        self.continue_execution()?;
+        let deadline = Instant::now() + timeout;
+        let unique_id = self.unique_id();
+        // A stop only ends this run once the process has been seen to
+        // resume.
+        let mut resumed = false;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(SBError::from_message(
+                    "timed out waiting for the process to stop",
+                ));
+            }
+            let Some(event) = listener.wait(remaining) else {
+                continue;
+            };
+            if event.event_type() & SBProcessEvent::BROADCAST_BIT_STATE_CHANGED == 0 {
+                continue;
+            }
+            let Some(process_event) = SBProcess::event_as_process_event(&event) else {
+                continue;
+            };
+            if process_event.process().unique_id() != unique_id {
+                continue;
+            }
+            match process_event.process_state() {
+                StateType::Running | StateType::Stepping | StateType::Launching => resumed = true,
+                StateType::Stopped if process_event.restarted() => {}
+                state @ (StateType::Exited | StateType::Detached) => return Ok(state),
+                state if resumed => return Ok(state),
+                _ => {}
+            }
+        }
+    }
+
     #[allow(missing_docs)]
     pub fn stop(&self) -> Result<(), SBError> {
         let error = SBError::wrap(unsafe { sys::SBProcessStop(self.raw) });
@@ -276,6 +757,100 @@ impl SBProcess {
         }
     }
 
+    /// Interrupt the process without waiting for it to stop.
+    ///
+    /// This is safe to call from another thread while the debugger is
+    /// busy, such as while an expression is being evaluated in
+    /// synchronous mode, and can be used to cancel long-running
+    /// operations.
+    ///
+    /// # Interrupting Expressions
+    ///
+    /// When an expression that calls functions in the target is being
+    /// [evaluated], the process is running. Interrupting it stops the
+    /// function call and the evaluation returns a value whose error
+    /// describes the interruption. If the expression options
+    /// [unwind on error], the stack is restored to how it was before the
+    /// call, so the debugging session can continue as usual.
+    ///
+    /// This complements [`SBExpressionOptions::set_timeout()`]: a
+    /// timeout bounds every evaluation up front, while an interrupt
+    /// lets a user cancel a particular evaluation at any time.
+    ///
+    /// ```no_run
+    /// use lldb::{SBExpressionOptions, SBFrame};
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// fn evaluate_cancellable(frame: &SBFrame, expression: &str) {
+    ///     let process = frame.thread().process();
+    ///     let watchdog = thread::spawn(move || {
+    ///         thread::sleep(Duration::from_secs(5));
+    ///         process.send_async_interrupt();
+    ///     });
+    ///     let options = SBExpressionOptions::new();
+    ///     options.set_unwind_on_error(true);
+    ///     let value = frame.evaluate_expression(expression, &options);
+    ///     if let Some(error) = value.error() {
+    ///         println!("Evaluation failed: {}", error);
+    ///     }
+    ///     watchdog.join().unwrap();
+    /// }
+    /// ```
+    ///
+    /// Interrupts are not queued: an interrupt sent when the process is
+    /// already stopped has no effect on a later evaluation.
+    ///
+    /// [evaluated]: crate::SBFrame::evaluate_expression()
+    /// [unwind on error]: crate::SBExpressionOptions::set_unwind_on_error()
+    /// [`SBExpressionOptions::set_timeout()`]: crate::SBExpressionOptions::set_timeout()
+    pub fn send_async_interrupt(&self) {
+        unsafe { sys::SBProcessSendAsyncInterrupt(self.raw) };
+    }
+
+    /// Stop the process, escalating if it does not stop in time.
+    ///
+    /// This first asks the process to stop, as with [`SBProcess::stop()`].
+    /// If the process has not stopped after half of `timeout` has
+    /// elapsed, it is sent a `SIGSTOP`, numbered as the platform of the
+    /// process numbers it. If it still has not stopped once `timeout`
+    /// has elapsed, an error is returned.
+    ///
+    /// In asynchronous mode, the process state only changes once
+    /// the corresponding events have been fetched from a listener,
+    /// so events should be handled on another thread while this runs.
+    ///
+    /// See also [`SBDebugger::set_interrupt_timeout()`].
+    ///
+    /// [`SBDebugger::set_interrupt_timeout()`]: crate::SBDebugger::set_interrupt_timeout()
+    pub fn stop_with_timeout(&self, timeout: Duration) -> Result<(), SBError> {
+        let start = Instant::now();
+        if self.is_stopped() {
+            return Ok(());
+        }
+        if self.stop().is_ok() && self.wait_until_stopped(start + timeout / 2) {
+            return Ok(());
+        }
+        // Signal numbers depend on the platform of the process, not the host.
+        self.send_signal(Signal::Stop)?;
+        if self.wait_until_stopped(start + timeout) {
+            Ok(())
+        } else {
+            Err(SBError::from_message(&format!(
+                "process did not stop within {timeout:?}"
+            )))
+        }
+    }
+
+    /// Poll the process state until it is no longer running or
+    /// `deadline` passes. Returns whether the process is stopped.
+    fn wait_until_stopped(&self, deadline: Instant) -> bool {
+        while self.is_running() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+        self.is_stopped()
+    }
+
     /// Same as calling `destroy`.
     pub fn kill(&self) -> Result<(), SBError> {
         let error = SBError::wrap(unsafe { sys::SBProcessKill(self.raw) });
@@ -306,22 +881,37 @@ impl SBProcess {
         }
     }
 
+    /// Send the process a [`Signal`], using the number that the
+    /// platform of the process has for it.
+    pub fn send_signal(&self, signal: Signal) -> Result<(), SBError> {
+        self.signal_by_name(signal.name())
+    }
+
+    /// Send the process the Unix signal named `name`, such as
+    /// `"SIGUSR1"`, using the number that the platform of the process
+    /// has for it.
+    pub fn signal_by_name(&self, name: &str) -> Result<(), SBError> {
+        let signo = self
+            .unix_signals()
+            .and_then(|signals| signals.signal_number_from_name(name))
+            .ok_or_else(|| SBError::from_message(&format!("unknown signal {name}")))?;
+        self.signal(signo)
+    }
+
+    /// The Unix signals of the platform of this process, and how the
+    /// debugger handles them.
+    pub fn unix_signals(&self) -> Option<SBUnixSignals> {
+        SBUnixSignals::maybe_wrap(unsafe { sys::SBProcessGetUnixSignals(self.raw) })
+    }
+
     /// Reads data from the current process's stdout stream until the end of the stream.
+    ///
+    /// Returns an empty string if there is no output, and `None` if the
+    /// output is not valid UTF-8. Use [`SBProcess::read_output()`] to
+    /// get the output as bytes.
     pub fn get_stdout_all(&self) -> Option<String> {
-        let dst_len = 0x1000;
-        let mut output = String::new();
-        let mut dst: Vec<u8> = Vec::with_capacity(dst_len);
-        loop {
-            let out_len =
-                unsafe { sys::SBProcessGetSTDOUT(self.raw, dst.as_mut_ptr() as *mut i8, dst_len) };
-            if out_len == 0 {
-                break;
-            }
-            unsafe { dst.set_len(out_len) };
-            output += std::str::from_utf8(&dst).ok()?;
-        }
-
-        Some(output)
+        let output = self.read_output(OutputStream::Stdout);
+        String::from_utf8(output.map(|output| output.bytes).unwrap_or_default()).ok()
     }
 
     /// Reads data from the current process's stdout stream.
@@ -337,21 +927,13 @@ impl SBProcess {
     }
 
     /// Reads data from the current process's stderr stream until the end of the stream.
+    ///
+    /// Returns an empty string if there is no output, and `None` if the
+    /// output is not valid UTF-8. Use [`SBProcess::read_output()`] to
+    /// get the output as bytes.
     pub fn get_stderr_all(&self) -> Option<String> {
-        let dst_len = 0x1000;
-        let mut output = String::new();
-        let mut dst: Vec<u8> = Vec::with_capacity(dst_len);
-        loop {
-            let out_len =
-                unsafe { sys::SBProcessGetSTDERR(self.raw, dst.as_mut_ptr() as *mut i8, dst_len) };
-            if out_len == 0 {
-                break;
-            }
-            unsafe { dst.set_len(out_len) };
-            output += std::str::from_utf8(&dst).ok()?;
-        }
-
-        Some(output)
+        let output = self.read_output(OutputStream::Stderr);
+        String::from_utf8(output.map(|output| output.bytes).unwrap_or_default()).ok()
     }
 
     /// Reads data from the current process's stderr stream.
@@ -366,6 +948,125 @@ impl SBProcess {
         String::from_utf8(dst).ok()
     }
 
+    /// Read all of the output currently available from one of the
+    /// process's standard streams, without blocking.
+    ///
+    /// Unlike [`SBProcess::get_stdout()`] and [`SBProcess::get_stderr()`],
+    /// the output is returned as bytes, so nothing is lost if it is not
+    /// valid UTF-8. Returns `None` if there is no output available.
+    pub fn read_output(&self, stream: OutputStream) -> Option<OutputChunk> {
+        let mut bytes = Vec::new();
+        let mut buf = [0u8; 0x1000];
+        loop {
+            let len = self.read_output_into(stream, &mut buf);
+            if len == 0 {
+                break;
+            }
+            bytes.extend_from_slice(&buf[..len]);
+        }
+        if bytes.is_empty() {
+            None
+        } else {
+            Some(OutputChunk {
+                stream,
+                bytes,
+                at: Instant::now(),
+            })
+        }
+    }
+
+    /// Read up to `buf.len()` bytes of the output currently available
+    /// from one of the process's standard streams, without blocking.
+    fn read_output_into(&self, stream: OutputStream, buf: &mut [u8]) -> usize {
+        unsafe {
+            let dst = buf.as_mut_ptr() as *mut c_char;
+            match stream {
+                OutputStream::Stdout => sys::SBProcessGetSTDOUT(self.raw, dst, buf.len()),
+                OutputStream::Stderr => sys::SBProcessGetSTDERR(self.raw, dst, buf.len()),
+            }
+        }
+    }
+
+    /// Iterate over the typed events of this process, as received by
+    /// `listener`.
+    ///
+    /// See [`ProcessEvents`].
+    pub fn events<'d>(&self, listener: &'d SBListener) -> ProcessEvents<'d> {
+        ProcessEvents::new(self.clone(), listener)
+    }
+
+    /// A blocking [`io::Read`] adapter for the process's stdout.
+    ///
+    /// See [`ProcessOutputReader`].
+    pub fn stdout_reader(&self) -> ProcessOutputReader {
+        ProcessOutputReader::new(self.clone(), OutputStream::Stdout)
+    }
+
+    /// A blocking [`io::Read`] adapter for the process's stderr.
+    ///
+    /// See [`ProcessOutputReader`].
+    pub fn stderr_reader(&self) -> ProcessOutputReader {
+        ProcessOutputReader::new(self.clone(), OutputStream::Stderr)
+    }
+
+    /// Read the output available from one of the process's standard
+    /// streams, waiting up to `timeout` for some to arrive.
+    ///
+    /// Rather than polling, this waits for the process to broadcast
+    /// that output is available. LLDB waits for events in whole seconds,
+    /// so the timeout is rounded up to the next second. Returns an empty
+    /// vector if no output arrived in time.
+    ///
+    /// The output is returned as bytes, so programs that write binary
+    /// or otherwise non-UTF-8 output are handled. To read output as it
+    /// arrives from an existing event loop instead, see
+    /// [`SBProcessEvent::output()`].
+    pub fn read_output_timeout(&self, stream: OutputStream, timeout: Duration) -> Vec<u8> {
+        if let Some(chunk) = self.read_output(stream) {
+            return chunk.bytes;
+        }
+        let event_mask = match stream {
+            OutputStream::Stdout => SBProcessEvent::BROADCAST_BIT_STDOUT,
+            OutputStream::Stderr => SBProcessEvent::BROADCAST_BIT_STDERR,
+        };
+        let listener = SBListener::new();
+        let broadcaster = self.broadcaster();
+        listener.start_listening_for_events(&broadcaster, event_mask);
+        let deadline = Instant::now() + timeout;
+        let event = SBEvent::new();
+        let bytes = loop {
+            // Output may have arrived before the listener was registered,
+            // so check again before each wait.
+            if let Some(chunk) = self.read_output(stream) {
+                break chunk.bytes;
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() || !self.is_alive() {
+                break Vec::new();
+            }
+            let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+            listener.wait_for_event(seconds.min(u64::from(u32::MAX - 1)) as u32, &event);
+        };
+        listener.stop_listening_for_events(&broadcaster, event_mask);
+        bytes
+    }
+
+    /// Read the output available from the process's stdout, waiting up
+    /// to `timeout` for some to arrive.
+    ///
+    /// See [`SBProcess::read_output_timeout()`].
+    pub fn read_stdout_timeout(&self, timeout: Duration) -> Vec<u8> {
+        self.read_output_timeout(OutputStream::Stdout, timeout)
+    }
+
+    /// Read the output available from the process's stderr, waiting up
+    /// to `timeout` for some to arrive.
+    ///
+    /// See [`SBProcess::read_output_timeout()`].
+    pub fn read_stderr_timeout(&self, timeout: Duration) -> Vec<u8> {
+        self.read_output_timeout(OutputStream::Stderr, timeout)
+    }
+
     #[allow(missing_docs)]
     pub fn broadcaster(&self) -> SBBroadcaster {
         SBBroadcaster::wrap(unsafe { sys::SBProcessGetBroadcaster(self.raw) })
@@ -376,6 +1077,19 @@ impl SBProcess {
         SBStructuredData::wrap(unsafe { sys::SBProcessGetExtendedCrashInformation(self.raw) })
     }
 
+    /// A typed view over the process' [extended crash information], if
+    /// there is any.
+    ///
+    /// [extended crash information]: SBProcess::get_extended_crash_information()
+    pub fn extended_crash_info(&self) -> Option<ExtendedCrashInfo> {
+        let data = self.get_extended_crash_information();
+        if data.is_valid() && data.size() > 0 {
+            Some(ExtendedCrashInfo::new(data))
+        } else {
+            None
+        }
+    }
+
     #[allow(missing_docs)]
     pub fn get_num_supported_hardware_watchpoints(&self) -> Result<u32, SBError> {
         let error = SBError::default();
@@ -397,6 +1111,51 @@ impl SBProcess {
         }
     }
 
+    /// Get a snapshot of the [threads] known to this process instance.
+    ///
+    /// LLDB has no API to fetch all threads at once, but unlike
+    /// [`SBProcess::threads()`], which asks for the number of threads
+    /// before each item, this asks only once. Since each call takes the
+    /// target's API lock, this is noticeably faster for processes with
+    /// many threads.
+    ///
+    /// Threads which exit while the snapshot is being taken are left
+    /// out, and threads which are created while it is being taken may
+    /// be missing, so this is only a snapshot of a process that isn't
+    /// running.
+    ///
+    /// [threads]: SBThread
+    pub fn threads_snapshot(&self) -> Vec<SBThread> {
+        let count = unsafe { sys::SBProcessGetNumThreads(self.raw) } as usize;
+        (0..count)
+            .filter_map(|idx| {
+                SBThread::maybe_wrap(unsafe { sys::SBProcessGetThreadAtIndex(self.raw, idx) })
+            })
+            .collect()
+    }
+
+    /// Gather the [details] of each thread in this process, such as for
+    /// a list of threads in a UI.
+    ///
+    /// [details]: ThreadInfo
+    pub fn thread_infos(&self) -> Vec<ThreadInfo> {
+        self.threads_snapshot().iter().map(SBThread::info).collect()
+    }
+
+    /// Get an owning iterator over the [threads] known to this process instance.
+    ///
+    /// Unlike [`SBProcess::threads()`], the returned iterator holds its own
+    /// reference to this process, so it can be returned from functions
+    /// or sent to other threads.
+    ///
+    /// [threads]: SBThread
+    pub fn owned_threads(&self) -> SBProcessOwnedThreadIter {
+        SBProcessOwnedThreadIter {
+            process: self.clone(),
+            idx: 0,
+        }
+    }
+
     /// Get an iterator over the [queues] known to this process instance.
     ///
     /// [queues]: SBQueue
@@ -437,6 +1196,31 @@ impl SBProcess {
         unsafe { sys::SBProcessSetSelectedThreadByIndexID(self.raw, thread_index_id) }
     }
 
+    /// Select a thread and one of its frames, as when a user clicks on
+    /// a frame in a backtrace.
+    ///
+    /// The frame is selected before the thread, so that anything
+    /// observing the thread selection sees the new frame. Returns the
+    /// selected frame, or `None` if there is no such thread or frame,
+    /// in which case the selection is unchanged.
+    pub fn select_thread_and_frame(
+        &self,
+        thread_id: lldb_tid_t,
+        frame_index: u32,
+    ) -> Option<SBFrame> {
+        let thread = self.thread_by_id(thread_id)?;
+        let previous_frame = thread.selected_frame();
+        let frame = thread.set_selected_frame(frame_index)?;
+        if self.set_selected_thread(&thread) {
+            Some(frame)
+        } else {
+            if previous_frame.is_valid() {
+                thread.set_selected_frame(previous_frame.frame_id());
+            }
+            None
+        }
+    }
+
     #[allow(missing_docs)]
     pub fn event_as_process_event(event: &SBEvent) -> Option<SBProcessEvent> {
         if unsafe { sys::SBProcessEventIsProcessEvent(event.raw) } {
@@ -446,6 +1230,47 @@ impl SBProcess {
         }
     }
 
+    /// The process that `event` reports as having stopped, unless it
+    /// was immediately restarted, such as for a breakpoint whose
+    /// condition wasn't met.
+    pub(crate) fn from_stop_event(event: &SBEvent) -> Option<SBProcess> {
+        let process_event = SBProcess::event_as_process_event(event)?;
+        process_event.is_stop().then(|| process_event.process())
+    }
+
+    /// Is the given instrumentation runtime, such as the address
+    /// sanitizer, loaded in the process?
+    pub fn is_instrumentation_runtime_present(&self, runtime: InstrumentationRuntimeType) -> bool {
+        unsafe { sys::SBProcessIsInstrumentationRuntimePresent(self.raw, runtime) }
+    }
+
+    /// The instrumentation runtimes, such as the address or thread
+    /// sanitizers, which are loaded in the process.
+    ///
+    /// Tools can use this to adapt their UI, such as by offering to show
+    /// [sanitizer reports] when a runtime is present.
+    ///
+    /// [sanitizer reports]: crate::SanitizerReport
+    pub fn instrumentation_runtimes(&self) -> Vec<InstrumentationRuntimeType> {
+        [
+            InstrumentationRuntimeType::AddressSanitizer,
+            InstrumentationRuntimeType::ThreadSanitizer,
+            InstrumentationRuntimeType::UndefinedBehaviorSanitizer,
+            InstrumentationRuntimeType::MainThreadChecker,
+            InstrumentationRuntimeType::SwiftRuntimeReporting,
+        ]
+        .into_iter()
+        .filter(|runtime| self.is_instrumentation_runtime_present(*runtime))
+        .collect()
+    }
+
+    /// Is any instrumentation runtime loaded in the process?
+    ///
+    /// See [`SBProcess::instrumentation_runtimes()`].
+    pub fn is_instrumented(&self) -> bool {
+        !self.instrumentation_runtimes().is_empty()
+    }
+
     /// Save the state of the process in a core file (or mini dump on Windows).
     pub fn save_core(&self, file_name: &str) -> Result<(), SBError> {
         let f = CString::new(file_name).unwrap();
@@ -582,6 +1407,16 @@ impl SBProcess {
     }
 
     /// Writes the `buffer` data to the memory at specified address in the process
+    ///
+    /// LLDB caches reads of process memory. Writing through this method
+    /// invalidates the cached memory for the written range, so later
+    /// reads return the new data. The whole cache is also discarded
+    /// whenever the process resumes, so there is no need to flush it
+    /// to observe changes made by the process itself.
+    ///
+    /// Breakpoint opcodes inserted by LLDB are also handled: writing
+    /// over a breakpoint site updates the saved original bytes, and
+    /// reads return the original bytes rather than the opcodes.
     pub fn write_memory(&self, addr: lldb_addr_t, buffer: &[u8]) -> Result<(), SBError> {
         let error = SBError::default();
         unsafe {
@@ -600,6 +1435,155 @@ impl SBProcess {
         }
     }
 
+    /// Writes the `buffer` data to the memory at the specified address in
+    /// the process and verifies the write by reading the memory back.
+    ///
+    /// This is useful when patching code, as writes to read-only or
+    /// executable memory can be silently dropped on some platforms.
+    pub fn write_memory_checked(&self, addr: lldb_addr_t, buffer: &[u8]) -> Result<(), SBError> {
+        self.write_memory(addr, buffer)?;
+        let mut written = vec![0; buffer.len()];
+        self.read_memory(addr, &mut written)?;
+        if written == buffer {
+            Ok(())
+        } else {
+            Err(SBError::from_message(&format!(
+                "memory at {addr:#x} does not match the data written to it"
+            )))
+        }
+    }
+
+    /// Read a NUL-terminated UTF-16 or UTF-32 string from the memory of
+    /// the process.
+    ///
+    /// `element_size` is the size in bytes of each code unit and must be
+    /// either 2 (UTF-16, as used by Windows `wchar_t`) or 4 (UTF-32, as
+    /// used by `wchar_t` on most other platforms). Code units are read
+    /// using the byte order of the process.
+    ///
+    /// At most `max_len` code units are read. The terminating NUL is not
+    /// included in the returned string.
+    ///
+    /// An error is returned if the memory can't be read or if it doesn't
+    /// contain a valid string.
+    pub fn read_wide_string_from_memory(
+        &self,
+        addr: lldb_addr_t,
+        element_size: usize,
+        max_len: usize,
+    ) -> Result<String, SBError> {
+        if element_size != 2 && element_size != 4 {
+            return Err(SBError::from_message("element size must be 2 or 4"));
+        }
+        let big_endian = match self.byte_order() {
+            crate::ByteOrder::Big => true,
+            crate::ByteOrder::Little => false,
+            _ => return Err(SBError::from_message("unsupported byte order")),
+        };
+
+        const PAGE_SIZE: u64 = 4096;
+        let mut units = Vec::new();
+        let mut buffer = Vec::new();
+        let mut cursor = addr;
+        'read: while units.len() < max_len {
+            // Avoid reading past the end of the page unless needed, as the
+            // string may end just before unreadable memory.
+            let to_page_end = (PAGE_SIZE - cursor % PAGE_SIZE) as usize;
+            let to_page_end = (to_page_end - to_page_end % element_size).max(element_size);
+            let len = ((max_len - units.len()) * element_size).min(to_page_end);
+            buffer.resize(len, 0);
+            self.read_memory(cursor, &mut buffer)?;
+            for bytes in buffer.chunks_exact(element_size) {
+                let unit = match (element_size, big_endian) {
+                    (2, true) => u32::from(u16::from_be_bytes([bytes[0], bytes[1]])),
+                    (2, false) => u32::from(u16::from_le_bytes([bytes[0], bytes[1]])),
+                    (_, true) => u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+                    (_, false) => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+                };
+                if unit == 0 {
+                    break 'read;
+                }
+                units.push(unit);
+            }
+            cursor += len as u64;
+        }
+
+        if element_size == 2 {
+            char::decode_utf16(units.into_iter().map(|u| u as u16))
+                .collect::<Result<String, _>>()
+                .map_err(|_| SBError::from_message("invalid UTF-16 string"))
+        } else {
+            units
+                .into_iter()
+                .map(char::from_u32)
+                .collect::<Option<String>>()
+                .ok_or_else(|| SBError::from_message("invalid UTF-32 string"))
+        }
+    }
+
+    /// Search the readable memory within `range` for `pattern`,
+    /// returning the address of each match.
+    ///
+    /// The search walks the [memory regions] overlapping `range`,
+    /// skipping those that aren't readable, and reads each region
+    /// in chunks. Matches that span two memory regions are not
+    /// reported.
+    ///
+    /// ```no_run
+    /// # use lldb::SBProcess;
+    /// # fn find_magic(process: &SBProcess) {
+    /// for addr in process.find_in_memory(b"\x7fELF", 0..0x7fff_ffff_ffff) {
+    ///     println!("ELF header at {addr:#x}");
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [memory regions]: SBMemoryRegionInfo
+    pub fn find_in_memory(&self, pattern: &[u8], range: Range<lldb_addr_t>) -> Vec<lldb_addr_t> {
+        const CHUNK_SIZE: u64 = 0x10000;
+
+        let mut matches = Vec::new();
+        if pattern.is_empty() {
+            return matches;
+        }
+        // Each chunk overlaps the next by enough to find matches
+        // which straddle the chunk boundary.
+        let overlap = pattern.len() as u64 - 1;
+        let mut buffer = Vec::new();
+        let mut addr = range.start;
+        while addr < range.end {
+            let Ok(region) = self.get_memory_region_info(addr) else {
+                break;
+            };
+            let region_end = region.get_region_end().min(range.end);
+            if region_end <= addr {
+                break;
+            }
+            if region.is_readable() {
+                let mut chunk_start = addr;
+                while chunk_start < region_end {
+                    let chunk_end = chunk_start
+                        .saturating_add(CHUNK_SIZE + overlap)
+                        .min(region_end);
+                    buffer.resize((chunk_end - chunk_start) as usize, 0);
+                    if self.read_memory(chunk_start, &mut buffer).is_err() {
+                        break;
+                    }
+                    matches.extend(
+                        buffer
+                            .windows(pattern.len())
+                            .enumerate()
+                            .filter(|(_, window)| *window == pattern)
+                            .map(|(offset, _)| chunk_start + offset as u64),
+                    );
+                    chunk_start = chunk_start.saturating_add(CHUNK_SIZE);
+                }
+            }
+            addr = region_end;
+        }
+        matches
+    }
+
     /// Returns the byte order of target process
     pub fn byte_order(&self) -> crate::ByteOrder {
         unsafe { sys::SBProcessGetByteOrder(self.raw) }
@@ -638,6 +1622,19 @@ impl SBProcess {
     pub fn target(&self) -> Option<SBTarget> {
         SBTarget::maybe_wrap(unsafe { sys::SBProcessGetTarget(self.raw) })
     }
+
+    /// Describe this process.
+    ///
+    /// LLDB only provides a single description of a process, so unlike
+    /// the `describe` methods of some other objects, this doesn't take
+    /// a [`DescriptionLevel`](crate::DescriptionLevel).
+    ///
+    /// This is also available via the `Display` implementation.
+    pub fn describe(&self) -> String {
+        let stream = SBStream::new();
+        unsafe { sys::SBProcessGetDescription(self.raw, stream.raw) };
+        stream.data().to_string()
+    }
 }
 
 /// Iterate over the [threads] in a [process].
@@ -670,6 +1667,52 @@ impl Iterator for SBProcessThreadIter<'_> {
     }
 }
 
+impl ExactSizeIterator for SBProcessThreadIter<'_> {}
+
+impl<'d> IntoIterator for &'d SBProcess {
+    type Item = SBThread;
+    type IntoIter = SBProcessThreadIter<'d>;
+
+    fn into_iter(self) -> SBProcessThreadIter<'d> {
+        self.threads()
+    }
+}
+
+/// Iterate over the [threads] in a [process], holding a reference
+/// to the process.
+///
+/// This is created by [`SBProcess::owned_threads()`].
+///
+/// [threads]: SBThread
+/// [process]: SBProcess
+pub struct SBProcessOwnedThreadIter {
+    process: SBProcess,
+    idx: usize,
+}
+
+impl Iterator for SBProcessOwnedThreadIter {
+    type Item = SBThread;
+
+    fn next(&mut self) -> Option<SBThread> {
+        if self.idx < unsafe { sys::SBProcessGetNumThreads(self.process.raw) as usize } {
+            let r = Some(SBThread::wrap(unsafe {
+                sys::SBProcessGetThreadAtIndex(self.process.raw, self.idx)
+            }));
+            self.idx += 1;
+            r
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let sz = unsafe { sys::SBProcessGetNumThreads(self.process.raw) } as usize;
+        (sz - self.idx, Some(sz))
+    }
+}
+
+impl ExactSizeIterator for SBProcessOwnedThreadIter {}
+
 /// Iterate over the [queues] in a [process].
 ///
 /// [queues]: SBQueue
@@ -713,9 +1756,13 @@ impl Clone for SBProcess {
 
 impl fmt::Debug for SBProcess {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let stream = SBStream::new();
-        unsafe { sys::SBProcessGetDescription(self.raw, stream.raw) };
-        write!(fmt, "SBProcess {{ {} }}", stream.data())
+        write!(fmt, "SBProcess {{ {} }}", self.describe())
+    }
+}
+
+impl fmt::Display for SBProcess {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}", self.describe())
     }
 }
 
@@ -755,6 +1802,80 @@ impl<'e> SBProcessEvent<'e> {
         unsafe { sys::SBProcessGetRestartedFromEvent(self.event.raw) }
     }
 
+    /// Read the output that this event announces, if it is a
+    /// [stdout](SBProcessEvent::BROADCAST_BIT_STDOUT) or
+    /// [stderr](SBProcessEvent::BROADCAST_BIT_STDERR) event.
+    ///
+    /// The chunk is timestamped when it is read, so this should be
+    /// called promptly upon receiving the event.
+    pub fn output(&self) -> Option<OutputChunk> {
+        let event_type = self.event.event_type();
+        let stream = if event_type & Self::BROADCAST_BIT_STDOUT != 0 {
+            OutputStream::Stdout
+        } else if event_type & Self::BROADCAST_BIT_STDERR != 0 {
+            OutputStream::Stderr
+        } else {
+            return None;
+        };
+        self.process().read_output(stream)
+    }
+
+    /// Does this event report that the process stopped, without it
+    /// being immediately restarted?
+    fn is_stop(&self) -> bool {
+        self.process_state() == StateType::Stopped && !self.restarted()
+    }
+
+    /// Run the [stop hooks] if this event reports that the process has
+    /// stopped, returning how many were run.
+    ///
+    /// [stop hooks]: StopHooks
+    pub fn run_stop_hooks(&self, hooks: &mut StopHooks) -> usize {
+        if self.is_stop() {
+            hooks.run(&self.process())
+        } else {
+            0
+        }
+    }
+
+    /// Does this event report that the process stopped because an
+    /// instrumentation runtime, such as the address sanitizer, detected an
+    /// issue?
+    pub fn is_instrumentation_stop(&self) -> bool {
+        self.is_stop()
+            && self
+                .process()
+                .threads()
+                .any(|thread| thread.stop_reason() == StopReason::Instrumentation)
+    }
+
+    /// The reports from instrumentation runtimes for the threads that
+    /// they stopped, if this event reports that the process stopped.
+    ///
+    /// ```no_run
+    /// # use lldb::{SBEvent, SBProcess};
+    /// # fn handle(event: &SBEvent) {
+    /// if let Some(process_event) = SBProcess::event_as_process_event(event) {
+    ///     for report in process_event.sanitizer_reports() {
+    ///         println!(
+    ///             "{}: {}",
+    ///             report.instrumentation_class().unwrap_or_default(),
+    ///             report.description().unwrap_or_default()
+    ///         );
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn sanitizer_reports(&self) -> Vec<SanitizerReport> {
+        if self.process_state() != StateType::Stopped || self.restarted() {
+            return Vec::new();
+        }
+        self.process()
+            .threads()
+            .filter_map(|thread| thread.sanitizer_report())
+            .collect()
+    }
+
     pub fn restarted_reasons(&self) -> SBProcessEventRestartedReasonIter {
         SBProcessEventRestartedReasonIter {
             event: self,
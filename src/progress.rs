@@ -0,0 +1,167 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{sys, SBEvent};
+use std::ffi::CStr;
+
+/// A progress report for a long running operation, such as loading
+/// a module or indexing its symbols.
+///
+/// Progress reports are broadcast by the debugger. See
+/// [`SBDebugger::progress_listener()`] and
+/// [`SBDebugger::event_as_progress_event()`].
+///
+/// Each operation sends a report when it starts, as it progresses and
+/// when it completes, all with the same [`id`].
+///
+/// ```no_run
+/// use lldb::SBDebugger;
+///
+/// fn show_progress(debugger: &SBDebugger) {
+///     let listener = debugger.progress_listener();
+///     for event in listener.events() {
+///         if let Some(progress) = SBDebugger::event_as_progress_event(&event) {
+///             match progress.fraction() {
+///                 Some(fraction) => println!("{}: {:.0}%", progress.title, fraction * 100.0),
+///                 None => println!("{}", progress.title),
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`SBDebugger::progress_listener()`]: crate::SBDebugger::progress_listener()
+/// [`SBDebugger::event_as_progress_event()`]: crate::SBDebugger::event_as_progress_event()
+/// [`id`]: Progress::id
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Progress {
+    /// Identifies the operation.
+    pub id: u64,
+    /// What the operation is doing, such as `"Loading Apple DWARF index"`.
+    pub title: String,
+    /// More details, such as the name of the module being processed.
+    pub details: Option<String>,
+    /// How many units of work have been completed.
+    pub completed: u64,
+    /// How many units of work there are in total, or `u64::MAX` when
+    /// this is not known.
+    pub total: u64,
+    /// Is this report about the work of one debugger rather than work
+    /// shared by all debuggers?
+    pub is_debugger_specific: bool,
+}
+
+impl Progress {
+    pub(crate) fn from_event(event: &SBEvent) -> Option<Progress> {
+        let mut id = 0;
+        let mut completed = 0;
+        let mut total = 0;
+        let mut is_debugger_specific = false;
+        let message = unsafe {
+            sys::SBDebuggerGetProgressFromEvent(
+                event.raw,
+                &mut id,
+                &mut completed,
+                &mut total,
+                &mut is_debugger_specific,
+            )
+            .as_ref()
+        }?;
+        let message = unsafe { CStr::from_ptr(message) }.to_string_lossy();
+        let (title, details) = Progress::split_message(&message);
+        Some(Progress {
+            id,
+            title,
+            details,
+            completed,
+            total,
+            is_debugger_specific,
+        })
+    }
+
+    /// Split a progress message into its title and details.
+    ///
+    /// LLDB only passes on a message, which is the title followed by
+    /// the details, if there are any.
+    fn split_message(message: &str) -> (String, Option<String>) {
+        match message.split_once(": ") {
+            Some((title, details)) => (title.to_string(), Some(details.to_string())),
+            None => (message.to_string(), None),
+        }
+    }
+
+    /// Is the total amount of work known?
+    pub fn is_determinate(&self) -> bool {
+        self.total != u64::MAX
+    }
+
+    /// Has the operation completed?
+    pub fn is_complete(&self) -> bool {
+        self.completed == self.total
+    }
+
+    /// The fraction of the work which has been completed, between `0.0`
+    /// and `1.0`, if the total amount of work is known.
+    pub fn fraction(&self) -> Option<f64> {
+        if self.is_determinate() && self.total > 0 {
+            Some(self.completed as f64 / self.total as f64)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Progress;
+
+    fn progress(completed: u64, total: u64) -> Progress {
+        Progress {
+            id: 1,
+            title: "Indexing".to_string(),
+            details: None,
+            completed,
+            total,
+            is_debugger_specific: false,
+        }
+    }
+
+    #[test]
+    fn test_split_message() {
+        assert_eq!(
+            Progress::split_message("Loading DWARF index: libfoo.so"),
+            (
+                "Loading DWARF index".to_string(),
+                Some("libfoo.so".to_string())
+            )
+        );
+        assert_eq!(
+            Progress::split_message("Parsing symbol table: a: b"),
+            ("Parsing symbol table".to_string(), Some("a: b".to_string()))
+        );
+        assert_eq!(
+            Progress::split_message("Launching"),
+            ("Launching".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(progress(0, 4).fraction(), Some(0.0));
+        assert_eq!(progress(1, 4).fraction(), Some(0.25));
+        assert_eq!(progress(4, 4).fraction(), Some(1.0));
+        assert_eq!(progress(0, 0).fraction(), None);
+        assert_eq!(progress(3, u64::MAX).fraction(), None);
+    }
+
+    #[test]
+    fn test_completion() {
+        assert!(progress(4, 4).is_complete());
+        assert!(!progress(3, 4).is_complete());
+        assert!(progress(3, 4).is_determinate());
+        assert!(!progress(3, u64::MAX).is_determinate());
+    }
+}
@@ -102,6 +102,20 @@ impl SBQueue {
         }
     }
 
+    /// The number of [pending items] known to this queue.
+    ///
+    /// [pending items]: SBQueueItem
+    pub fn num_pending_items(&self) -> u32 {
+        unsafe { sys::SBQueueGetNumPendingItems(self.raw) }
+    }
+
+    /// The number of [threads] associated with this queue.
+    ///
+    /// [threads]: SBThread
+    pub fn num_threads(&self) -> u32 {
+        unsafe { sys::SBQueueGetNumThreads(self.raw) }
+    }
+
     /// The number of work items that this queue is currently running.
     ///
     /// For a serial queue, this will be `0` or `1`.  For a concurrent
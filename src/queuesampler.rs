@@ -0,0 +1,153 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{sys, SBEvent, SBProcess};
+use std::time::{Duration, Instant};
+
+/// The item counts for a single queue within a [`QueueSample`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QueueCounts {
+    /// The unique ID of the queue. See [`SBQueue::queue_id()`].
+    ///
+    /// [`SBQueue::queue_id()`]: crate::SBQueue::queue_id()
+    pub queue_id: u64,
+    /// The name of the queue.
+    pub name: String,
+    /// Whether the queue is serial or concurrent.
+    pub kind: sys::QueueKind,
+    /// The number of work items waiting to run.
+    pub pending_items: u32,
+    /// The number of work items currently running.
+    pub running_items: u32,
+    /// The number of threads servicing the queue.
+    pub threads: u32,
+}
+
+/// The state of every queue in a process at one stop.
+#[derive(Clone, Debug)]
+pub struct QueueSample {
+    /// The stop ID at which the sample was taken.
+    pub stop_id: u32,
+    /// The time since the sampler was created.
+    pub elapsed: Duration,
+    /// The counts for each queue.
+    pub queues: Vec<QueueCounts>,
+}
+
+/// Records the pending and running item counts of the `libdispatch`
+/// queues in a process over time.
+///
+/// Queue information can only be read while the process is stopped, so
+/// a sample is taken each time the process stops. To build a time
+/// series from a running process, stop it periodically, for example
+/// with [`SBProcess::stop()`] followed by [`SBProcess::continue_execution()`].
+///
+/// ```no_run
+/// # use lldb::{QueueSampler, SBEvent, SBListener};
+/// # fn sample(listener: &SBListener) {
+/// let mut sampler = QueueSampler::new();
+/// let event = SBEvent::new();
+/// while listener.wait_for_event(1, &event) {
+///     sampler.handle_event(&event);
+/// }
+/// for sample in sampler.samples() {
+///     for queue in &sample.queues {
+///         println!("{:?} {}: {} pending", sample.elapsed, queue.name, queue.pending_items);
+///     }
+/// }
+/// # }
+/// ```
+pub struct QueueSampler {
+    start: Instant,
+    last_stop_id: Option<u32>,
+    samples: Vec<QueueSample>,
+}
+
+impl QueueSampler {
+    /// Create a new sampler with no samples.
+    pub fn new() -> QueueSampler {
+        QueueSampler {
+            start: Instant::now(),
+            last_stop_id: None,
+            samples: Vec::new(),
+        }
+    }
+
+    /// Take a sample if `event` reports that a process has stopped.
+    ///
+    /// Returns whether or not a sample was taken.
+    pub fn handle_event(&mut self, event: &SBEvent) -> bool {
+        match SBProcess::from_stop_event(event) {
+            Some(process) => self.sample(&process),
+            None => false,
+        }
+    }
+
+    /// Take a sample of the queues in `process`.
+    ///
+    /// Nothing is recorded if the process isn't stopped or if a sample
+    /// has already been taken for the current stop. Returns whether or
+    /// not a sample was taken.
+    pub fn sample(&mut self, process: &SBProcess) -> bool {
+        if !process.is_stopped() {
+            return false;
+        }
+        let stop_id = process.stop_id(false);
+        if self.last_stop_id == Some(stop_id) {
+            return false;
+        }
+        self.last_stop_id = Some(stop_id);
+
+        let queues = process
+            .queues()
+            .map(|queue| QueueCounts {
+                queue_id: queue.queue_id(),
+                name: queue.name().to_string(),
+                kind: queue.kind(),
+                pending_items: queue.num_pending_items(),
+                running_items: queue.num_running_items(),
+                threads: queue.num_threads(),
+            })
+            .collect();
+        self.samples.push(QueueSample {
+            stop_id,
+            elapsed: self.start.elapsed(),
+            queues,
+        });
+        true
+    }
+
+    /// All of the samples taken so far, oldest first.
+    pub fn samples(&self) -> &[QueueSample] {
+        &self.samples
+    }
+
+    /// The counts for one queue over time, with the time since the
+    /// sampler was created.
+    ///
+    /// Samples where the queue didn't exist are skipped.
+    pub fn series(&self, queue_id: u64) -> impl Iterator<Item = (Duration, &QueueCounts)> {
+        self.samples.iter().filter_map(move |sample| {
+            sample
+                .queues
+                .iter()
+                .find(|queue| queue.queue_id == queue_id)
+                .map(|queue| (sample.elapsed, queue))
+        })
+    }
+
+    /// Discard all samples.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+        self.last_stop_id = None;
+    }
+}
+
+impl Default for QueueSampler {
+    fn default() -> QueueSampler {
+        QueueSampler::new()
+    }
+}
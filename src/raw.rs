@@ -0,0 +1,144 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Access to the underlying `lldb-sys` handles.
+//!
+//! This is only available when the `unsafe-raw` feature is enabled.
+
+use crate::*;
+
+/// Conversion to and from the raw `lldb-sys` handle underlying
+/// a wrapper type.
+///
+/// This is an escape hatch for calling functions in [`sys`] that
+/// do not yet have a safe binding in this crate. If you find yourself
+/// needing this, please file an issue so that a binding can be added.
+///
+/// # Example
+///
+/// Calling an unwrapped function and wrapping the result:
+///
+/// ```no_run
+/// use lldb::{sys, AsRaw, SBTarget, SBValue};
+/// use std::ffi::CString;
+///
+/// fn first_global(target: &SBTarget, name: &str) -> Option<SBValue> {
+///     let name = CString::new(name).unwrap();
+///     let raw = unsafe { sys::SBTargetFindFirstGlobalVariable(target.as_raw(), name.as_ptr()) };
+///     let value = unsafe { SBValue::from_raw(raw) };
+///     if value.is_valid() {
+///         Some(value)
+///     } else {
+///         None
+///     }
+/// }
+/// ```
+pub trait AsRaw: Sized {
+    /// The raw `lldb-sys` handle type.
+    type Raw: Copy;
+
+    /// Get the raw handle.
+    ///
+    /// The handle remains owned by `self` and must not be disposed.
+    fn as_raw(&self) -> Self::Raw;
+
+    /// Take ownership of a raw handle.
+    ///
+    /// The handle will be disposed when the returned value is dropped.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must be a handle of the right type, as returned from
+    /// [`sys`], which is not owned or disposed of elsewhere.
+    unsafe fn from_raw(raw: Self::Raw) -> Self;
+
+    /// Give up ownership of the raw handle.
+    ///
+    /// The caller becomes responsible for disposing of the handle, or
+    /// passing it back to [`AsRaw::from_raw()`].
+    fn into_raw(self) -> Self::Raw {
+        let raw = self.as_raw();
+        std::mem::forget(self);
+        raw
+    }
+}
+
+macro_rules! impl_as_raw {
+    ($($ty:ident => $raw:ident,)*) => {
+        $(
+            impl AsRaw for $ty {
+                type Raw = sys::$raw;
+
+                fn as_raw(&self) -> sys::$raw {
+                    self.raw
+                }
+
+                unsafe fn from_raw(raw: sys::$raw) -> Self {
+                    $ty { raw }
+                }
+            }
+        )*
+    };
+}
+
+impl_as_raw! {
+    SBAddress => SBAddressRef,
+    SBAttachInfo => SBAttachInfoRef,
+    SBBlock => SBBlockRef,
+    SBBreakpoint => SBBreakpointRef,
+    SBBreakpointList => SBBreakpointListRef,
+    SBBreakpointLocation => SBBreakpointLocationRef,
+    SBBroadcaster => SBBroadcasterRef,
+    SBCommandInterpreter => SBCommandInterpreterRef,
+    SBCompileUnit => SBCompileUnitRef,
+    SBData => SBDataRef,
+    SBDebugger => SBDebuggerRef,
+    SBError => SBErrorRef,
+    SBEvent => SBEventRef,
+    SBExpressionOptions => SBExpressionOptionsRef,
+    SBFile => SBFileRef,
+    SBFileSpec => SBFileSpecRef,
+    SBFileSpecList => SBFileSpecListRef,
+    SBFrame => SBFrameRef,
+    SBFunction => SBFunctionRef,
+    SBInstruction => SBInstructionRef,
+    SBInstructionList => SBInstructionListRef,
+    SBLaunchInfo => SBLaunchInfoRef,
+    SBLineEntry => SBLineEntryRef,
+    SBListener => SBListenerRef,
+    SBMemoryRegionInfo => SBMemoryRegionInfoRef,
+    SBMemoryRegionInfoList => SBMemoryRegionInfoListRef,
+    SBModule => SBModuleRef,
+    SBModuleSpec => SBModuleSpecRef,
+    SBPlatform => SBPlatformRef,
+    SBPlatformConnectOptions => SBPlatformConnectOptionsRef,
+    SBProcess => SBProcessRef,
+    SBProcessInfo => SBProcessInfoRef,
+    SBQueue => SBQueueRef,
+    SBQueueItem => SBQueueItemRef,
+    SBSection => SBSectionRef,
+    SBStream => SBStreamRef,
+    SBStringList => SBStringListRef,
+    SBStructuredData => SBStructuredDataRef,
+    SBSymbol => SBSymbolRef,
+    SBSymbolContext => SBSymbolContextRef,
+    SBSymbolContextList => SBSymbolContextListRef,
+    SBTarget => SBTargetRef,
+    SBThread => SBThreadRef,
+    SBType => SBTypeRef,
+    SBTypeCategory => SBTypeCategoryRef,
+    SBTypeFilter => SBTypeFilterRef,
+    SBTypeFormat => SBTypeFormatRef,
+    SBTypeList => SBTypeListRef,
+    SBTypeNameSpecifier => SBTypeNameSpecifierRef,
+    SBTypeSummary => SBTypeSummaryRef,
+    SBTypeSynthetic => SBTypeSyntheticRef,
+    SBUnixSignals => SBUnixSignalsRef,
+    SBValue => SBValueRef,
+    SBValueList => SBValueListRef,
+    SBVariablesOptions => SBVariablesOptionsRef,
+    SBWatchpoint => SBWatchpointRef,
+}
@@ -0,0 +1,141 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Decoding of CPU register values.
+//!
+//! LLDB presents status registers such as `rflags` or `cpsr` as plain
+//! integers. [`decode_flags()`] splits them into their named flag bits
+//! so that they can be displayed symbolically:
+//!
+//! ```no_run
+//! use lldb::registers;
+//!
+//! let flags = registers::decode_flags("x86_64-apple-macosx", 0x246);
+//! assert!(flags.contains(&("ZF", true)));
+//! assert!(flags.contains(&("CF", false)));
+//! ```
+//!
+//! See also [`SBFrame::flags()`](crate::SBFrame::flags).
+
+/// A named flag bit within a status register.
+struct FlagBit {
+    name: &'static str,
+    bit: u32,
+}
+
+const fn flag(name: &'static str, bit: u32) -> FlagBit {
+    FlagBit { name, bit }
+}
+
+/// The `eflags` / `rflags` status flags on x86 and x86-64.
+const X86_FLAGS: &[FlagBit] = &[
+    flag("CF", 0),
+    flag("PF", 2),
+    flag("AF", 4),
+    flag("ZF", 6),
+    flag("SF", 7),
+    flag("TF", 8),
+    flag("IF", 9),
+    flag("DF", 10),
+    flag("OF", 11),
+];
+
+/// The condition flags in `cpsr` on 64-bit ARM.
+const AARCH64_FLAGS: &[FlagBit] = &[flag("N", 31), flag("Z", 30), flag("C", 29), flag("V", 28)];
+
+/// The condition and state flags in `cpsr` on 32-bit ARM.
+const ARM_FLAGS: &[FlagBit] = &[
+    flag("N", 31),
+    flag("Z", 30),
+    flag("C", 29),
+    flag("V", 28),
+    flag("Q", 27),
+    flag("T", 5),
+];
+
+/// The status flags layout for the architecture of `arch_triple`.
+fn flag_bits(arch_triple: &str) -> &'static [FlagBit] {
+    let arch = arch_triple.split('-').next().unwrap_or_default();
+    match arch {
+        "x86_64" | "x86_64h" | "i386" | "i486" | "i586" | "i686" => X86_FLAGS,
+        "aarch64" | "arm64" | "arm64e" | "arm64_32" => AARCH64_FLAGS,
+        _ if arch.starts_with("arm") || arch.starts_with("thumb") => ARM_FLAGS,
+        _ => &[],
+    }
+}
+
+/// The name of the status flags register for the architecture of
+/// `arch_triple`, if it is known.
+pub fn flags_register_name(arch_triple: &str) -> Option<&'static str> {
+    let arch = arch_triple.split('-').next().unwrap_or_default();
+    match arch {
+        "x86_64" | "x86_64h" => Some("rflags"),
+        "i386" | "i486" | "i586" | "i686" => Some("eflags"),
+        _ if !flag_bits(arch).is_empty() => Some("cpsr"),
+        _ => None,
+    }
+}
+
+/// Decode the value of a status flags register into its named flags.
+///
+/// `arch_triple` is a target triple such as `"x86_64-unknown-linux-gnu"`
+/// or `"arm64-apple-ios"`; only the architecture is used. The flags are
+/// returned in the conventional display order for the architecture.
+/// Unknown architectures return no flags.
+pub fn decode_flags(arch_triple: &str, flags_value: u64) -> Vec<(&'static str, bool)> {
+    flag_bits(arch_triple)
+        .iter()
+        .map(|f| (f.name, flags_value & (1 << f.bit) != 0))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_flags, flag_bits, flags_register_name};
+
+    #[test]
+    fn test_flag_bits() {
+        assert_eq!(flag_bits("x86_64-unknown-linux-gnu").len(), 9);
+        assert_eq!(flag_bits("i686-pc-windows-msvc").len(), 9);
+        assert_eq!(flag_bits("arm64-apple-ios").len(), 4);
+        assert_eq!(flag_bits("aarch64-unknown-linux-gnu").len(), 4);
+        assert_eq!(flag_bits("armv7-unknown-linux-gnueabihf").len(), 6);
+        assert_eq!(flag_bits("thumbv7em-none-eabi").len(), 6);
+        assert!(flag_bits("riscv64gc-unknown-linux-gnu").is_empty());
+    }
+
+    #[test]
+    fn test_flags_register_name() {
+        assert_eq!(flags_register_name("x86_64-apple-macosx"), Some("rflags"));
+        assert_eq!(flags_register_name("i386-apple-macosx"), Some("eflags"));
+        assert_eq!(flags_register_name("arm64-apple-ios"), Some("cpsr"));
+        assert_eq!(flags_register_name("riscv64-unknown-linux-gnu"), None);
+    }
+
+    #[test]
+    fn test_decode_x86_flags() {
+        let flags = decode_flags("x86_64-apple-macosx", 0x246);
+        assert!(flags.contains(&("ZF", true)));
+        assert!(flags.contains(&("PF", true)));
+        assert!(flags.contains(&("IF", true)));
+        assert!(flags.contains(&("CF", false)));
+        assert!(flags.contains(&("SF", false)));
+    }
+
+    #[test]
+    fn test_decode_aarch64_flags() {
+        let flags = decode_flags("arm64-apple-ios", 0x6000_0000);
+        assert_eq!(
+            flags,
+            vec![("N", false), ("Z", true), ("C", true), ("V", false)]
+        );
+    }
+
+    #[test]
+    fn test_decode_unknown_arch() {
+        assert!(decode_flags("riscv64-unknown-linux-gnu", u64::MAX).is_empty());
+    }
+}
@@ -0,0 +1,123 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{lldb_addr_t, InstrumentationRuntimeType, SBStructuredData, SBThread};
+
+/// A report from an instrumentation runtime, such as the address or
+/// thread sanitizer, that stopped a thread.
+///
+/// This is a typed view over the [structured data] that LLDB extracts
+/// from the runtime when a thread stops with
+/// [`StopReason::Instrumentation`]. The fields that are present depend
+/// on the runtime and the kind of issue, so the accessors return
+/// `None` when a field is missing. The full payload is available from
+/// [`SanitizerReport::data()`].
+///
+/// See [`SBThread::sanitizer_report()`] and
+/// [`SBProcessEvent::sanitizer_reports()`].
+///
+/// [structured data]: SBThread::stop_reason_extended_info()
+/// [`StopReason::Instrumentation`]: crate::StopReason::Instrumentation
+/// [`SBProcessEvent::sanitizer_reports()`]: crate::SBProcessEvent::sanitizer_reports()
+#[derive(Debug)]
+pub struct SanitizerReport {
+    thread: SBThread,
+    data: SBStructuredData,
+}
+
+impl SanitizerReport {
+    pub(crate) fn new(thread: SBThread, data: SBStructuredData) -> SanitizerReport {
+        SanitizerReport { thread, data }
+    }
+
+    /// The thread that was stopped by the report.
+    pub fn thread(&self) -> &SBThread {
+        &self.thread
+    }
+
+    /// The raw structured data of the report.
+    pub fn data(&self) -> &SBStructuredData {
+        &self.data
+    }
+
+    /// The name of the instrumentation runtime, such as
+    /// `"AddressSanitizer"`.
+    pub fn instrumentation_class(&self) -> Option<String> {
+        self.string("instrumentation_class")
+    }
+
+    /// The instrumentation runtime that produced the report.
+    pub fn runtime(&self) -> Option<InstrumentationRuntimeType> {
+        match self.instrumentation_class()?.as_str() {
+            "AddressSanitizer" => Some(InstrumentationRuntimeType::AddressSanitizer),
+            "ThreadSanitizer" => Some(InstrumentationRuntimeType::ThreadSanitizer),
+            "UndefinedBehaviorSanitizer" => {
+                Some(InstrumentationRuntimeType::UndefinedBehaviorSanitizer)
+            }
+            "MainThreadChecker" => Some(InstrumentationRuntimeType::MainThreadChecker),
+            "SwiftRuntimeReporting" => Some(InstrumentationRuntimeType::SwiftRuntimeReporting),
+            _ => None,
+        }
+    }
+
+    /// A one line description of the issue, such as
+    /// `"heap-use-after-free"` or `"data-race"`.
+    pub fn description(&self) -> Option<String> {
+        self.string("description")
+    }
+
+    /// A longer summary of the issue, when the runtime provides one.
+    pub fn summary(&self) -> Option<String> {
+        self.string("summary")
+    }
+
+    /// The kind of issue reported by the thread sanitizer, such as
+    /// `"data-race"`.
+    pub fn issue_type(&self) -> Option<String> {
+        self.string("issue_type")
+    }
+
+    /// The bad memory address involved in the issue.
+    pub fn address(&self) -> Option<lldb_addr_t> {
+        self.integer("address")
+            .or_else(|| self.integer("memory_address"))
+    }
+
+    /// The address of the instruction that triggered the report.
+    pub fn pc(&self) -> Option<lldb_addr_t> {
+        self.integer("pc")
+    }
+
+    /// Whether the bad access was a write, for address sanitizer
+    /// reports.
+    pub fn is_write(&self) -> Option<bool> {
+        self.integer("access_type").map(|access| access != 0)
+    }
+
+    /// The size in bytes of the bad access, for address sanitizer
+    /// reports.
+    pub fn access_size(&self) -> Option<u64> {
+        self.integer("access_size")
+    }
+
+    /// The source file reported by the undefined behavior sanitizer.
+    pub fn filename(&self) -> Option<String> {
+        self.string("filename")
+    }
+
+    /// The source line reported by the undefined behavior sanitizer.
+    pub fn line(&self) -> Option<u32> {
+        self.integer("line").map(|line| line as u32)
+    }
+
+    fn string(&self, key: &str) -> Option<String> {
+        self.data.value_for_key(key)?.string_value()
+    }
+
+    fn integer(&self, key: &str) -> Option<u64> {
+        self.data.value_for_key(key)?.integer_value()
+    }
+}
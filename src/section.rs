@@ -4,9 +4,10 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::{sys, Permissions, SBData, SBStream, SBTarget};
+use crate::{sys, Permissions, SBData, SBError, SBStream, SBTarget};
 use std::ffi::{CStr, CString};
 use std::fmt;
+use std::ops::Range;
 
 /// Represents an executable image section.
 ///
@@ -111,6 +112,43 @@ impl SBSection {
         SBData::wrap(unsafe { sys::SBSectionGetSectionData2(self.raw, offset, size) })
     }
 
+    /// Read the contents of this section from the object file into
+    /// `buffer`, replacing anything it held before.
+    ///
+    /// Returns the number of bytes read, which is zero for sections
+    /// that occupy no space in the file, such as `.bss`.
+    ///
+    /// ```no_run
+    /// # use lldb::SBModule;
+    /// # fn dump_text(module: &SBModule) {
+    /// let mut contents = Vec::new();
+    /// if let Some(text) = module.find_section("__TEXT") {
+    ///     text.read_contents(&mut contents).unwrap();
+    ///     println!("{} bytes of code", contents.len());
+    /// }
+    /// # }
+    /// ```
+    pub fn read_contents(&self, buffer: &mut Vec<u8>) -> Result<usize, SBError> {
+        let data = self.section_data();
+        buffer.clear();
+        buffer.resize(data.byte_size(), 0);
+        data.read_raw_data(0, buffer)?;
+        Ok(buffer.len())
+    }
+
+    /// The range of bytes in the object file occupied by this section.
+    ///
+    /// Returns `None` for sections that occupy no space in the file,
+    /// such as `.bss`.
+    pub fn file_range(&self) -> Option<Range<u64>> {
+        let size = self.file_byte_size();
+        if size == 0 {
+            return None;
+        }
+        let offset = self.file_offset();
+        Some(offset..offset + size)
+    }
+
     #[allow(missing_docs)]
     pub fn section_type(&self) -> sys::SectionType {
         unsafe { sys::SBSectionGetSectionType(self.raw) }
@@ -0,0 +1,290 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Saving and restoring debugger sessions.
+//!
+//! A session is stored as JSON with this layout:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "platform": "host",
+//!   "selected_target": 0,
+//!   "targets": [
+//!     {
+//!       "executable": "/usr/local/bin/servo",
+//!       "triple": "x86_64-unknown-linux-gnu",
+//!       "breakpoints": [ { "Breakpoint": { ... } } ],
+//!       "watchpoints": [
+//!         { "address": 4096, "size": 4, "read": false, "write": true,
+//!           "enabled": true, "ignore_count": 0 }
+//!       ]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Breakpoints use LLDB's own serialization, so they can also be
+//! read by `breakpoint read`.
+//!
+//! Watchpoints are saved by address, which only identifies the same
+//! variable in a later run if the program is loaded at the same
+//! addresses. LLDB disables address space layout randomization for
+//! the processes that it launches unless `target.disable-aslr` is
+//! turned off, but not for processes that it attaches to.
+
+use crate::{
+    sys, DescriptionLevel, SBBreakpointList, SBDebugger, SBError, SBFileSpec, SBStructuredData,
+    SBTarget, SBWatchpoint,
+};
+use std::fmt::Write;
+use std::fs;
+use std::io::Write as _;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const SESSION_VERSION: u64 = 1;
+
+/// Used to give each temporary breakpoint file a unique name.
+static NEXT_FILE_ID: AtomicUsize = AtomicUsize::new(0);
+
+pub(crate) fn save(debugger: &SBDebugger) -> Result<SBStructuredData, SBError> {
+    let mut json = String::new();
+    write!(json, "{{\"version\":{SESSION_VERSION}").unwrap();
+    let platform = debugger.selected_platform();
+    if platform.is_valid() {
+        write!(json, ",\"platform\":{}", quote(platform.name())).unwrap();
+    }
+    if let Some(selected) = debugger.selected_target() {
+        let index = unsafe { sys::SBDebuggerGetIndexOfTarget(debugger.raw, selected.raw) };
+        write!(json, ",\"selected_target\":{index}").unwrap();
+    }
+    json.push_str(",\"targets\":[");
+    for (i, target) in debugger.targets().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        save_target(&target, &mut json)?;
+    }
+    json.push_str("]}");
+    SBStructuredData::from_json(&json)
+}
+
+fn save_target(target: &SBTarget, json: &mut String) -> Result<(), SBError> {
+    json.push('{');
+    match target.executable() {
+        Some(executable) => write!(json, "\"executable\":{}", quote(&executable.path())),
+        None => write!(json, "\"executable\":null"),
+    }
+    .unwrap();
+    if let Some(triple) = target.triple() {
+        write!(json, ",\"triple\":{}", quote(triple)).unwrap();
+    }
+    json.push_str(",\"breakpoints\":[");
+    for (i, breakpoint) in target.breakpoints().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        json.push_str(
+            breakpoint
+                .serialize_to_structured_data()
+                .get_as_json()?
+                .data(),
+        );
+    }
+    json.push_str("],\"watchpoints\":[");
+    for (i, watchpoint) in target.watchpoints().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        let (read, write) = watchpoint_kind(&watchpoint);
+        write!(
+            json,
+            "{{\"address\":{},\"size\":{},\"read\":{},\"write\":{},\"enabled\":{},\"ignore_count\":{}}}",
+            watchpoint.watch_address(),
+            watchpoint.watch_size(),
+            read,
+            write,
+            watchpoint.is_enabled(),
+            watchpoint.ignore_count(),
+        )
+        .unwrap();
+    }
+    json.push_str("]}");
+    Ok(())
+}
+
+/// Whether a watchpoint watches for reads and for writes.
+///
+/// `SBWatchpoint` doesn't expose this, so it is read from the
+/// `type = r`, `type = w` or `type = rw` part of the description, as
+/// printed by `Watchpoint::DumpWithLevel()`. Newer versions of LLDB
+/// also print `m` for watchpoints which only stop when the value is
+/// modified, and these are saved as write watchpoints. If the
+/// description can't be understood, the watchpoint is saved as a
+/// write watchpoint, which is what `watchpoint set` creates by default.
+fn watchpoint_kind(watchpoint: &SBWatchpoint) -> (bool, bool) {
+    let description = watchpoint.describe(DescriptionLevel::Brief);
+    let kind = description
+        .split("type = ")
+        .nth(1)
+        .and_then(|kind| kind.split_whitespace().next())
+        .unwrap_or_default();
+    let read = kind.contains('r');
+    let write = kind.contains('w') || kind.contains('m');
+    if read || write {
+        (read, write)
+    } else {
+        (false, true)
+    }
+}
+
+pub(crate) fn restore(
+    debugger: &SBDebugger,
+    session: &SBStructuredData,
+) -> Result<Vec<SBTarget>, SBError> {
+    let version = session
+        .value_for_key("version")
+        .and_then(|v| v.integer_value());
+    if version != Some(SESSION_VERSION) {
+        return Err(SBError::from_message("unsupported session version"));
+    }
+    if let Some(platform) = session
+        .value_for_key("platform")
+        .and_then(|p| p.string_value())
+    {
+        debugger.set_current_platform(&platform);
+    }
+    let mut targets = Vec::new();
+    if let Some(saved_targets) = session.value_for_key("targets") {
+        for i in 0..saved_targets.size() {
+            if let Some(saved) = saved_targets.item_at_index(i) {
+                targets.push(restore_target(debugger, &saved)?);
+            }
+        }
+    }
+    if let Some(selected) = session
+        .value_for_key("selected_target")
+        .and_then(|s| s.integer_value())
+    {
+        if let Some(target) = targets.get(selected as usize) {
+            debugger.set_selected_target(target);
+        }
+    }
+    Ok(targets)
+}
+
+fn restore_target(debugger: &SBDebugger, saved: &SBStructuredData) -> Result<SBTarget, SBError> {
+    let executable = saved
+        .value_for_key("executable")
+        .and_then(|e| e.string_value())
+        .unwrap_or_default();
+    let triple = saved.value_for_key("triple").and_then(|t| t.string_value());
+    let target = debugger.create_target(&executable, triple.as_deref(), None, true)?;
+    if let Some(breakpoints) = saved.value_for_key("breakpoints") {
+        if breakpoints.size() > 0 {
+            restore_breakpoints(&target, &breakpoints)?;
+        }
+    }
+    Ok(target)
+}
+
+/// LLDB can only recreate serialized breakpoints from a file, so the
+/// breakpoints are written to a temporary one.
+fn restore_breakpoints(target: &SBTarget, breakpoints: &SBStructuredData) -> Result<(), SBError> {
+    let json = breakpoints.get_as_json()?;
+    let path = std::env::temp_dir().join(format!(
+        "lldb-session-{}-{}.json",
+        process::id(),
+        NEXT_FILE_ID.fetch_add(1, Ordering::Relaxed)
+    ));
+    // The file must be new, so that a file or link planted in the
+    // shared temporary directory isn't written through or read back.
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options
+        .open(&path)
+        .and_then(|mut file| file.write_all(json.data().as_bytes()))
+        .map_err(|e| SBError::from_message(&e.to_string()))?;
+    let file = SBFileSpec::from_path(&path, false);
+    let mut new_breakpoints = SBBreakpointList::new(target);
+    let result = target.breakpoints_create_from_file(&file, &mut new_breakpoints);
+    let _ = fs::remove_file(&path);
+    result
+}
+
+pub(crate) fn restore_watchpoints(
+    target: &SBTarget,
+    session: &SBStructuredData,
+) -> Result<Vec<SBWatchpoint>, SBError> {
+    let executable = target.executable().map(|e| e.path());
+    let saved = session.value_for_key("targets").and_then(|targets| {
+        (0..targets.size())
+            .filter_map(|i| targets.item_at_index(i))
+            .find(|t| t.value_for_key("executable").and_then(|e| e.string_value()) == executable)
+    });
+    let mut watchpoints = Vec::new();
+    let Some(saved) = saved.and_then(|s| s.value_for_key("watchpoints")) else {
+        return Ok(watchpoints);
+    };
+    for i in 0..saved.size() {
+        let Some(entry) = saved.item_at_index(i) else {
+            continue;
+        };
+        let integer = |key| entry.value_for_key(key).and_then(|v| v.integer_value());
+        let boolean = |key| entry.value_for_key(key).and_then(|v| v.boolean_value());
+        let (Some(address), Some(size)) = (integer("address"), integer("size")) else {
+            continue;
+        };
+        let watchpoint = target.watch_address(
+            address,
+            size as usize,
+            boolean("read").unwrap_or(false),
+            boolean("write").unwrap_or(true),
+        )?;
+        watchpoint.set_enabled(boolean("enabled").unwrap_or(true));
+        watchpoint.set_ignore_count(integer("ignore_count").unwrap_or(0) as u32);
+        watchpoints.push(watchpoint);
+    }
+    Ok(watchpoints)
+}
+
+/// Quote `s` as a JSON string.
+fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(quoted, "\\u{:04x}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::quote;
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote(""), r#""""#);
+        assert_eq!(quote("/usr/bin/ls"), r#""/usr/bin/ls""#);
+        assert_eq!(quote(r#"a "b" c"#), r#""a \"b\" c""#);
+        assert_eq!(quote(r"C:\bin"), r#""C:\\bin""#);
+        assert_eq!(quote("a\nb\tc\r"), r#""a\nb\tc\r""#);
+        assert_eq!(quote("\u{1}"), r#""\u0001""#);
+        assert_eq!(quote("héllo"), "\"héllo\"");
+    }
+}
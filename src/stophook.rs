@@ -0,0 +1,126 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::SBProcess;
+use std::fmt;
+
+type StopHook = Box<dyn FnMut(&SBProcess) + Send>;
+
+/// Identifies a stop hook added with [`StopHooks::add()`], so that it
+/// can later be removed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StopHookId(u64);
+
+/// A set of hooks which run each time a process stops.
+///
+/// These are owned by the event loop which runs them, rather than by
+/// LLDB: LLDB's own stop hooks can only run commands or Python. They
+/// are run for each stop reported by [`ProcessEvents`] when given to
+/// [`ProcessEvents::with_stop_hooks()`], or by passing each event to
+/// [`SBProcessEvent::run_stop_hooks()`] when handling events directly.
+/// This makes them a good place to refresh views, such as watch
+/// windows, which depend on the state of a stopped process.
+///
+/// ```no_run
+/// use lldb::{SBListener, SBProcess, StopHooks};
+///
+/// fn run_until_exit(process: &SBProcess) {
+///     let mut hooks = StopHooks::new();
+///     hooks.add(|process: &SBProcess| {
+///         println!("Stopped at {:#x}", process.selected_thread().selected_frame().pc());
+///     });
+///     let listener = SBListener::new();
+///     for _ in process.events(&listener).with_stop_hooks(&mut hooks) {
+///         if process.is_stopped() {
+///             let _ = process.continue_execution();
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`ProcessEvents`]: crate::ProcessEvents
+/// [`ProcessEvents::with_stop_hooks()`]: crate::ProcessEvents::with_stop_hooks()
+/// [`SBProcessEvent::run_stop_hooks()`]: crate::SBProcessEvent::run_stop_hooks()
+#[derive(Default)]
+pub struct StopHooks {
+    hooks: Vec<(StopHookId, StopHook)>,
+    next_id: u64,
+}
+
+impl StopHooks {
+    /// Construct a new, empty set of stop hooks.
+    pub fn new() -> StopHooks {
+        StopHooks::default()
+    }
+
+    /// Add a hook, returning an ID which can be used to [remove] it.
+    ///
+    /// Hooks are run in the order in which they were added.
+    ///
+    /// [remove]: StopHooks::remove()
+    pub fn add<F>(&mut self, hook: F) -> StopHookId
+    where
+        F: FnMut(&SBProcess) + Send + 'static,
+    {
+        let id = StopHookId(self.next_id);
+        self.next_id += 1;
+        self.hooks.push((id, Box::new(hook)));
+        id
+    }
+
+    /// Remove a hook added with [`StopHooks::add()`].
+    ///
+    /// Returns `false` if there was no such hook.
+    pub fn remove(&mut self, id: StopHookId) -> bool {
+        let before = self.hooks.len();
+        self.hooks.retain(|(hook_id, _)| *hook_id != id);
+        self.hooks.len() != before
+    }
+
+    /// The number of hooks.
+    pub fn len(&self) -> usize {
+        self.hooks.len()
+    }
+
+    /// Are there no hooks?
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// Run each hook for a stop of `process`, returning how many were run.
+    pub fn run(&mut self, process: &SBProcess) -> usize {
+        for (_, hook) in &mut self.hooks {
+            hook(process);
+        }
+        self.hooks.len()
+    }
+}
+
+impl fmt::Debug for StopHooks {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let ids: Vec<StopHookId> = self.hooks.iter().map(|(id, _)| *id).collect();
+        fmt.debug_struct("StopHooks").field("hooks", &ids).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_and_remove() {
+        let mut hooks = StopHooks::new();
+        let first = hooks.add(|_: &SBProcess| {});
+        let second = hooks.add(|_: &SBProcess| {});
+        assert_ne!(first, second);
+        assert_eq!(hooks.len(), 2);
+        assert!(hooks.remove(first));
+        assert!(!hooks.remove(first));
+        assert_eq!(hooks.len(), 1);
+        assert!(hooks.remove(second));
+        assert!(hooks.is_empty());
+    }
+}
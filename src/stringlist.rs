@@ -39,9 +39,14 @@ impl SBStringList {
         unsafe { sys::SBStringListIsValid(self.raw) }
     }
 
+    /// The number of items in this string list.
+    pub fn len(&self) -> usize {
+        unsafe { sys::SBStringListGetSize(self.raw) as usize }
+    }
+
     /// Is this string list empty?
     pub fn is_empty(&self) -> bool {
-        unsafe { sys::SBStringListGetSize(self.raw) == 0 }
+        self.len() == 0
     }
 
     /// Clear this string list.
@@ -65,6 +70,7 @@ impl SBStringList {
         SBStringListIter {
             string_list: self,
             idx: 0,
+            end: self.len(),
         }
     }
 }
@@ -104,13 +110,14 @@ unsafe impl Sync for SBStringList {}
 pub struct SBStringListIter<'d> {
     string_list: &'d SBStringList,
     idx: usize,
+    end: usize,
 }
 
 impl<'d> Iterator for SBStringListIter<'d> {
     type Item = &'d str;
 
     fn next(&mut self) -> Option<&'d str> {
-        if self.idx < unsafe { sys::SBStringListGetSize(self.string_list.raw) as usize } {
+        if self.idx < self.end {
             let r = unsafe {
                 match CStr::from_ptr(sys::SBStringListGetStringAtIndex(
                     self.string_list.raw,
@@ -130,9 +137,31 @@ impl<'d> Iterator for SBStringListIter<'d> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let sz = unsafe { sys::SBStringListGetSize(self.string_list.raw) } as usize;
-        (sz - self.idx, Some(sz))
+        let sz = self.end - self.idx;
+        (sz, Some(sz))
     }
 }
 
 impl ExactSizeIterator for SBStringListIter<'_> {}
+
+impl<'d> DoubleEndedIterator for SBStringListIter<'d> {
+    fn next_back(&mut self) -> Option<&'d str> {
+        if self.idx < self.end {
+            self.end -= 1;
+            let r = unsafe {
+                match CStr::from_ptr(sys::SBStringListGetStringAtIndex(
+                    self.string_list.raw,
+                    self.end,
+                ))
+                .to_str()
+                {
+                    Ok(s) => s,
+                    _ => panic!("Invalid string?"),
+                }
+            };
+            Some(r)
+        } else {
+            None
+        }
+    }
+}
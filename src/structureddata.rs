@@ -30,6 +30,15 @@ impl SBStructuredData {
         }
     }
 
+    /// Parse `json` into a new `SBStructuredData`.
+    pub fn from_json(json: &str) -> Result<SBStructuredData, SBError> {
+        let data = SBStructuredData::wrap(unsafe { sys::CreateSBStructuredData() });
+        let json = CString::new(json).unwrap();
+        SBError::wrap(unsafe { sys::SBStructuredDataSetFromJSON2(data.raw, json.as_ptr()) })
+            .into_result()?;
+        Ok(data)
+    }
+
     /// Check whether or not this is a valid `SBStructuredData` value.
     pub fn is_valid(&self) -> bool {
         unsafe { sys::SBStructuredDataIsValid(self.raw) }
@@ -131,7 +140,8 @@ impl SBStructuredData {
                 let sz = sys::SBStructuredDataGetStringValue(self.raw, ptr::null_mut(), 0) + 1;
                 let mut buf: Vec<u8> = Vec::with_capacity(sz);
                 sys::SBStructuredDataGetStringValue(self.raw, buf.as_mut_ptr() as *mut i8, sz);
-                buf.set_len(sz);
+                // Don't include the trailing NUL in the string.
+                buf.set_len(sz - 1);
                 String::from_utf8(buf).ok()
             }
         } else {
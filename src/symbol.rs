@@ -5,7 +5,7 @@
 // except according to those terms.
 
 use crate::{sys, DisassemblyFlavor, SBAddress, SBInstructionList, SBStream, SBTarget, SymbolType};
-use std::ffi::{CStr, CString};
+use std::ffi::CStr;
 use std::fmt;
 use std::os::raw::c_char;
 use std::ptr;
@@ -67,16 +67,12 @@ impl SBSymbol {
         target: &SBTarget,
         flavor: DisassemblyFlavor,
     ) -> SBInstructionList {
-        let flavor = match flavor {
-            DisassemblyFlavor::ATT => CString::new("att").ok(),
-            DisassemblyFlavor::Default => None,
-            DisassemblyFlavor::Intel => CString::new("intel").ok(),
-        };
+        let flavor = flavor.to_cstring();
         SBInstructionList::wrap(unsafe {
             sys::SBSymbolGetInstructions2(
                 self.raw,
                 target.raw,
-                flavor.map_or(ptr::null(), |s| s.as_ptr()),
+                flavor.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
             )
         })
     }
@@ -4,7 +4,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::{sys, SBSymbolContext};
+use crate::{sys, SBSymbol, SBSymbolContext};
 
 /// A list of [symbol contexts].
 ///
@@ -16,6 +16,11 @@ pub struct SBSymbolContextList {
 }
 
 impl SBSymbolContextList {
+    /// Construct a new, empty `SBSymbolContextList`.
+    pub fn new() -> SBSymbolContextList {
+        SBSymbolContextList::wrap(unsafe { sys::CreateSBSymbolContextList() })
+    }
+
     /// Construct a new `SBSymbolContextList`.
     pub(crate) fn wrap(raw: sys::SBSymbolContextListRef) -> SBSymbolContextList {
         SBSymbolContextList { raw }
@@ -36,19 +41,24 @@ impl SBSymbolContextList {
         unsafe { sys::SBSymbolContextListIsValid(self.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// Append a symbol context to this list.
     pub fn append(&self, context: &SBSymbolContext) {
         unsafe { sys::SBSymbolContextListAppend(self.raw, context.raw) };
     }
 
-    #[allow(missing_docs)]
+    /// Append the symbol contexts of another list to this one.
     pub fn append_list(&self, contexts: &SBSymbolContextList) {
         unsafe { sys::SBSymbolContextListAppendList(self.raw, contexts.raw) };
     }
 
+    /// The number of items in this context list.
+    pub fn len(&self) -> usize {
+        unsafe { sys::SBSymbolContextListGetSize(self.raw) as usize }
+    }
+
     /// Is this context list empty?
     pub fn is_empty(&self) -> bool {
-        unsafe { sys::SBSymbolContextListGetSize(self.raw) == 0 }
+        self.len() == 0
     }
 
     /// Clear this context list.
@@ -56,11 +66,32 @@ impl SBSymbolContextList {
         unsafe { sys::SBSymbolContextListClear(self.raw) };
     }
 
+    /// Get the symbol context at `idx`, if `idx` is within bounds.
+    pub fn get(&self, idx: usize) -> Option<SBSymbolContext> {
+        if idx < self.len() {
+            SBSymbolContext::maybe_wrap(unsafe {
+                sys::SBSymbolContextListGetContextAtIndex(self.raw, idx as u32)
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over the valid [symbols] of the contexts in this list.
+    ///
+    /// [symbols]: SBSymbol
+    pub fn symbols(&self) -> impl Iterator<Item = SBSymbol> + '_ {
+        self.iter()
+            .map(|context| context.symbol())
+            .filter(|symbol| symbol.is_valid())
+    }
+
     /// Iterate over this context list.
     pub fn iter(&self) -> SBSymbolContextListIter {
         SBSymbolContextListIter {
             context_list: self,
             idx: 0,
+            end: self.len(),
         }
     }
 }
@@ -73,6 +104,12 @@ impl Clone for SBSymbolContextList {
     }
 }
 
+impl Default for SBSymbolContextList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Drop for SBSymbolContextList {
     fn drop(&mut self) {
         unsafe { sys::DisposeSBSymbolContextList(self.raw) };
@@ -96,13 +133,14 @@ unsafe impl Sync for SBSymbolContextList {}
 pub struct SBSymbolContextListIter<'d> {
     context_list: &'d SBSymbolContextList,
     idx: usize,
+    end: usize,
 }
 
 impl Iterator for SBSymbolContextListIter<'_> {
     type Item = SBSymbolContext;
 
     fn next(&mut self) -> Option<SBSymbolContext> {
-        if self.idx < unsafe { sys::SBSymbolContextListGetSize(self.context_list.raw) as usize } {
+        if self.idx < self.end {
             let r = SBSymbolContext::wrap(unsafe {
                 sys::SBSymbolContextListGetContextAtIndex(self.context_list.raw, self.idx as u32)
             });
@@ -114,9 +152,23 @@ impl Iterator for SBSymbolContextListIter<'_> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let sz = unsafe { sys::SBSymbolContextListGetSize(self.context_list.raw) } as usize;
-        (sz - self.idx, Some(sz))
+        let sz = self.end - self.idx;
+        (sz, Some(sz))
     }
 }
 
 impl ExactSizeIterator for SBSymbolContextListIter<'_> {}
+
+impl DoubleEndedIterator for SBSymbolContextListIter<'_> {
+    fn next_back(&mut self) -> Option<SBSymbolContext> {
+        if self.idx < self.end {
+            self.end -= 1;
+            let r = SBSymbolContext::wrap(unsafe {
+                sys::SBSymbolContextListGetContextAtIndex(self.context_list.raw, self.end as u32)
+            });
+            Some(r)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{lldb_addr_t, SBSymbolContext, SBTarget, LLDB_INVALID_ADDRESS};
+use std::fmt;
+
+/// A description of the code at an address, as returned by
+/// [`SBTarget::symbolicate()`] and
+/// [`SymbolizedLocation::symbolication()`].
+///
+/// [`SymbolizedLocation::symbolication()`]: crate::SymbolizedLocation::symbolication()
+///
+/// When displayed, this is formatted similarly to a frame in a
+/// backtrace:
+///
+/// ```text
+/// libfoo.so`function_name + 12 at main.c:10:5
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Symbolication {
+    /// The load address that was symbolicated.
+    pub address: lldb_addr_t,
+    /// The file name of the module containing the address.
+    pub module: String,
+    /// The name of the function containing the address, or the name
+    /// of the symbol if there is no debug information.
+    pub name: Option<String>,
+    /// The offset of the address from the start of the function
+    /// or symbol.
+    pub offset: Option<u64>,
+    /// The path to the source file.
+    pub file: Option<String>,
+    /// The 1-based line number within `file`.
+    pub line: Option<u32>,
+    /// The 1-based column number within `line`.
+    pub column: Option<u32>,
+}
+
+impl Symbolication {
+    pub(crate) fn from_context(
+        target: &SBTarget,
+        address: lldb_addr_t,
+        context: &SBSymbolContext,
+    ) -> Option<Symbolication> {
+        let module = context.module();
+        if !module.is_valid() {
+            return None;
+        }
+
+        let function = context.function();
+        let symbol = context.symbol();
+        let (name, start) = if function.is_valid() {
+            (
+                Some(function.name().to_string()),
+                Some(function.start_address().load_address(target)),
+            )
+        } else if symbol.is_valid() {
+            (
+                Some(symbol.name().to_string()),
+                symbol
+                    .start_address()
+                    .map(|start| start.load_address(target)),
+            )
+        } else {
+            (None, None)
+        };
+        let offset = start
+            .filter(|&start| start != LLDB_INVALID_ADDRESS && start <= address)
+            .map(|start| address - start);
+
+        let line_entry = context.line_entry();
+        let file = line_entry
+            .as_ref()
+            .map(|line_entry| line_entry.filespec())
+            .filter(|filespec| filespec.is_valid())
+            .map(|filespec| filespec.path());
+        let line = line_entry
+            .as_ref()
+            .map(|line_entry| line_entry.line())
+            .filter(|&line| line > 0);
+        let column = line_entry
+            .as_ref()
+            .map(|line_entry| line_entry.column())
+            .filter(|&column| column > 0);
+
+        Some(Symbolication {
+            address,
+            module: module.filespec().filename().to_string(),
+            name,
+            offset,
+            file,
+            line,
+            column,
+        })
+    }
+}
+
+impl fmt::Display for Symbolication {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}`", self.module)?;
+        match &self.name {
+            Some(name) => write!(fmt, "{}", name)?,
+            None => write!(fmt, "{:#x}", self.address)?,
+        }
+        if let Some(offset) = self.offset.filter(|&offset| offset > 0) {
+            write!(fmt, " + {}", offset)?;
+        }
+        if let Some(file) = &self.file {
+            let file = file.rsplit('/').next().unwrap_or(file);
+            write!(fmt, " at {}", file)?;
+            if let Some(line) = self.line {
+                write!(fmt, ":{}", line)?;
+                if let Some(column) = self.column {
+                    write!(fmt, ":{}", column)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
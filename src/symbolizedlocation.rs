@@ -0,0 +1,231 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{
+    lldb_addr_t, SBAddress, SBFileSpec, SBSymbolContext, SBTarget, SymbolContextItem,
+    Symbolication, LLDB_INVALID_ADDRESS,
+};
+use std::ops::Range;
+
+/// The result of symbolizing an address with [`SBTarget::symbolize_batch()`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SymbolizedLocation {
+    /// The load address that was symbolized.
+    pub address: lldb_addr_t,
+    /// The frames at this address.
+    ///
+    /// When inlined functions are expanded, the innermost inlined
+    /// function is first and the concrete function containing it
+    /// is last. This is empty if the address couldn't be resolved
+    /// to a module.
+    pub frames: Vec<SymbolizedFrame>,
+}
+
+impl SymbolizedLocation {
+    /// Describe the outermost frame at this address, in the same way as
+    /// [`SBTarget::symbolicate()`].
+    ///
+    /// This will be `None` if the address couldn't be resolved to a
+    /// module.
+    pub fn symbolication(&self) -> Option<Symbolication> {
+        let frame = self.frames.last()?;
+        Some(Symbolication {
+            address: self.address,
+            module: frame.module.clone()?,
+            name: frame.function.clone(),
+            offset: frame.offset,
+            file: frame.file.clone(),
+            line: frame.line,
+            column: frame.column,
+        })
+    }
+}
+
+/// A single, possibly inlined, frame within a [`SymbolizedLocation`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SymbolizedFrame {
+    /// The file name of the module containing the address.
+    pub module: Option<String>,
+    /// The function name, or the symbol name if there is no debug
+    /// information for the function.
+    pub function: Option<String>,
+    /// The offset of the address from the start of the function or
+    /// symbol. This is `None` for inlined functions.
+    pub offset: Option<u64>,
+    /// The path to the source file.
+    pub file: Option<String>,
+    /// The 1-based line number within `file`.
+    pub line: Option<u32>,
+    /// The 1-based column number within `line`.
+    pub column: Option<u32>,
+    /// Whether or not this frame is a function that was inlined
+    /// into the following frame.
+    pub is_inlined: bool,
+}
+
+/// Symbolize `vm_addr`, returning the frames and the range of addresses
+/// around it which will symbolize identically, if known.
+pub(crate) fn symbolize(
+    target: &SBTarget,
+    vm_addr: lldb_addr_t,
+    need_inlines: bool,
+) -> (Vec<SymbolizedFrame>, Option<Range<lldb_addr_t>>) {
+    let mut frames = vec![];
+    let Some(address) = target.resolve_load_address(vm_addr) else {
+        return (frames, None);
+    };
+    let mut context =
+        target.resolve_symbol_context_for_address(&address, SymbolContextItem::EVERYTHING);
+    let same_result = same_result_range(target, &context, &address);
+
+    let mut pc = address;
+    loop {
+        let frame = symbolized_frame(target, &context, &pc);
+        let is_inlined = frame.is_inlined;
+        frames.push(frame);
+        if !need_inlines || !is_inlined {
+            break;
+        }
+        let parent_pc = SBAddress::wrap(unsafe { crate::sys::CreateSBAddress() });
+        let parent = context.parent_of_inlined_scope(&pc, &parent_pc);
+        if !parent.is_valid() {
+            break;
+        }
+        context = parent;
+        pc = parent_pc;
+    }
+    (frames, same_result)
+}
+
+fn symbolized_frame(
+    target: &SBTarget,
+    context: &SBSymbolContext,
+    pc: &SBAddress,
+) -> SymbolizedFrame {
+    let module = context.module();
+    let module = if module.is_valid() {
+        Some(module.filespec().filename().to_string())
+    } else {
+        None
+    };
+
+    let block = context.block();
+    let inlined_block = if block.is_valid() {
+        block.containing_inlined_block()
+    } else {
+        None
+    };
+    let function = context.function();
+    let symbol = context.symbol();
+    let (name, start) = if let Some(inlined_block) = &inlined_block {
+        (Some(inlined_block.inlined_name().to_string()), None)
+    } else if function.is_valid() {
+        (
+            Some(function.name().to_string()),
+            Some(function.start_address().load_address(target)),
+        )
+    } else if symbol.is_valid() {
+        (
+            Some(symbol.name().to_string()),
+            symbol
+                .start_address()
+                .map(|start| start.load_address(target)),
+        )
+    } else {
+        (None, None)
+    };
+    let pc = pc.load_address(target);
+    let offset = start
+        .filter(|&start| start != LLDB_INVALID_ADDRESS && start <= pc)
+        .map(|start| pc - start);
+
+    let line_entry = context.line_entry();
+    let file = line_entry
+        .as_ref()
+        .map(|line_entry| line_entry.filespec())
+        .filter(SBFileSpec::is_valid)
+        .map(|filespec| filespec.path());
+    let line = line_entry
+        .as_ref()
+        .map(|line_entry| line_entry.line())
+        .filter(|&line| line > 0);
+    let column = line_entry
+        .as_ref()
+        .map(|line_entry| line_entry.column())
+        .filter(|&column| column > 0);
+
+    SymbolizedFrame {
+        module,
+        function: name,
+        offset,
+        file,
+        line,
+        column,
+        is_inlined: inlined_block.is_some(),
+    }
+}
+
+/// The addresses around `address` which share its line entry and block,
+/// and so will produce the same symbolized frames.
+pub(crate) fn same_result_range(
+    target: &SBTarget,
+    context: &SBSymbolContext,
+    address: &SBAddress,
+) -> Option<Range<lldb_addr_t>> {
+    let line_entry = context.line_entry()?;
+    let mut start = line_entry.start_address().load_address(target);
+    let mut end = line_entry.end_address().load_address(target);
+    let block = context.block();
+    if block.is_valid() {
+        let idx = block.range_index_for_block_address(address);
+        if idx == u32::MAX {
+            return None;
+        }
+        start = start.max(block.range_start_address(idx).load_address(target));
+        end = end.min(block.range_end_address(idx).load_address(target));
+    }
+    if start == LLDB_INVALID_ADDRESS || end == LLDB_INVALID_ADDRESS || start >= end {
+        return None;
+    }
+    Some(start..end)
+}
+
+/// Resolve many addresses, in sorted order, with `resolve`, returning
+/// the results in the order of `addrs`.
+///
+/// `resolve` returns the result for an address along with the range of
+/// addresses around it that would resolve the same way. Addresses which
+/// fall within the range of the previous lookup are given `reuse` of its
+/// result instead of being resolved again.
+pub(crate) fn resolve_sorted<T, R, U>(
+    addrs: &[lldb_addr_t],
+    mut resolve: R,
+    mut reuse: U,
+) -> Vec<Option<T>>
+where
+    T: Clone,
+    R: FnMut(lldb_addr_t) -> (Option<T>, Option<Range<lldb_addr_t>>),
+    U: FnMut(&T, lldb_addr_t) -> T,
+{
+    let mut order: Vec<usize> = (0..addrs.len()).collect();
+    order.sort_unstable_by_key(|&i| addrs[i]);
+
+    let mut results = vec![None; addrs.len()];
+    let mut cached: Option<(Range<lldb_addr_t>, T)> = None;
+    for i in order {
+        let address = addrs[i];
+        if let Some((range, previous)) = &cached {
+            if range.contains(&address) {
+                results[i] = Some(reuse(previous, address));
+                continue;
+            }
+        }
+        let (result, range) = resolve(address);
+        cached = range.zip(result.clone());
+        results[i] = result;
+    }
+    results
+}
@@ -4,15 +4,21 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use crate::session;
 use crate::{
-    lldb_addr_t, sys, DescriptionLevel, MatchType, SBAddress, SBAttachInfo, SBBreakpoint,
-    SBBroadcaster, SBDebugger, SBError, SBEvent, SBExpressionOptions, SBFileSpec, SBLaunchInfo,
-    SBModule, SBModuleSpec, SBPlatform, SBProcess, SBStream, SBSymbolContextList, SBValue,
-    SBWatchpoint, SymbolType,
+    lldb_addr_t, symbolizedlocation, sys, BasicType, BreakpointBuilder, DescriptionLevel,
+    DisassemblyFlavor, FunctionNameType, MatchType, SBAddress, SBAttachInfo, SBBreakpoint,
+    SBBreakpointList, SBBroadcaster, SBDebugger, SBError, SBEvent, SBExpressionOptions, SBFileSpec,
+    SBFileSpecList, SBInstructionList, SBLaunchInfo, SBListener, SBModule, SBModuleSpec,
+    SBPlatform, SBProcess, SBSection, SBStream, SBStructuredData, SBSymbolContext,
+    SBSymbolContextList, SBType, SBTypeList, SBValue, SBValueList, SBWatchpoint, SymbolContextItem,
+    SymbolType, Symbolication, SymbolizedLocation, LLDB_INVALID_ADDRESS,
 };
 use lldb_sys::ByteOrder;
 use std::ffi::{CStr, CString};
 use std::fmt;
+use std::path::Path;
+use std::ptr;
 
 /// The target program running under the debugger.
 ///
@@ -146,7 +152,9 @@ impl SBTarget {
         }
     }
 
-    #[allow(missing_docs)]
+    /// Attach to a process.
+    ///
+    /// See [`SBAttachInfo`] for how to choose the process.
     pub fn attach(&self, attach_info: SBAttachInfo) -> Result<SBProcess, SBError> {
         let error: SBError = SBError::default();
         let process =
@@ -158,6 +166,65 @@ impl SBTarget {
         }
     }
 
+    /// Attach to a process and continue running it once attached, as
+    /// `process attach --continue` does.
+    ///
+    /// Attaching stops the process. This resumes it, so that breakpoints
+    /// and other stops are reported as usual. In asynchronous mode, wait
+    /// for the process to stop after [attaching] and continue it instead.
+    ///
+    /// [attaching]: SBTarget::attach()
+    pub fn attach_and_continue(&self, attach_info: SBAttachInfo) -> Result<SBProcess, SBError> {
+        let process = self.attach(attach_info)?;
+        process.continue_execution()?;
+        Ok(process)
+    }
+
+    /// Connect to a remote debug server, such as `gdbserver` or
+    /// `debugserver`, and create a process for it.
+    ///
+    /// `url` is of the form `connect://host:port`. When `plugin_name` is
+    /// `None`, a suitable process plugin is chosen automatically.
+    ///
+    /// LLDB doesn't report the URL or packet statistics for the
+    /// connection afterwards. The timings that it does keep, such as
+    /// how long it took to connect, are in [`SBTarget::statistics()`].
+    pub fn connect_remote(
+        &self,
+        listener: &SBListener,
+        url: &str,
+        plugin_name: Option<&str>,
+    ) -> Result<SBProcess, SBError> {
+        let error: SBError = SBError::default();
+        let url = CString::new(url).unwrap();
+        let plugin_name = plugin_name.map(|name| CString::new(name).unwrap());
+        let process = SBProcess::wrap(unsafe {
+            sys::SBTargetConnectRemote(
+                self.raw,
+                listener.raw,
+                url.as_ptr(),
+                plugin_name
+                    .as_ref()
+                    .map_or(ptr::null(), |name| name.as_ptr()),
+                error.raw,
+            )
+        });
+        if error.is_success() {
+            Ok(process)
+        } else {
+            Err(error)
+        }
+    }
+
+    /// Statistics about this target, as reported by `statistics dump`.
+    ///
+    /// This includes timing information such as how long it took to
+    /// launch, attach or connect to the process and to reach the first
+    /// stop, which can help to diagnose slow remote sessions.
+    pub fn statistics(&self) -> SBStructuredData {
+        SBStructuredData::wrap(unsafe { sys::SBTargetGetStatistics(self.raw) })
+    }
+
     /// Get a filespec for the executable.
     pub fn executable(&self) -> Option<SBFileSpec> {
         SBFileSpec::maybe_wrap(unsafe { sys::SBTargetGetExecutable(self.raw) })
@@ -178,6 +245,81 @@ impl SBTarget {
         unsafe { sys::SBTargetRemoveModule(self.raw, module.raw) }
     }
 
+    /// Add the module at `path` to the target, loading its debug
+    /// information from `symbol_file`.
+    ///
+    /// This is for modules which aren't part of the target yet. To
+    /// attach debug information to a module which is already loaded,
+    /// use [`SBTarget::add_symbol_file()`].
+    pub fn add_module_with_symbol_file<P: AsRef<Path>, S: AsRef<Path>>(
+        &self,
+        path: P,
+        symbol_file: S,
+    ) -> Option<SBModule> {
+        let module_spec = SBModuleSpec::new();
+        module_spec.set_filespec(&SBFileSpec::from_path(path, true));
+        module_spec.set_symbol_filespec(&SBFileSpec::from_path(symbol_file, true));
+        self.add_module_spec(&module_spec)
+    }
+
+    /// Attach the debug information in `symbol_file`, such as a `.dSYM`
+    /// bundle or a separate DWARF file, to a module which is already part
+    /// of the target, as `target symbols add` does.
+    ///
+    /// If `module` is `None`, LLDB picks the module by matching the UUID
+    /// of the symbol file. This can be used at any time during a session,
+    /// such as once symbols have been downloaded from a symbol server,
+    /// and breakpoints are re-resolved against the new debug information.
+    ///
+    /// The LLDB API has no direct equivalent, so this runs the command in
+    /// the target's debugger, in the context of this target. The
+    /// debugger's selected target is left alone.
+    pub fn add_symbol_file<P: AsRef<Path>>(
+        &self,
+        symbol_file: P,
+        module: Option<&SBModule>,
+    ) -> Result<(), SBError> {
+        let mut command = String::from("target symbols add");
+        if let Some(module) = module {
+            command.push_str(" --shlib ");
+            command.push_str(&quote_argument(&module.filespec().path()));
+        }
+        command.push_str(" -- ");
+        command.push_str(&quote_argument(&symbol_file.as_ref().to_string_lossy()));
+
+        self.execute_command(&command)
+    }
+
+    /// Run an LLDB command in the context of this target, rather than
+    /// of the debugger's selected target.
+    fn execute_command(&self, command: &str) -> Result<(), SBError> {
+        let interpreter = self.debugger().command_interpreter();
+        let command = CString::new(command).unwrap();
+        unsafe {
+            let context = sys::CreateSBExecutionContext2(self.raw);
+            let result = sys::CreateSBCommandReturnObject();
+            sys::SBCommandInterpreterHandleCommand2(
+                interpreter.raw,
+                command.as_ptr(),
+                context,
+                result,
+                false,
+            );
+            let outcome = if sys::SBCommandReturnObjectSucceeded(result) {
+                Ok(())
+            } else {
+                let message = sys::SBCommandReturnObjectGetError(result)
+                    .as_ref()
+                    .map(|message| CStr::from_ptr(message).to_string_lossy())
+                    .unwrap_or_default();
+                Err(SBError::from_message(message.trim_end()))
+            };
+            sys::DisposeSBCommandReturnObject(result);
+            sys::DisposeSBExecutionContext(context);
+            outcome
+        }
+    }
+
     /// Get the debugger controlling this target.
     pub fn debugger(&self) -> SBDebugger {
         SBDebugger {
@@ -195,6 +337,20 @@ impl SBTarget {
         }
     }
 
+    /// Get an owning iterator over the [modules] known to this target instance.
+    ///
+    /// Unlike [`SBTarget::modules()`], the returned iterator holds its own
+    /// reference to this target, so it can be returned from functions
+    /// or sent to other threads.
+    ///
+    /// [modules]: SBModule
+    pub fn owned_modules(&self) -> SBTargetOwnedModuleIter {
+        SBTargetOwnedModuleIter {
+            target: self.clone(),
+            idx: 0,
+        }
+    }
+
     /// Find the module for the given `SBFileSpec`.
     pub fn find_module(&self, file_spec: &SBFileSpec) -> Option<SBModule> {
         SBModule::maybe_wrap(unsafe { sys::SBTargetFindModule(self.raw, file_spec.raw) })
@@ -213,6 +369,259 @@ impl SBTarget {
         SBAddress::maybe_wrap(unsafe { sys::SBTargetResolveLoadAddress(self.raw, vm_addr) })
     }
 
+    /// Disassemble up to `count` instructions from the memory of the
+    /// process, starting at `base_addr`.
+    ///
+    /// With [`DisassemblyFlavor::Default`], the debugger's
+    /// [disassembly flavor] is used.
+    ///
+    /// [disassembly flavor]: SBDebugger::disassembly_flavor()
+    pub fn read_instructions(
+        &self,
+        base_addr: &SBAddress,
+        count: u32,
+        flavor: DisassemblyFlavor,
+    ) -> SBInstructionList {
+        let flavor = flavor.to_cstring();
+        SBInstructionList::wrap(unsafe {
+            sys::SBTargetReadInstructions2(
+                self.raw,
+                base_addr.raw,
+                count,
+                flavor.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
+            )
+        })
+    }
+
+    /// Disassemble the machine code in `buf`, as though it were loaded
+    /// at `base_addr`.
+    ///
+    /// With [`DisassemblyFlavor::Default`], the debugger's
+    /// [disassembly flavor] is used.
+    ///
+    /// [disassembly flavor]: SBDebugger::disassembly_flavor()
+    pub fn get_instructions(
+        &self,
+        base_addr: lldb_addr_t,
+        buf: &[u8],
+        flavor: DisassemblyFlavor,
+    ) -> SBInstructionList {
+        let flavor = flavor.to_cstring();
+        SBInstructionList::wrap(unsafe {
+            sys::SBTargetGetInstructionsWithFlavor2(
+                self.raw,
+                base_addr,
+                flavor.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
+                buf.as_ptr() as *mut _,
+                buf.len(),
+            )
+        })
+    }
+
+    /// Resolve the symbol context for an address.
+    ///
+    /// `resolve_scope` determines which parts of the symbol context
+    /// are looked up.
+    pub fn resolve_symbol_context_for_address(
+        &self,
+        address: &SBAddress,
+        resolve_scope: SymbolContextItem,
+    ) -> SBSymbolContext {
+        SBSymbolContext::wrap(unsafe {
+            sys::SBTargetResolveSymbolContextForAddress(self.raw, address.raw, resolve_scope.bits())
+        })
+    }
+
+    /// Describe the code at a load address.
+    ///
+    /// This gives the module, function or symbol name, and source
+    /// location for the address, which is everything needed to print
+    /// a frame of a backtrace.
+    ///
+    /// The return value will be `None` if `vm_addr` doesn't resolve to
+    /// a module.
+    ///
+    /// See [`SBTarget::symbolize_batch()`] for many addresses, or when
+    /// inlined functions are needed.
+    pub fn symbolicate(&self, vm_addr: lldb_addr_t) -> Option<Symbolication> {
+        let address = self.resolve_load_address(vm_addr)?;
+        let context =
+            self.resolve_symbol_context_for_address(&address, SymbolContextItem::EVERYTHING);
+        Symbolication::from_context(self, vm_addr, &context)
+    }
+
+    /// Symbolize many load addresses at once.
+    ///
+    /// The results are in the same order as `addrs`. When `need_inlines`
+    /// is set, each location includes a frame for every inlined function
+    /// at the address as well as the concrete function containing them.
+    ///
+    /// This is intended for symbolizing the many addresses found in
+    /// profiler stacks. The addresses are processed in sorted order so
+    /// that lookups within a module stay together, and addresses which
+    /// share a line table entry and lexical block reuse the same result.
+    /// Every other address is looked up separately, taking LLDB's
+    /// target lock for each call.
+    ///
+    /// Each location can be turned into the same description as
+    /// [`SBTarget::symbolicate()`] gives with
+    /// [`SymbolizedLocation::symbolication()`].
+    pub fn symbolize_batch(
+        &self,
+        addrs: &[lldb_addr_t],
+        need_inlines: bool,
+    ) -> Vec<SymbolizedLocation> {
+        let locations = symbolizedlocation::resolve_sorted(
+            addrs,
+            |address| {
+                let (frames, range) = symbolizedlocation::symbolize(self, address, need_inlines);
+                (Some(SymbolizedLocation { address, frames }), range)
+            },
+            |previous, address| {
+                // Sorting means that `address` is at or after `previous.address`.
+                let delta = address - previous.address;
+                let mut frames = previous.frames.clone();
+                // Only the innermost frame is at the address itself; the
+                // others are at the call sites of the inlined functions.
+                if let Some(frame) = frames.first_mut() {
+                    frame.offset = frame.offset.map(|offset| offset + delta);
+                }
+                SymbolizedLocation { address, frames }
+            },
+        );
+        addrs
+            .iter()
+            .zip(locations)
+            .map(|(&address, location)| {
+                location.unwrap_or(SymbolizedLocation {
+                    address,
+                    frames: Vec::new(),
+                })
+            })
+            .collect()
+    }
+
+    /// Resolve a current load address into a section offset address
+    /// within a specific module.
+    ///
+    /// When several modules claim the same address range (for example,
+    /// images from a shared cache), [`SBTarget::resolve_load_address()`]
+    /// may pick a different module than the one intended. This only
+    /// considers the sections of `module`.
+    ///
+    /// The return value will be `None` if `vm_addr` isn't within a
+    /// loaded section of `module`.
+    pub fn resolve_load_address_in_module(
+        &self,
+        vm_addr: lldb_addr_t,
+        module: &SBModule,
+    ) -> Option<SBAddress> {
+        module
+            .sections()
+            .find_map(|section| self.section_containing_load_address(section, vm_addr))
+            .map(|section| {
+                let offset = vm_addr - section.load_address(self);
+                SBAddress::from_section_offset(&section, offset)
+            })
+    }
+
+    /// Find the most specific section or subsection containing `vm_addr`.
+    fn section_containing_load_address(
+        &self,
+        section: SBSection,
+        vm_addr: lldb_addr_t,
+    ) -> Option<SBSection> {
+        let start = section.load_address(self);
+        if start == LLDB_INVALID_ADDRESS
+            || vm_addr < start
+            || vm_addr - start >= section.byte_size()
+        {
+            return None;
+        }
+        section
+            .subsections()
+            .find_map(|sub| self.section_containing_load_address(sub, vm_addr))
+            .or(Some(section))
+    }
+
+    /// Resolve a current load address, moving it past the function
+    /// prologue if it falls within one.
+    ///
+    /// This matches the placement used by `breakpoint set --skip-prologue`.
+    /// Addresses that are not within a prologue are resolved unchanged.
+    ///
+    /// The return value will be `None` if the `vm_addr` doesn't resolve to
+    /// a section within a module.
+    pub fn resolve_past_prologue(&self, vm_addr: lldb_addr_t) -> Option<SBAddress> {
+        let address = self.resolve_load_address(vm_addr)?;
+        let (start, prologue_size) = if let Some(function) = address.function() {
+            (function.start_address(), function.prologue_byte_size())
+        } else if let Some(symbol) = address.symbol() {
+            (symbol.start_address()?, symbol.prologue_byte_size())
+        } else {
+            return Some(address);
+        };
+        let start = start.load_address(self);
+        if start == LLDB_INVALID_ADDRESS || prologue_size == 0 {
+            return Some(address);
+        }
+        let end = start + u64::from(prologue_size);
+        if (start..end).contains(&vm_addr) {
+            self.resolve_load_address(end)
+        } else {
+            Some(address)
+        }
+    }
+
+    /// Set the load address of a section.
+    ///
+    /// This is useful when there is no dynamic loader to report where
+    /// images are loaded, such as with bare-metal and firmware images.
+    ///
+    /// See also:
+    /// - [`SBTarget::clear_section_load_address()`]
+    /// - [`SBTarget::set_module_load_address()`]
+    pub fn set_section_load_address(
+        &self,
+        section: &SBSection,
+        section_base_addr: lldb_addr_t,
+    ) -> Result<(), SBError> {
+        SBError::wrap(unsafe {
+            sys::SBTargetSetSectionLoadAddress(self.raw, section.raw, section_base_addr)
+        })
+        .into_result()
+    }
+
+    /// Clear the load address of a section, so that it is no longer
+    /// considered to be loaded.
+    ///
+    /// See also:
+    /// - [`SBTarget::set_section_load_address()`]
+    pub fn clear_section_load_address(&self, section: &SBSection) -> Result<(), SBError> {
+        SBError::wrap(unsafe { sys::SBTargetClearSectionLoadAddress(self.raw, section.raw) })
+            .into_result()
+    }
+
+    /// Load all sections of a module, each at its file address
+    /// plus `slide`.
+    ///
+    /// See also:
+    /// - [`SBTarget::clear_module_load_address()`]
+    /// - [`SBTarget::set_section_load_address()`]
+    pub fn set_module_load_address(&self, module: &SBModule, slide: i64) -> Result<(), SBError> {
+        SBError::wrap(unsafe { sys::SBTargetSetModuleLoadAddress(self.raw, module.raw, slide) })
+            .into_result()
+    }
+
+    /// Clear the load addresses of all sections of a module.
+    ///
+    /// See also:
+    /// - [`SBTarget::set_module_load_address()`]
+    pub fn clear_module_load_address(&self, module: &SBModule) -> Result<(), SBError> {
+        SBError::wrap(unsafe { sys::SBTargetClearModuleLoadAddress(self.raw, module.raw) })
+            .into_result()
+    }
+
     #[allow(missing_docs)]
     pub fn delete_breakpoint(&self, break_id: i32) {
         unsafe { sys::SBTargetBreakpointDelete(self.raw, break_id) };
@@ -238,6 +647,13 @@ impl SBTarget {
         unsafe { sys::SBTargetDeleteAllBreakpoints(self.raw) };
     }
 
+    /// Start building a breakpoint.
+    ///
+    /// See [`BreakpointBuilder`].
+    pub fn breakpoint(&self) -> BreakpointBuilder<'_> {
+        BreakpointBuilder::new(self)
+    }
+
     #[allow(missing_docs)]
     pub fn breakpoint_create_by_location(&self, file: &str, line: u32) -> SBBreakpoint {
         let file = CString::new(file).unwrap();
@@ -258,6 +674,143 @@ impl SBTarget {
         })
     }
 
+    /// Create a breakpoint at a source location, limited to the
+    /// modules in `module_list`.
+    ///
+    /// An empty `module_list` places no restriction on which modules
+    /// the breakpoint may resolve within. A `column` of `0` matches
+    /// any column.
+    pub fn breakpoint_create_by_location_in_modules(
+        &self,
+        file_spec: &SBFileSpec,
+        line: u32,
+        column: u32,
+        offset: lldb_addr_t,
+        module_list: &SBFileSpecList,
+        move_to_nearest_code: bool,
+    ) -> SBBreakpoint {
+        SBBreakpoint::wrap(unsafe {
+            sys::SBTargetBreakpointCreateByLocation6(
+                self.raw,
+                file_spec.raw,
+                line,
+                column,
+                offset,
+                module_list.raw,
+                move_to_nearest_code,
+            )
+        })
+    }
+
+    /// Create a breakpoint on functions named `symbol_name`, limited
+    /// to the modules in `module_list` and the compile units in
+    /// `comp_unit_list`.
+    ///
+    /// Empty lists place no restriction on where the breakpoint
+    /// may resolve. `name_type_mask` is made up of [`FunctionNameType`]
+    /// bits.
+    pub fn breakpoint_create_by_name(
+        &self,
+        symbol_name: &str,
+        name_type_mask: FunctionNameType,
+        module_list: &SBFileSpecList,
+        comp_unit_list: &SBFileSpecList,
+    ) -> SBBreakpoint {
+        let symbol_name = CString::new(symbol_name).unwrap();
+        SBBreakpoint::wrap(unsafe {
+            sys::SBTargetBreakpointCreateByName3(
+                self.raw,
+                symbol_name.as_ptr(),
+                name_type_mask.bits(),
+                module_list.raw,
+                comp_unit_list.raw,
+            )
+        })
+    }
+
+    /// Create a breakpoint on functions whose names match
+    /// `symbol_name_regex`, limited to the modules in `module_list`
+    /// and the compile units in `comp_unit_list`.
+    ///
+    /// Empty lists place no restriction on where the breakpoint
+    /// may resolve.
+    pub fn breakpoint_create_by_regex(
+        &self,
+        symbol_name_regex: &str,
+        module_list: &SBFileSpecList,
+        comp_unit_list: &SBFileSpecList,
+    ) -> SBBreakpoint {
+        let symbol_name_regex = CString::new(symbol_name_regex).unwrap();
+        SBBreakpoint::wrap(unsafe {
+            sys::SBTargetBreakpointCreateByRegex2(
+                self.raw,
+                symbol_name_regex.as_ptr(),
+                module_list.raw,
+                comp_unit_list.raw,
+            )
+        })
+    }
+
+    /// Create a breakpoint on source lines matching `source_regex`
+    /// within the files in `source_files`, limited to the modules
+    /// in `module_list`.
+    pub fn breakpoint_create_by_source_regex(
+        &self,
+        source_regex: &str,
+        module_list: &SBFileSpecList,
+        source_files: &SBFileSpecList,
+    ) -> SBBreakpoint {
+        let source_regex = CString::new(source_regex).unwrap();
+        SBBreakpoint::wrap(unsafe {
+            sys::SBTargetBreakpointCreateBySourceRegex2(
+                self.raw,
+                source_regex.as_ptr(),
+                module_list.raw,
+                source_files.raw,
+            )
+        })
+    }
+
+    /// Write all of the breakpoints in this target to a file.
+    ///
+    /// The breakpoints can be recreated later, perhaps in another
+    /// debug session, with [`SBTarget::breakpoints_create_from_file()`].
+    pub fn breakpoints_write_to_file(&self, dest_file: &SBFileSpec) -> Result<(), SBError> {
+        SBError::wrap(unsafe { sys::SBTargetBreakpointsWriteToFile(self.raw, dest_file.raw) })
+            .into_result()
+    }
+
+    /// Write the given breakpoints to a file.
+    ///
+    /// If `append` is `true`, the breakpoints are added to those
+    /// already in the file rather than replacing them.
+    pub fn breakpoint_list_write_to_file(
+        &self,
+        dest_file: &SBFileSpec,
+        breakpoints: &SBBreakpointList,
+        append: bool,
+    ) -> Result<(), SBError> {
+        SBError::wrap(unsafe {
+            sys::SBTargetBreakpointsWriteToFile2(self.raw, dest_file.raw, breakpoints.raw, append)
+        })
+        .into_result()
+    }
+
+    /// Create the breakpoints that were written to a file with
+    /// [`SBTarget::breakpoints_write_to_file()`].
+    ///
+    /// The newly created breakpoints are added to `new_breakpoints`.
+    pub fn breakpoints_create_from_file(
+        &self,
+        source_file: &SBFileSpec,
+        new_breakpoints: &mut SBBreakpointList,
+    ) -> Result<(), SBError> {
+        SBError::wrap(unsafe {
+            sys::SBTargetBreakpointsCreateFromFile(self.raw, source_file.raw, new_breakpoints.raw)
+        })
+        .into_result()
+    }
+
     #[allow(missing_docs)]
     pub fn breakpoints(&self) -> SBTargetBreakpointIter {
         SBTargetBreakpointIter {
@@ -266,6 +819,20 @@ impl SBTarget {
         }
     }
 
+    /// Get an owning iterator over the [breakpoints] known to this target instance.
+    ///
+    /// Unlike [`SBTarget::breakpoints()`], the returned iterator holds its own
+    /// reference to this target, so it can be returned from functions
+    /// or sent to other threads.
+    ///
+    /// [breakpoints]: SBBreakpoint
+    pub fn owned_breakpoints(&self) -> SBTargetOwnedBreakpointIter {
+        SBTargetOwnedBreakpointIter {
+            target: self.clone(),
+            idx: 0,
+        }
+    }
+
     #[allow(missing_docs)]
     pub fn delete_watchpoint(&self, watch_id: i32) {
         unsafe { sys::SBTargetDeleteWatchpoint(self.raw, watch_id) };
@@ -309,6 +876,24 @@ impl SBTarget {
         }
     }
 
+    /// Set the watchpoints saved for this target in a session from
+    /// [`SBDebugger::save_session()`], returning the new watchpoints.
+    ///
+    /// The saved target is found by the path of its executable. This
+    /// must be called once this target has a live process.
+    ///
+    /// Watchpoints are saved by address, so they only watch the same
+    /// variables as before if the process is loaded at the same
+    /// addresses as the saved one. This is usually the case for
+    /// processes launched by LLDB, which disables address space layout
+    /// randomization by default, but not for processes attached to.
+    pub fn restore_watchpoints(
+        &self,
+        session: &SBStructuredData,
+    ) -> Result<Vec<SBWatchpoint>, SBError> {
+        session::restore_watchpoints(self, session)
+    }
+
     #[allow(missing_docs)]
     pub fn watchpoints(&self) -> SBTargetWatchpointIter {
         SBTargetWatchpointIter {
@@ -343,6 +928,42 @@ impl SBTarget {
         })
     }
 
+    /// Find up to `max_matches` global variables named `name` in any of
+    /// the modules in this target.
+    ///
+    /// Their values can be read without evaluating an expression, as
+    /// long as they are in memory.
+    pub fn find_global_variables(&self, name: &str, max_matches: u32) -> SBValueList {
+        let name = CString::new(name).unwrap();
+        SBValueList::wrap(unsafe {
+            sys::SBTargetFindGlobalVariables(self.raw, name.as_ptr(), max_matches)
+        })
+    }
+
+    /// Find up to `max_matches` global variables whose names match
+    /// `name` according to `matchtype`, in any of the modules in this
+    /// target.
+    pub fn find_global_variables_matching(
+        &self,
+        name: &str,
+        max_matches: u32,
+        matchtype: MatchType,
+    ) -> SBValueList {
+        let name = CString::new(name).unwrap();
+        SBValueList::wrap(unsafe {
+            sys::SBTargetFindGlobalVariables2(self.raw, name.as_ptr(), max_matches, matchtype)
+        })
+    }
+
+    /// Find the first global variable named `name` in any of the
+    /// modules in this target.
+    pub fn find_first_global_variable(&self, name: &str) -> Option<SBValue> {
+        let name = CString::new(name).unwrap();
+        SBValue::maybe_wrap(unsafe {
+            sys::SBTargetFindFirstGlobalVariable(self.raw, name.as_ptr())
+        })
+    }
+
     #[allow(missing_docs)]
     pub fn find_symbols(&self, name: &str, symbol_type: SymbolType) -> SBSymbolContextList {
         let name = CString::new(name).unwrap();
@@ -351,6 +972,25 @@ impl SBTarget {
         })
     }
 
+    /// Find the first type matching `name` in any of the modules
+    /// in this target.
+    pub fn find_first_type(&self, name: &str) -> Option<SBType> {
+        let name = CString::new(name).unwrap();
+        SBType::maybe_wrap(unsafe { sys::SBTargetFindFirstType(self.raw, name.as_ptr()) })
+    }
+
+    /// Find all types matching `name` in any of the modules in
+    /// this target.
+    pub fn find_types(&self, name: &str) -> SBTypeList {
+        let name = CString::new(name).unwrap();
+        SBTypeList::wrap(unsafe { sys::SBTargetFindTypes(self.raw, name.as_ptr()) })
+    }
+
+    /// Get the type corresponding to a [`BasicType`] for this target.
+    pub fn basic_type(&self, basic_type: BasicType) -> Option<SBType> {
+        SBType::maybe_wrap(unsafe { sys::SBTargetGetBasicType(self.raw, basic_type) })
+    }
+
     /// Evaluate an expression.
     pub fn evaluate_expression(&self, expression: &str, options: &SBExpressionOptions) -> SBValue {
         let expression = CString::new(expression).unwrap();
@@ -388,14 +1028,55 @@ impl SBTarget {
         unsafe { sys::SBTargetSetLaunchInfo(self.raw, launch_info.raw) };
     }
 
-    /// Returns the byte order of target
+    /// The target triple, such as `"x86_64-apple-macosx"`.
+    pub fn triple(&self) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(sys::SBTargetGetTriple(self.raw).as_ref()?).to_str() {
+                Ok(s) => Some(s),
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// The byte order of the target.
     pub fn byte_order(&self) -> ByteOrder {
         unsafe { sys::SBTargetGetByteOrder(self.raw) }
     }
 
+    /// The size of an address in bytes, such as 8 for 64-bit targets.
+    ///
+    /// This is the width to use when formatting pointers for this
+    /// target.
+    pub fn address_byte_size(&self) -> u32 {
+        unsafe { sys::SBTargetGetAddressByteSize(self.raw) }
+    }
+
     /// Returns the size of address in bytes
     pub fn get_address_byte_size(&self) -> u32 {
-        unsafe { sys::SBTargetGetAddressByteSize(self.raw) }
+        self.address_byte_size()
+    }
+
+    /// The size in bytes of the smallest addressable unit of data
+    /// memory, which is 1 except on some DSP targets.
+    pub fn data_byte_size(&self) -> u32 {
+        unsafe { sys::SBTargetGetDataByteSize(self.raw) }
+    }
+
+    /// The size in bytes of the smallest addressable unit of code
+    /// memory, which is 1 except on some DSP targets.
+    pub fn code_byte_size(&self) -> u32 {
+        unsafe { sys::SBTargetGetCodeByteSize(self.raw) }
+    }
+
+    /// Describe this target at the given level of detail.
+    ///
+    /// This is also available via the `Display` implementation, which
+    /// uses [`DescriptionLevel::Brief`], or [`DescriptionLevel::Full`]
+    /// when formatted with `{:#}`.
+    pub fn describe(&self, level: DescriptionLevel) -> String {
+        let stream = SBStream::new();
+        unsafe { sys::SBTargetGetDescription(self.raw, stream.raw, level) };
+        stream.data().to_string()
     }
 }
 
@@ -409,9 +1090,22 @@ impl Clone for SBTarget {
 
 impl fmt::Debug for SBTarget {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let stream = SBStream::new();
-        unsafe { sys::SBTargetGetDescription(self.raw, stream.raw, DescriptionLevel::Brief) };
-        write!(fmt, "SBTarget {{ {} }}", stream.data())
+        write!(
+            fmt,
+            "SBTarget {{ {} }}",
+            self.describe(DescriptionLevel::Brief)
+        )
+    }
+}
+
+impl fmt::Display for SBTarget {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let level = if fmt.alternate() {
+            DescriptionLevel::Full
+        } else {
+            DescriptionLevel::Brief
+        };
+        write!(fmt, "{}", self.describe(level))
     }
 }
 
@@ -456,6 +1150,41 @@ impl Iterator for SBTargetBreakpointIter<'_> {
 
 impl ExactSizeIterator for SBTargetBreakpointIter<'_> {}
 
+/// Iterate over the [breakpoints] in a [target], holding a reference
+/// to the target.
+///
+/// This is created by [`SBTarget::owned_breakpoints()`].
+///
+/// [breakpoints]: SBBreakpoint
+/// [target]: SBTarget
+pub struct SBTargetOwnedBreakpointIter {
+    target: SBTarget,
+    idx: usize,
+}
+
+impl Iterator for SBTargetOwnedBreakpointIter {
+    type Item = SBBreakpoint;
+
+    fn next(&mut self) -> Option<SBBreakpoint> {
+        if self.idx < unsafe { sys::SBTargetGetNumBreakpoints(self.target.raw) as usize } {
+            let r = Some(SBBreakpoint::wrap(unsafe {
+                sys::SBTargetGetBreakpointAtIndex(self.target.raw, self.idx as u32)
+            }));
+            self.idx += 1;
+            r
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let sz = unsafe { sys::SBTargetGetNumBreakpoints(self.target.raw) } as usize;
+        (sz - self.idx, Some(sz))
+    }
+}
+
+impl ExactSizeIterator for SBTargetOwnedBreakpointIter {}
+
 /// Iterate over the [watchpoints] in a [target].
 ///
 /// [watchpoints]: SBWatchpoint
@@ -509,6 +1238,44 @@ impl<'e> SBTargetEvent<'e> {
             idx: 0,
         }
     }
+
+    /// The broadcast bits for this event.
+    ///
+    /// This can be compared against the `BROADCAST_BIT_*` constants, or
+    /// checked with one of the more specific helpers such as
+    /// [`SBTargetEvent::is_modules_loaded()`].
+    pub fn event_type(&self) -> u32 {
+        self.event.event_type()
+    }
+
+    /// Does this event report that modules were loaded into the target?
+    pub fn is_modules_loaded(&self) -> bool {
+        self.event_type() & Self::BROADCAST_BIT_MODULES_LOADED != 0
+    }
+
+    /// Does this event report that modules were unloaded from the target?
+    pub fn is_modules_unloaded(&self) -> bool {
+        self.event_type() & Self::BROADCAST_BIT_MODULES_UNLOADED != 0
+    }
+
+    /// Does this event report that symbols were loaded for modules in
+    /// the target?
+    pub fn is_symbols_loaded(&self) -> bool {
+        self.event_type() & Self::BROADCAST_BIT_SYMBOLS_LOADED != 0
+    }
+
+    /// Does this event report that the symbols for modules in the target
+    /// have changed?
+    pub fn is_symbols_changed(&self) -> bool {
+        self.event_type() & Self::BROADCAST_BIT_SYMBOLS_CHANGED != 0
+    }
+
+    pub const BROADCAST_BIT_BREAKPOINT_CHANGED: u32 = (1 << 0);
+    pub const BROADCAST_BIT_MODULES_LOADED: u32 = (1 << 1);
+    pub const BROADCAST_BIT_MODULES_UNLOADED: u32 = (1 << 2);
+    pub const BROADCAST_BIT_WATCHPOINT_CHANGED: u32 = (1 << 3);
+    pub const BROADCAST_BIT_SYMBOLS_LOADED: u32 = (1 << 4);
+    pub const BROADCAST_BIT_SYMBOLS_CHANGED: u32 = (1 << 5);
 }
 
 /// Iterate over the [modules] referenced from a [target event].
@@ -576,6 +1343,50 @@ impl Iterator for SBTargetModuleIter<'_> {
 
 impl ExactSizeIterator for SBTargetModuleIter<'_> {}
 
+impl<'d> IntoIterator for &'d SBTarget {
+    type Item = SBModule;
+    type IntoIter = SBTargetModuleIter<'d>;
+
+    fn into_iter(self) -> SBTargetModuleIter<'d> {
+        self.modules()
+    }
+}
+
+/// Iterate over the [modules] in a [target], holding a reference
+/// to the target.
+///
+/// This is created by [`SBTarget::owned_modules()`].
+///
+/// [modules]: SBModule
+/// [target]: SBTarget
+pub struct SBTargetOwnedModuleIter {
+    target: SBTarget,
+    idx: u32,
+}
+
+impl Iterator for SBTargetOwnedModuleIter {
+    type Item = SBModule;
+
+    fn next(&mut self) -> Option<SBModule> {
+        if self.idx < unsafe { sys::SBTargetGetNumModules(self.target.raw) } {
+            let r = Some(SBModule::wrap(unsafe {
+                sys::SBTargetGetModuleAtIndex(self.target.raw, self.idx)
+            }));
+            self.idx += 1;
+            r
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let sz = unsafe { sys::SBTargetGetNumModules(self.target.raw) } as usize;
+        (sz - self.idx as usize, Some(sz))
+    }
+}
+
+impl ExactSizeIterator for SBTargetOwnedModuleIter {}
+
 #[cfg(feature = "graphql")]
 #[juniper::graphql_object]
 impl SBTarget {
@@ -603,3 +1414,23 @@ impl SBTarget {
         self.watchpoints().collect()
     }
 }
+
+/// Quote an argument for the command interpreter.
+pub(crate) fn quote_argument(argument: &str) -> String {
+    double_quote(argument, &['"', '\\', '`'])
+}
+
+/// Wrap `argument` in double quotes, escaping each of the `special`
+/// characters in it with a backslash.
+pub(crate) fn double_quote(argument: &str, special: &[char]) -> String {
+    let mut quoted = String::with_capacity(argument.len() + 2);
+    quoted.push('"');
+    for c in argument.chars() {
+        if special.contains(&c) {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
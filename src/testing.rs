@@ -0,0 +1,543 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Helpers for integration tests.
+//!
+//! This module is only available with the `testing` feature.
+//!
+//! # Fixtures
+//!
+//! [`Fixture`] builds tiny C or Rust programs with debug information
+//! at test time and [`Session`] launches them stopped at their entry
+//! point, with assertions about breakpoints and variables.
+//!
+//! ```no_run
+//! use lldb::testing::{Fixture, Session};
+//!
+//! let fixture = Fixture::c(
+//!     "add",
+//!     "int add(int a, int b) { return a + b; }\n\
+//!      int main(void) { return add(1, 2); }\n",
+//! )
+//! .unwrap();
+//! let session = Session::launch(fixture.path()).unwrap();
+//! let breakpoint = session.break_at_function("add");
+//! session.continue_to_breakpoint(&breakpoint);
+//! session.assert_variable("a", "1");
+//! session.assert_variable("b", "2");
+//! ```
+//!
+//! Fixtures are compiled with the C compiler named by the `CC`
+//! environment variable, or `cc`, and the Rust compiler named by the
+//! `RUSTC` environment variable, or `rustc`. Cargo sets `RUSTC` when
+//! running tests.
+//!
+//! On macOS, the test executable itself must be able to find
+//! `LLDB.framework` when it starts, so `DYLD_FRAMEWORK_PATH` has to be
+//! set in the environment of `cargo test`, typically to the
+//! `SharedFrameworks` directory of Xcode or the `PrivateFrameworks`
+//! directory of the Command Line Tools. It can't be set from within
+//! the test.
+//!
+//! # Remote Debugging
+//!
+//! [`PlatformServer`] and [`GdbServer`] spawn debug servers on free
+//! local ports and connect to them, using the LLDB tools installed on
+//! the system, so that tests of remote flows don't need to manage
+//! these processes themselves.
+//!
+//! ```no_run
+//! use lldb::testing::GdbServer;
+//! use lldb::SBDebugger;
+//!
+//! let _guard = SBDebugger::initialize_guard();
+//! let debugger = SBDebugger::create(false);
+//! let target = debugger.create_target_simple("/bin/ls").unwrap();
+//! let server = GdbServer::spawn("/bin/ls", &["-l"]).expect("no debug server");
+//! let process = server.connect(&target).unwrap();
+//! assert!(process.is_alive());
+//! ```
+//!
+//! Servers are killed when they are dropped.
+
+use crate::{
+    FunctionNameType, InitializeGuard, LaunchFlags, SBBreakpoint, SBDebugger, SBError,
+    SBFileSpecList, SBLaunchInfo, SBPlatform, SBProcess, SBTarget, SBThread, SBValue, StateType,
+    StopReason,
+};
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::io;
+use std::net::{Ipv4Addr, TcpListener};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Distinguishes fixtures built by the same test process.
+static NEXT_FIXTURE: AtomicUsize = AtomicUsize::new(0);
+
+/// How long to keep retrying to connect to a newly spawned server.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A fixture program built with debug information in a temporary
+/// directory.
+///
+/// The directory is removed when the fixture is dropped.
+pub struct Fixture {
+    dir: PathBuf,
+    path: PathBuf,
+}
+
+impl Fixture {
+    /// Build a C program from `source`.
+    pub fn c(name: &str, source: &str) -> io::Result<Fixture> {
+        let compiler = env::var_os("CC").unwrap_or_else(|| "cc".into());
+        Fixture::build(name, "c", source, |source, output| {
+            let mut command = Command::new(&compiler);
+            command
+                .arg("-g")
+                .arg("-O0")
+                .arg("-o")
+                .arg(output)
+                .arg(source);
+            command
+        })
+    }
+
+    /// Build a Rust program from `source`.
+    pub fn rust(name: &str, source: &str) -> io::Result<Fixture> {
+        let compiler = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+        Fixture::build(name, "rs", source, |source, output| {
+            let mut command = Command::new(&compiler);
+            command
+                .arg("-g")
+                .arg("-C")
+                .arg("opt-level=0")
+                .arg("-o")
+                .arg(output)
+                .arg(source);
+            command
+        })
+    }
+
+    fn build(
+        name: &str,
+        extension: &str,
+        source: &str,
+        compile: impl FnOnce(&Path, &Path) -> Command,
+    ) -> io::Result<Fixture> {
+        let dir = env::temp_dir().join(format!(
+            "lldb-rs-fixture-{}-{}",
+            std::process::id(),
+            NEXT_FIXTURE.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&dir)?;
+        let fixture = Fixture {
+            path: dir.join(name).with_extension(env::consts::EXE_EXTENSION),
+            dir,
+        };
+        let source_path = fixture.dir.join(name).with_extension(extension);
+        fs::write(&source_path, source)?;
+        let output = compile(&source_path, &fixture.path).output()?;
+        if output.status.success() {
+            Ok(fixture)
+        } else {
+            Err(io::Error::other(format!(
+                "failed to build fixture {}:\n{}",
+                name,
+                String::from_utf8_lossy(&output.stderr)
+            )))
+        }
+    }
+
+    /// The path to the built executable.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for Fixture {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// A synchronous debugger with a process launched and stopped at its
+/// entry point.
+///
+/// The process is killed and the debugger destroyed when the session
+/// is dropped.
+pub struct Session {
+    debugger: Option<SBDebugger>,
+    target: SBTarget,
+    process: SBProcess,
+    _initialize: InitializeGuard,
+}
+
+impl Session {
+    /// Launch `executable` with [`LaunchFlags::STOP_AT_ENTRY`].
+    ///
+    /// LLDB is kept [initialized] for as long as the session is alive.
+    ///
+    /// [initialized]: SBDebugger::initialize_guard()
+    pub fn launch<P: AsRef<Path>>(executable: P) -> Result<Session, SBError> {
+        Session::launch_with_args(executable, &[])
+    }
+
+    /// Launch `executable` with `args` and
+    /// [`LaunchFlags::STOP_AT_ENTRY`].
+    pub fn launch_with_args<P: AsRef<Path>>(
+        executable: P,
+        args: &[&str],
+    ) -> Result<Session, SBError> {
+        let initialize = SBDebugger::initialize_guard();
+        let debugger = SBDebugger::create(false);
+        debugger.set_asynchronous(false);
+        let executable = executable.as_ref().to_string_lossy();
+        let target = debugger
+            .create_target_simple(&executable)
+            .ok_or_else(|| SBError::from_message(&format!("can't create target {executable}")))?;
+        let launch_info = SBLaunchInfo::new();
+        launch_info.set_launch_flags(LaunchFlags::STOP_AT_ENTRY);
+        launch_info.set_arguments(args.iter().copied(), false);
+        let process = target.launch(launch_info)?;
+        Ok(Session {
+            debugger: Some(debugger),
+            target,
+            process,
+            _initialize: initialize,
+        })
+    }
+
+    /// The debugger.
+    pub fn debugger(&self) -> &SBDebugger {
+        self.debugger.as_ref().unwrap()
+    }
+
+    /// The target for the launched executable.
+    pub fn target(&self) -> &SBTarget {
+        &self.target
+    }
+
+    /// The launched process.
+    pub fn process(&self) -> &SBProcess {
+        &self.process
+    }
+
+    /// Set a breakpoint on the function `name`, asserting that it
+    /// resolved to at least one location.
+    pub fn break_at_function(&self, name: &str) -> SBBreakpoint {
+        let breakpoint = self.target.breakpoint_create_by_name(
+            name,
+            FunctionNameType::AUTO,
+            &SBFileSpecList::new(),
+            &SBFileSpecList::new(),
+        );
+        assert!(
+            breakpoint.locations().next().is_some(),
+            "breakpoint on {name} has no locations"
+        );
+        breakpoint
+    }
+
+    /// Set a breakpoint at `file:line`, asserting that it resolved to
+    /// at least one location.
+    pub fn break_at_line(&self, file: &str, line: u32) -> SBBreakpoint {
+        let breakpoint = self.target.breakpoint_create_by_location(file, line);
+        assert!(
+            breakpoint.locations().next().is_some(),
+            "breakpoint at {file}:{line} has no locations"
+        );
+        breakpoint
+    }
+
+    /// Continue the process, asserting that it stops at `breakpoint`,
+    /// and return the thread that hit it.
+    ///
+    /// The thread is selected, so [`variable()`] and
+    /// [`assert_variable()`] look at the frame where it stopped.
+    ///
+    /// [`variable()`]: Session::variable()
+    /// [`assert_variable()`]: Session::assert_variable()
+    pub fn continue_to_breakpoint(&self, breakpoint: &SBBreakpoint) -> SBThread {
+        self.process
+            .continue_execution()
+            .unwrap_or_else(|error| panic!("failed to continue: {error}"));
+        assert_eq!(self.process.state(), StateType::Stopped);
+        let thread = self
+            .process
+            .threads()
+            .find(|thread| {
+                thread.stop_reason() == StopReason::Breakpoint
+                    && thread.stop_reason_data().first() == Some(&(breakpoint.id() as u64))
+            })
+            .unwrap_or_else(|| panic!("process did not stop at breakpoint {}", breakpoint.id()));
+        self.process.set_selected_thread(&thread);
+        thread
+    }
+
+    /// Look up the variable `name` in the selected frame of the
+    /// selected thread.
+    pub fn variable(&self, name: &str) -> Option<SBValue> {
+        self.process
+            .selected_thread()
+            .selected_frame()
+            .find_variable(name)
+    }
+
+    /// Assert that the variable `name` in the selected frame of the
+    /// selected thread has the value `expected`.
+    pub fn assert_variable(&self, name: &str, expected: &str) {
+        let value = self
+            .variable(name)
+            .unwrap_or_else(|| panic!("variable {name} not found"));
+        assert_eq!(value.value(), Some(expected), "value of {name}");
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        let _ = self.process.kill();
+        if let Some(debugger) = self.debugger.take() {
+            SBDebugger::destroy(debugger);
+        }
+    }
+}
+
+/// Locate the `lldb` command line tool.
+///
+/// The `LLDB` environment variable takes precedence, followed by
+/// `lldb` on the `PATH`.
+pub fn find_lldb() -> Option<PathBuf> {
+    from_env("LLDB").or_else(|| find_in_path("lldb"))
+}
+
+/// Locate `lldb-server`.
+///
+/// The `LLDB_SERVER` environment variable takes precedence, followed
+/// by `lldb-server` on the `PATH` and then `lldb-server` next to the
+/// [`lldb`](find_lldb()) executable, after resolving symlinks.
+pub fn find_lldb_server() -> Option<PathBuf> {
+    from_env("LLDB_SERVER")
+        .or_else(|| find_in_path("lldb-server"))
+        .or_else(|| {
+            let lldb = find_lldb()?.canonicalize().ok()?;
+            let server = lldb.with_file_name("lldb-server");
+            server.is_file().then_some(server)
+        })
+}
+
+/// Locate `debugserver`, the debug server used on macOS.
+///
+/// The `LLDB_DEBUGSERVER_PATH` environment variable, which LLDB itself
+/// also uses, takes precedence, followed by `debugserver` on the
+/// `PATH` and then the copies installed with Xcode or the Command
+/// Line Tools.
+pub fn find_debugserver() -> Option<PathBuf> {
+    const INSTALLED: &[&str] = &[
+        "/Applications/Xcode.app/Contents/SharedFrameworks/LLDB.framework/Versions/A/Resources/debugserver",
+        "/Library/Developer/CommandLineTools/Library/PrivateFrameworks/LLDB.framework/Versions/A/Resources/debugserver",
+    ];
+    from_env("LLDB_DEBUGSERVER_PATH")
+        .or_else(|| find_in_path("debugserver"))
+        .or_else(|| {
+            INSTALLED
+                .iter()
+                .map(PathBuf::from)
+                .find(|path| path.is_file())
+        })
+}
+
+/// The name of the remote platform plugin for the host OS.
+pub fn remote_platform_name() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "remote-macosx"
+    } else if cfg!(target_os = "windows") {
+        "remote-windows"
+    } else if cfg!(target_os = "freebsd") {
+        "remote-freebsd"
+    } else if cfg!(target_os = "netbsd") {
+        "remote-netbsd"
+    } else {
+        "remote-linux"
+    }
+}
+
+/// An `lldb-server platform` process listening on a local port.
+///
+/// Connecting to it with [`PlatformServer::connect()`] allows
+/// processes to be launched and attached to through the remote
+/// platform, as would be done for a remote machine.
+pub struct PlatformServer {
+    child: Child,
+    port: u16,
+}
+
+impl PlatformServer {
+    /// Spawn [`lldb-server`](find_lldb_server()) in platform mode.
+    pub fn spawn() -> io::Result<PlatformServer> {
+        let server = find_lldb_server().ok_or_else(|| not_found("lldb-server"))?;
+        let port = free_port()?;
+        let child = Command::new(server)
+            .arg("platform")
+            .arg("--server")
+            .arg("--listen")
+            .arg(format!("{}:{}", Ipv4Addr::LOCALHOST, port))
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+        Ok(PlatformServer { child, port })
+    }
+
+    /// The port that the server is listening on.
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// The URL to connect to the server.
+    pub fn url(&self) -> String {
+        format!("connect://{}:{}", Ipv4Addr::LOCALHOST, self.port)
+    }
+
+    /// Connect a new [remote platform](remote_platform_name()) to the
+    /// server and select it in `debugger`, so that subsequently created
+    /// targets use it.
+    ///
+    /// Connecting is retried for a while, as the server may not have
+    /// started listening yet.
+    pub fn connect(&self, debugger: &SBDebugger) -> Result<SBPlatform, SBError> {
+        let platform = SBPlatform::new(remote_platform_name());
+        retry(|| platform.connect_remote(&self.url()))?;
+        debugger.set_selected_platform(&platform);
+        Ok(platform)
+    }
+}
+
+impl Drop for PlatformServer {
+    fn drop(&mut self) {
+        kill(&mut self.child);
+    }
+}
+
+/// A debug server, either `debugserver` or `lldb-server gdbserver`,
+/// running a program and waiting for a connection on a local port.
+pub struct GdbServer {
+    child: Child,
+    port: u16,
+}
+
+impl GdbServer {
+    /// Spawn a debug server to launch `program` with `args`.
+    ///
+    /// On macOS, [`debugserver`](find_debugserver()) is used if it is
+    /// available. Otherwise, [`lldb-server`](find_lldb_server()) is
+    /// used in `gdbserver` mode.
+    pub fn spawn<P, S>(program: P, args: &[S]) -> io::Result<GdbServer>
+    where
+        P: AsRef<Path>,
+        S: AsRef<OsStr>,
+    {
+        let port = free_port()?;
+        let address = format!("{}:{}", Ipv4Addr::LOCALHOST, port);
+        let debugserver = if cfg!(target_os = "macos") {
+            find_debugserver()
+        } else {
+            None
+        };
+        let mut command = match debugserver {
+            Some(debugserver) => {
+                let mut command = Command::new(debugserver);
+                command.arg(address);
+                command
+            }
+            None => {
+                let server = find_lldb_server().ok_or_else(|| not_found("lldb-server"))?;
+                let mut command = Command::new(server);
+                command.arg("gdbserver").arg(address).arg("--");
+                command
+            }
+        };
+        let child = command
+            .arg(program.as_ref())
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+        Ok(GdbServer { child, port })
+    }
+
+    /// The port that the server is listening on.
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// The URL to connect to the server.
+    pub fn url(&self) -> String {
+        format!("connect://{}:{}", Ipv4Addr::LOCALHOST, self.port)
+    }
+
+    /// Connect `target` to the server, returning the process, which
+    /// will be stopped at its entry point.
+    ///
+    /// Connecting is retried for a while, as the server may not have
+    /// started listening yet.
+    pub fn connect(&self, target: &SBTarget) -> Result<SBProcess, SBError> {
+        let listener = target.debugger().listener();
+        retry(|| target.connect_remote(&listener, &self.url(), Some("gdb-remote")))
+    }
+}
+
+impl Drop for GdbServer {
+    fn drop(&mut self) {
+        kill(&mut self.child);
+    }
+}
+
+fn from_env(var: &str) -> Option<PathBuf> {
+    let path = PathBuf::from(env::var_os(var)?);
+    path.is_file().then_some(path)
+}
+
+fn find_in_path(name: &str) -> Option<PathBuf> {
+    let name = Path::new(name).with_extension(env::consts::EXE_EXTENSION);
+    env::split_paths(&env::var_os("PATH")?)
+        .map(|dir| dir.join(&name))
+        .find(|path| path.is_file())
+}
+
+fn not_found(name: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, format!("{name} not found"))
+}
+
+/// Find a free local port by binding to port 0 and releasing it.
+///
+/// There is a small window in which another process could take the
+/// port, but this is acceptable for tests.
+fn free_port() -> io::Result<u16> {
+    Ok(TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?
+        .local_addr()?
+        .port())
+}
+
+fn retry<T>(mut f: impl FnMut() -> Result<T, SBError>) -> Result<T, SBError> {
+    let deadline = Instant::now() + CONNECT_TIMEOUT;
+    loop {
+        match f() {
+            Err(_) if Instant::now() < deadline => thread::sleep(Duration::from_millis(100)),
+            result => return result,
+        }
+    }
+}
+
+fn kill(child: &mut Child) {
+    let _ = child.kill();
+    let _ = child.wait();
+}
@@ -5,8 +5,9 @@
 // except according to those terms.
 
 use crate::{
-    lldb_tid_t, sys, RunMode, SBError, SBEvent, SBFileSpec, SBFrame, SBProcess, SBQueue, SBStream,
-    SBValue, StopReason,
+    lldb_tid_t, sys, DescriptionLevel, RunMode, SBError, SBEvent, SBFileSpec, SBFrame, SBProcess,
+    SBQueue, SBStream, SBStructuredData, SBValue, SanitizerReport, StopReason,
+    LLDB_INVALID_LINE_NUMBER, LLDB_INVALID_THREAD_ID,
 };
 use std::ffi::{CStr, CString};
 use std::fmt;
@@ -97,6 +98,72 @@ impl SBThread {
         unsafe { sys::SBThreadGetStopReason(self.raw) }
     }
 
+    /// The data associated with the stop reason, such as the breakpoint
+    /// and location IDs for [`StopReason::Breakpoint`] or the exception
+    /// type and codes for a Mach exception.
+    ///
+    /// The meaning of each item depends on the [stop reason].
+    ///
+    /// [stop reason]: SBThread::stop_reason()
+    pub fn stop_reason_data(&self) -> Vec<u64> {
+        let count = unsafe { sys::SBThreadGetStopReasonDataCount(self.raw) } as u32;
+        (0..count)
+            .map(|idx| unsafe { sys::SBThreadGetStopReasonDataAtIndex(self.raw, idx) })
+            .collect()
+    }
+
+    /// Extended information about the stop reason as JSON, such as the
+    /// details of a memory tagging fault or an instrumentation runtime
+    /// report.
+    ///
+    /// Returns `None` if there is no extended information for the
+    /// current stop reason.
+    pub fn stop_reason_extended_info_json(&self) -> Option<String> {
+        let stream = SBStream::new();
+        if unsafe { sys::SBThreadGetStopReasonExtendedInfoAsJSON(self.raw, stream.raw) }
+            && !stream.is_empty()
+        {
+            Some(stream.data().to_string())
+        } else {
+            None
+        }
+    }
+
+    /// Extended information about the stop reason as structured data.
+    ///
+    /// See [`SBThread::stop_reason_extended_info_json()`].
+    pub fn stop_reason_extended_info(&self) -> Option<SBStructuredData> {
+        let stream = SBStream::new();
+        if !unsafe { sys::SBThreadGetStopReasonExtendedInfoAsJSON(self.raw, stream.raw) }
+            || stream.is_empty()
+        {
+            return None;
+        }
+        let data = SBStructuredData::wrap(unsafe { sys::CreateSBStructuredData() });
+        data.set_from_json(&stream).ok()?;
+        Some(data)
+    }
+
+    /// The report from the instrumentation runtime, such as
+    /// the address sanitizer, if this thread stopped with
+    /// [`StopReason::Instrumentation`].
+    pub fn sanitizer_report(&self) -> Option<SanitizerReport> {
+        if self.stop_reason() != StopReason::Instrumentation {
+            return None;
+        }
+        let data = self.stop_reason_extended_info()?;
+        Some(SanitizerReport::new(self.clone(), data))
+    }
+
+    /// The `siginfo_t` for the signal that stopped this thread, if the
+    /// platform and process plugin provide it.
+    ///
+    /// This is available on Linux and the BSDs when the thread stopped
+    /// due to a [signal](StopReason::Signal).
+    pub fn siginfo(&self) -> Option<SBValue> {
+        SBValue::maybe_wrap(unsafe { sys::SBThreadGetSiginfo(self.raw) })
+    }
+
     /// The return value from the last stop if we just stopped due
     /// to stepping out of a function
     pub fn stop_return_value(&self) -> Option<SBValue> {
@@ -114,6 +181,17 @@ impl SBThread {
         unsafe { sys::SBThreadGetThreadID(self.raw) }
     }
 
+    /// Returns the thread identifier, or `None` if this thread
+    /// is not valid.
+    ///
+    /// See [`SBThread::thread_id()`].
+    pub fn try_thread_id(&self) -> Option<lldb_tid_t> {
+        match self.thread_id() {
+            LLDB_INVALID_THREAD_ID => None,
+            tid => Some(tid),
+        }
+    }
+
     /// Return the index number for this `SBThread`.  The index
     /// number is the same thing that a user gives as an argument
     /// to `thread select` in the command line lldb.
@@ -150,6 +228,18 @@ impl SBThread {
         unsafe { self.check_null_ptr(sys::SBThreadGetQueueName(self.raw)) }
     }
 
+    /// Gather the commonly displayed details of this thread, such as
+    /// for a list of threads in a UI.
+    pub fn info(&self) -> ThreadInfo {
+        ThreadInfo {
+            id: self.thread_id(),
+            index_id: self.index_id(),
+            name: self.name().map(|s| s.to_string()),
+            queue: self.queue_name().map(|s| s.to_string()),
+            stop_reason: self.stop_reason(),
+        }
+    }
+
     /// Return the `dispatch_queue_id` for this thread, if any.
     ///
     /// For example, this would report a `libdispatch` (Grand Central Dispatch)
@@ -206,6 +296,36 @@ impl SBThread {
         }
     }
 
+    /// Get a snapshot of the [frames] known to this thread instance.
+    ///
+    /// Like [`SBProcess::threads_snapshot()`], this asks for the number
+    /// of frames only once, rather than before each item as
+    /// [`SBThread::frames()`] does.
+    ///
+    /// [frames]: SBFrame
+    pub fn frames_snapshot(&self) -> Vec<SBFrame> {
+        let count = unsafe { sys::SBThreadGetNumFrames(self.raw) };
+        (0..count)
+            .filter_map(|idx| {
+                SBFrame::maybe_wrap(unsafe { sys::SBThreadGetFrameAtIndex(self.raw, idx) })
+            })
+            .collect()
+    }
+
+    /// Get an owning iterator over the [frames] known to this thread instance.
+    ///
+    /// Unlike [`SBThread::frames()`], the returned iterator holds its own
+    /// reference to this thread, so it can be returned from functions
+    /// or sent to other threads.
+    ///
+    /// [frames]: SBFrame
+    pub fn owned_frames(&self) -> SBThreadOwnedFrameIter {
+        SBThreadOwnedFrameIter {
+            thread: self.clone(),
+            idx: 0,
+        }
+    }
+
     /// Get the currently selected frame for this thread.
     pub fn selected_frame(&self) -> SBFrame {
         SBFrame::wrap(unsafe { sys::SBThreadGetSelectedFrame(self.raw) })
@@ -216,6 +336,13 @@ impl SBThread {
         SBFrame::maybe_wrap(unsafe { sys::SBThreadSetSelectedFrame(self.raw, frame_index) })
     }
 
+    /// Is this the [selected thread] of its process?
+    ///
+    /// [selected thread]: SBProcess::selected_thread()
+    pub fn is_selected(&self) -> bool {
+        self.is_valid() && self.process().selected_thread().thread_id() == self.thread_id()
+    }
+
     /// Get the process in which this thread is running.
     pub fn process(&self) -> SBProcess {
         SBProcess::wrap(unsafe { sys::SBThreadGetProcess(self.raw) })
@@ -239,7 +366,11 @@ impl SBThread {
         }
     }
 
-    #[allow(missing_docs)]
+    /// Step into a function call on the current line, stopping if the
+    /// step reaches `end_line` instead.
+    ///
+    /// If `target_name` is given, only a call to the function with that
+    /// name is stepped into. See [`SBThread::step_into_target()`].
     pub fn step_into_until(
         &self,
         target_name: Option<&str>,
@@ -252,7 +383,7 @@ impl SBThread {
         unsafe {
             sys::SBThreadStepInto3(
                 self.raw,
-                target_name.map(|s| s.as_ptr()).unwrap_or_else(ptr::null),
+                target_name.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
                 end_line,
                 error.raw,
                 stop_other_threads,
@@ -265,6 +396,27 @@ impl SBThread {
         }
     }
 
+    /// Step into a particular function called on the current line,
+    /// as with `thread step-in --step-in-target`.
+    ///
+    /// When a line contains several calls, such as `f(g(x), h(y))`,
+    /// this steps over the calls to other functions until `function_name`
+    /// is called and then stops in it. The step ends without entering any
+    /// function if the call is not reached before `end_line`, or before
+    /// the end of the current line if `end_line` is `None`.
+    pub fn step_into_target(
+        &self,
+        function_name: &str,
+        end_line: Option<u32>,
+        stop_other_threads: RunMode,
+    ) -> Result<(), SBError> {
+        self.step_into_until(
+            Some(function_name),
+            end_line.unwrap_or(LLDB_INVALID_LINE_NUMBER),
+            stop_other_threads,
+        )
+    }
+
     #[allow(missing_docs)]
     pub fn step_out(&self) -> Result<(), SBError> {
         let error = SBError::default();
@@ -331,6 +483,44 @@ impl SBThread {
             None
         }
     }
+
+    /// Describe this thread at the given level of detail.
+    ///
+    /// At [`DescriptionLevel::Brief`], this is a one line summary
+    /// of the thread. Otherwise, this is the thread's status as
+    /// reported by `thread info`, including the current frame.
+    ///
+    /// This is also available via the `Display` implementation, which
+    /// uses [`DescriptionLevel::Brief`], or [`DescriptionLevel::Full`]
+    /// when formatted with `{:#}`.
+    pub fn describe(&self, level: DescriptionLevel) -> String {
+        let stream = SBStream::new();
+        if level == DescriptionLevel::Brief {
+            unsafe { sys::SBThreadGetDescription(self.raw, stream.raw) };
+        } else {
+            unsafe { sys::SBThreadGetStatus(self.raw, stream.raw) };
+        }
+        stream.data().to_string()
+    }
+}
+
+/// The commonly displayed details of a thread, as gathered by
+/// [`SBThread::info()`].
+///
+/// Unlike an [`SBThread`], this is a plain snapshot which doesn't
+/// change as the process runs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThreadInfo {
+    /// The system thread ID. See [`SBThread::thread_id()`].
+    pub id: lldb_tid_t,
+    /// The index ID assigned by LLDB. See [`SBThread::index_id()`].
+    pub index_id: u32,
+    /// The name of the thread. Most threads are unnamed.
+    pub name: Option<String>,
+    /// The name of the queue that the thread is servicing, if any.
+    pub queue: Option<String>,
+    /// Why the thread stopped.
+    pub stop_reason: StopReason,
 }
 
 /// Iterate over the [frames] in a [thread].
@@ -365,6 +555,50 @@ impl Iterator for SBThreadFrameIter<'_> {
 
 impl ExactSizeIterator for SBThreadFrameIter<'_> {}
 
+impl<'d> IntoIterator for &'d SBThread {
+    type Item = SBFrame;
+    type IntoIter = SBThreadFrameIter<'d>;
+
+    fn into_iter(self) -> SBThreadFrameIter<'d> {
+        self.frames()
+    }
+}
+
+/// Iterate over the [frames] in a [thread], holding a reference
+/// to the thread.
+///
+/// This is created by [`SBThread::owned_frames()`].
+///
+/// [frames]: SBFrame
+/// [thread]: SBThread
+pub struct SBThreadOwnedFrameIter {
+    thread: SBThread,
+    idx: usize,
+}
+
+impl Iterator for SBThreadOwnedFrameIter {
+    type Item = SBFrame;
+
+    fn next(&mut self) -> Option<SBFrame> {
+        if self.idx < unsafe { sys::SBThreadGetNumFrames(self.thread.raw) as usize } {
+            let r = Some(SBFrame::wrap(unsafe {
+                sys::SBThreadGetFrameAtIndex(self.thread.raw, self.idx as u32)
+            }));
+            self.idx += 1;
+            r
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let sz = unsafe { sys::SBThreadGetNumFrames(self.thread.raw) } as usize;
+        (sz - self.idx, Some(sz))
+    }
+}
+
+impl ExactSizeIterator for SBThreadOwnedFrameIter {}
+
 impl Clone for SBThread {
     fn clone(&self) -> SBThread {
         SBThread {
@@ -375,9 +609,22 @@ impl Clone for SBThread {
 
 impl fmt::Debug for SBThread {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let stream = SBStream::new();
-        unsafe { sys::SBThreadGetDescription(self.raw, stream.raw) };
-        write!(fmt, "SBThread {{ {} }}", stream.data())
+        write!(
+            fmt,
+            "SBThread {{ {} }}",
+            self.describe(DescriptionLevel::Brief)
+        )
+    }
+}
+
+impl fmt::Display for SBThread {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let level = if fmt.alternate() {
+            DescriptionLevel::Full
+        } else {
+            DescriptionLevel::Brief
+        };
+        write!(fmt, "{}", self.describe(level))
     }
 }
 
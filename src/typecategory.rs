@@ -0,0 +1,264 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{
+    sys, DescriptionLevel, LanguageType, SBStream, SBTypeFilter, SBTypeFormat, SBTypeNameSpecifier,
+    SBTypeSummary, SBTypeSynthetic,
+};
+use std::ffi::CStr;
+use std::fmt;
+
+/// A named, independently enabled group of data formatters.
+///
+/// Categories are looked up or created via the debugger:
+///
+/// ```no_run
+/// use lldb::{SBDebugger, SBTypeNameSpecifier, SBTypeSummary, TypeOptions};
+///
+/// let debugger = SBDebugger::create(false);
+/// if let Some(category) = debugger.create_category("my-tool") {
+///     category.add_type_summary(
+///         &SBTypeNameSpecifier::new("Point", false),
+///         &SBTypeSummary::with_summary_string("(${var.x}, ${var.y})", TypeOptions::CASCADE),
+///     );
+///     category.set_enabled(true);
+/// }
+/// ```
+///
+/// See also:
+/// - [`SBDebugger::get_category()`](crate::SBDebugger::get_category)
+/// - [`SBDebugger::create_category()`](crate::SBDebugger::create_category)
+pub struct SBTypeCategory {
+    /// The underlying raw `SBTypeCategoryRef`.
+    pub raw: sys::SBTypeCategoryRef,
+}
+
+impl SBTypeCategory {
+    /// Construct a new `SBTypeCategory`.
+    pub(crate) fn wrap(raw: sys::SBTypeCategoryRef) -> SBTypeCategory {
+        SBTypeCategory { raw }
+    }
+
+    /// Construct a new `Some(SBTypeCategory)` or `None`.
+    pub(crate) fn maybe_wrap(raw: sys::SBTypeCategoryRef) -> Option<SBTypeCategory> {
+        if unsafe { sys::SBTypeCategoryIsValid(raw) } {
+            Some(SBTypeCategory { raw })
+        } else {
+            None
+        }
+    }
+
+    /// Check whether or not this is a valid `SBTypeCategory` value.
+    pub fn is_valid(&self) -> bool {
+        unsafe { sys::SBTypeCategoryIsValid(self.raw) }
+    }
+
+    /// The name of this category.
+    pub fn name(&self) -> &str {
+        unsafe {
+            match CStr::from_ptr(sys::SBTypeCategoryGetName(self.raw)).to_str() {
+                Ok(s) => s,
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// Are the formatters in this category in use?
+    pub fn enabled(&self) -> bool {
+        unsafe { sys::SBTypeCategoryGetEnabled(self.raw) }
+    }
+
+    /// Enable or disable the formatters in this category.
+    pub fn set_enabled(&self, enabled: bool) {
+        unsafe { sys::SBTypeCategorySetEnabled(self.raw, enabled) };
+    }
+
+    /// The languages that this category applies to.
+    pub fn languages(&self) -> Vec<LanguageType> {
+        let count = unsafe { sys::SBTypeCategoryGetNumLanguages(self.raw) };
+        (0..count)
+            .map(|idx| unsafe { sys::SBTypeCategoryGetLanguageAtIndex(self.raw, idx) })
+            .collect()
+    }
+
+    /// Restrict this category to values of the given language, in
+    /// addition to any languages already added.
+    pub fn add_language(&self, language: LanguageType) {
+        unsafe { sys::SBTypeCategoryAddLanguage(self.raw, language) };
+    }
+
+    /// The formats in this category, with the types they apply to.
+    pub fn formats(&self) -> Vec<(SBTypeNameSpecifier, SBTypeFormat)> {
+        let count = unsafe { sys::SBTypeCategoryGetNumFormats(self.raw) };
+        (0..count)
+            .map(|idx| unsafe {
+                (
+                    SBTypeNameSpecifier::wrap(
+                        sys::SBTypeCategoryGetTypeNameSpecifierForFormatAtIndex(self.raw, idx),
+                    ),
+                    SBTypeFormat::wrap(sys::SBTypeCategoryGetFormatAtIndex(self.raw, idx)),
+                )
+            })
+            .collect()
+    }
+
+    /// The summaries in this category, with the types they apply to.
+    pub fn summaries(&self) -> Vec<(SBTypeNameSpecifier, SBTypeSummary)> {
+        let count = unsafe { sys::SBTypeCategoryGetNumSummaries(self.raw) };
+        (0..count)
+            .map(|idx| unsafe {
+                (
+                    SBTypeNameSpecifier::wrap(
+                        sys::SBTypeCategoryGetTypeNameSpecifierForSummaryAtIndex(self.raw, idx),
+                    ),
+                    SBTypeSummary::wrap(sys::SBTypeCategoryGetSummaryAtIndex(self.raw, idx)),
+                )
+            })
+            .collect()
+    }
+
+    /// The filters in this category, with the types they apply to.
+    pub fn filters(&self) -> Vec<(SBTypeNameSpecifier, SBTypeFilter)> {
+        let count = unsafe { sys::SBTypeCategoryGetNumFilters(self.raw) };
+        (0..count)
+            .map(|idx| unsafe {
+                (
+                    SBTypeNameSpecifier::wrap(
+                        sys::SBTypeCategoryGetTypeNameSpecifierForFilterAtIndex(self.raw, idx),
+                    ),
+                    SBTypeFilter::wrap(sys::SBTypeCategoryGetFilterAtIndex(self.raw, idx)),
+                )
+            })
+            .collect()
+    }
+
+    /// The synthetic children providers in this category, with the
+    /// types they apply to.
+    pub fn synthetics(&self) -> Vec<(SBTypeNameSpecifier, SBTypeSynthetic)> {
+        let count = unsafe { sys::SBTypeCategoryGetNumSynthetics(self.raw) };
+        (0..count)
+            .map(|idx| unsafe {
+                (
+                    SBTypeNameSpecifier::wrap(
+                        sys::SBTypeCategoryGetTypeNameSpecifierForSyntheticAtIndex(self.raw, idx),
+                    ),
+                    SBTypeSynthetic::wrap(sys::SBTypeCategoryGetSyntheticAtIndex(self.raw, idx)),
+                )
+            })
+            .collect()
+    }
+
+    /// The format registered for exactly this type specifier, if any.
+    pub fn format_for_type(&self, spec: &SBTypeNameSpecifier) -> Option<SBTypeFormat> {
+        SBTypeFormat::maybe_wrap(unsafe { sys::SBTypeCategoryGetFormatForType(self.raw, spec.raw) })
+    }
+
+    /// The summary registered for exactly this type specifier, if any.
+    pub fn summary_for_type(&self, spec: &SBTypeNameSpecifier) -> Option<SBTypeSummary> {
+        SBTypeSummary::maybe_wrap(unsafe {
+            sys::SBTypeCategoryGetSummaryForType(self.raw, spec.raw)
+        })
+    }
+
+    /// The filter registered for exactly this type specifier, if any.
+    pub fn filter_for_type(&self, spec: &SBTypeNameSpecifier) -> Option<SBTypeFilter> {
+        SBTypeFilter::maybe_wrap(unsafe { sys::SBTypeCategoryGetFilterForType(self.raw, spec.raw) })
+    }
+
+    /// The synthetic children provider registered for exactly this
+    /// type specifier, if any.
+    pub fn synthetic_for_type(&self, spec: &SBTypeNameSpecifier) -> Option<SBTypeSynthetic> {
+        SBTypeSynthetic::maybe_wrap(unsafe {
+            sys::SBTypeCategoryGetSyntheticForType(self.raw, spec.raw)
+        })
+    }
+
+    /// Add a format for the given types, replacing any existing one.
+    ///
+    /// Returns `false` if the format could not be added.
+    pub fn add_type_format(&self, spec: &SBTypeNameSpecifier, format: &SBTypeFormat) -> bool {
+        unsafe { sys::SBTypeCategoryAddTypeFormat(self.raw, spec.raw, format.raw) }
+    }
+
+    /// Remove the format for the given types.
+    ///
+    /// Returns `false` if there was no such format.
+    pub fn delete_type_format(&self, spec: &SBTypeNameSpecifier) -> bool {
+        unsafe { sys::SBTypeCategoryDeleteTypeFormat(self.raw, spec.raw) }
+    }
+
+    /// Add a summary for the given types, replacing any existing one.
+    ///
+    /// Returns `false` if the summary could not be added.
+    pub fn add_type_summary(&self, spec: &SBTypeNameSpecifier, summary: &SBTypeSummary) -> bool {
+        unsafe { sys::SBTypeCategoryAddTypeSummary(self.raw, spec.raw, summary.raw) }
+    }
+
+    /// Remove the summary for the given types.
+    ///
+    /// Returns `false` if there was no such summary.
+    pub fn delete_type_summary(&self, spec: &SBTypeNameSpecifier) -> bool {
+        unsafe { sys::SBTypeCategoryDeleteTypeSummary(self.raw, spec.raw) }
+    }
+
+    /// Add a filter for the given types, replacing any existing one.
+    ///
+    /// Returns `false` if the filter could not be added.
+    pub fn add_type_filter(&self, spec: &SBTypeNameSpecifier, filter: &SBTypeFilter) -> bool {
+        unsafe { sys::SBTypeCategoryAddTypeFilter(self.raw, spec.raw, filter.raw) }
+    }
+
+    /// Remove the filter for the given types.
+    ///
+    /// Returns `false` if there was no such filter.
+    pub fn delete_type_filter(&self, spec: &SBTypeNameSpecifier) -> bool {
+        unsafe { sys::SBTypeCategoryDeleteTypeFilter(self.raw, spec.raw) }
+    }
+
+    /// Add a synthetic children provider for the given types, replacing
+    /// any existing one.
+    ///
+    /// Returns `false` if the provider could not be added.
+    pub fn add_type_synthetic(
+        &self,
+        spec: &SBTypeNameSpecifier,
+        synthetic: &SBTypeSynthetic,
+    ) -> bool {
+        unsafe { sys::SBTypeCategoryAddTypeSynthetic(self.raw, spec.raw, synthetic.raw) }
+    }
+
+    /// Remove the synthetic children provider for the given types.
+    ///
+    /// Returns `false` if there was no such provider.
+    pub fn delete_type_synthetic(&self, spec: &SBTypeNameSpecifier) -> bool {
+        unsafe { sys::SBTypeCategoryDeleteTypeSynthetic(self.raw, spec.raw) }
+    }
+}
+
+impl Clone for SBTypeCategory {
+    fn clone(&self) -> SBTypeCategory {
+        SBTypeCategory {
+            raw: unsafe { sys::CloneSBTypeCategory(self.raw) },
+        }
+    }
+}
+
+impl fmt::Debug for SBTypeCategory {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let stream = SBStream::new();
+        unsafe { sys::SBTypeCategoryGetDescription(self.raw, stream.raw, DescriptionLevel::Brief) };
+        write!(fmt, "SBTypeCategory {{ {} }}", stream.data())
+    }
+}
+
+impl Drop for SBTypeCategory {
+    fn drop(&mut self) {
+        unsafe { sys::DisposeSBTypeCategory(self.raw) };
+    }
+}
+
+unsafe impl Send for SBTypeCategory {}
+unsafe impl Sync for SBTypeCategory {}
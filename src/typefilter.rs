@@ -0,0 +1,144 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{sys, DescriptionLevel, SBStream, TypeOptions};
+use std::ffi::{CStr, CString};
+use std::fmt;
+
+/// A data formatter which limits the children shown for values of
+/// a type, as with `type filter add`.
+///
+/// Only the children named by the expression paths, such as `.x`
+/// or `->next`, are shown.
+pub struct SBTypeFilter {
+    /// The underlying raw `SBTypeFilterRef`.
+    pub raw: sys::SBTypeFilterRef,
+}
+
+impl SBTypeFilter {
+    /// Construct a new, empty `SBTypeFilter`.
+    pub fn new(options: TypeOptions) -> SBTypeFilter {
+        SBTypeFilter::wrap(unsafe { sys::CreateSBTypeFilter2(options.bits()) })
+    }
+
+    /// Construct a new `SBTypeFilter`.
+    pub(crate) fn wrap(raw: sys::SBTypeFilterRef) -> SBTypeFilter {
+        SBTypeFilter { raw }
+    }
+
+    /// Construct a new `Some(SBTypeFilter)` or `None`.
+    pub(crate) fn maybe_wrap(raw: sys::SBTypeFilterRef) -> Option<SBTypeFilter> {
+        if unsafe { sys::SBTypeFilterIsValid(raw) } {
+            Some(SBTypeFilter { raw })
+        } else {
+            None
+        }
+    }
+
+    /// Check whether or not this is a valid `SBTypeFilter` value.
+    pub fn is_valid(&self) -> bool {
+        unsafe { sys::SBTypeFilterIsValid(self.raw) }
+    }
+
+    /// Iterate over the expression paths of the children to show.
+    pub fn expression_paths(&self) -> SBTypeFilterExpressionPathIter<'_> {
+        SBTypeFilterExpressionPathIter {
+            filter: self,
+            idx: 0,
+        }
+    }
+
+    /// Add the expression path of a child to show.
+    pub fn append_expression_path(&self, path: &str) {
+        let path = CString::new(path).unwrap();
+        unsafe { sys::SBTypeFilterAppendExpressionPath(self.raw, path.as_ptr()) };
+    }
+
+    /// Replace the expression path at the given index.
+    ///
+    /// Returns `false` if there is no expression path at `idx`.
+    pub fn replace_expression_path_at_index(&self, idx: u32, path: &str) -> bool {
+        let path = CString::new(path).unwrap();
+        unsafe { sys::SBTypeFilterReplaceExpressionPathAtIndex(self.raw, idx, path.as_ptr()) }
+    }
+
+    /// Remove all expression paths.
+    pub fn clear(&self) {
+        unsafe { sys::SBTypeFilterClear(self.raw) };
+    }
+
+    /// The options controlling which values this filter applies to.
+    pub fn options(&self) -> TypeOptions {
+        TypeOptions::from_bits_truncate(unsafe { sys::SBTypeFilterGetOptions(self.raw) })
+    }
+
+    /// Set the options controlling which values this filter applies to.
+    pub fn set_options(&self, options: TypeOptions) {
+        unsafe { sys::SBTypeFilterSetOptions(self.raw, options.bits()) };
+    }
+}
+
+impl Clone for SBTypeFilter {
+    fn clone(&self) -> SBTypeFilter {
+        SBTypeFilter {
+            raw: unsafe { sys::CloneSBTypeFilter(self.raw) },
+        }
+    }
+}
+
+impl fmt::Debug for SBTypeFilter {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let stream = SBStream::new();
+        unsafe { sys::SBTypeFilterGetDescription(self.raw, stream.raw, DescriptionLevel::Brief) };
+        write!(fmt, "SBTypeFilter {{ {} }}", stream.data())
+    }
+}
+
+impl Drop for SBTypeFilter {
+    fn drop(&mut self) {
+        unsafe { sys::DisposeSBTypeFilter(self.raw) };
+    }
+}
+
+unsafe impl Send for SBTypeFilter {}
+unsafe impl Sync for SBTypeFilter {}
+
+/// Iterate over the expression paths in a [type filter].
+///
+/// [type filter]: SBTypeFilter
+pub struct SBTypeFilterExpressionPathIter<'d> {
+    filter: &'d SBTypeFilter,
+    idx: u32,
+}
+
+impl<'d> Iterator for SBTypeFilterExpressionPathIter<'d> {
+    type Item = &'d str;
+
+    fn next(&mut self) -> Option<&'d str> {
+        let raw = self.filter.raw;
+        if self.idx < unsafe { sys::SBTypeFilterGetNumberOfExpressionPaths(raw) } {
+            let r = unsafe {
+                match CStr::from_ptr(sys::SBTypeFilterGetExpressionPathAtIndex(raw, self.idx))
+                    .to_str()
+                {
+                    Ok(s) => s,
+                    _ => panic!("Invalid string?"),
+                }
+            };
+            self.idx += 1;
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let sz = unsafe { sys::SBTypeFilterGetNumberOfExpressionPaths(self.filter.raw) } as usize;
+        (sz - self.idx as usize, Some(sz))
+    }
+}
+
+impl ExactSizeIterator for SBTypeFilterExpressionPathIter<'_> {}
@@ -0,0 +1,114 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{sys, DescriptionLevel, Format, SBStream, TypeOptions};
+use std::ffi::{CStr, CString};
+use std::fmt;
+
+/// A data formatter which controls how values of a type are
+/// formatted, as with `type format add`.
+///
+/// A format either displays values in a given [`Format`], such as
+/// hexadecimal, or displays them as though they were of another type.
+pub struct SBTypeFormat {
+    /// The underlying raw `SBTypeFormatRef`.
+    pub raw: sys::SBTypeFormatRef,
+}
+
+impl SBTypeFormat {
+    /// Construct a new `SBTypeFormat` displaying values in `format`.
+    pub fn new(format: Format, options: TypeOptions) -> SBTypeFormat {
+        SBTypeFormat::wrap(unsafe { sys::CreateSBTypeFormat2(format, options.bits()) })
+    }
+
+    /// Construct a new `SBTypeFormat` displaying values as though they
+    /// were of the type named `type_name`.
+    pub fn with_type_name(type_name: &str, options: TypeOptions) -> SBTypeFormat {
+        let type_name = CString::new(type_name).unwrap();
+        SBTypeFormat::wrap(unsafe { sys::CreateSBTypeFormat3(type_name.as_ptr(), options.bits()) })
+    }
+
+    /// Construct a new `SBTypeFormat`.
+    pub(crate) fn wrap(raw: sys::SBTypeFormatRef) -> SBTypeFormat {
+        SBTypeFormat { raw }
+    }
+
+    /// Construct a new `Some(SBTypeFormat)` or `None`.
+    pub(crate) fn maybe_wrap(raw: sys::SBTypeFormatRef) -> Option<SBTypeFormat> {
+        if unsafe { sys::SBTypeFormatIsValid(raw) } {
+            Some(SBTypeFormat { raw })
+        } else {
+            None
+        }
+    }
+
+    /// Check whether or not this is a valid `SBTypeFormat` value.
+    pub fn is_valid(&self) -> bool {
+        unsafe { sys::SBTypeFormatIsValid(self.raw) }
+    }
+
+    /// The format used to display values.
+    pub fn format(&self) -> Format {
+        unsafe { sys::SBTypeFormatGetFormat(self.raw) }
+    }
+
+    /// Set the format used to display values.
+    pub fn set_format(&self, format: Format) {
+        unsafe { sys::SBTypeFormatSetFormat(self.raw, format) };
+    }
+
+    /// The name of the type that values are displayed as, if any.
+    pub fn type_name(&self) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(sys::SBTypeFormatGetTypeName(self.raw).as_ref()?).to_str() {
+                Ok("") => None,
+                Ok(s) => Some(s),
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// Set the name of the type that values are displayed as.
+    pub fn set_type_name(&self, type_name: &str) {
+        let type_name = CString::new(type_name).unwrap();
+        unsafe { sys::SBTypeFormatSetTypeName(self.raw, type_name.as_ptr()) };
+    }
+
+    /// The options controlling which values this format applies to.
+    pub fn options(&self) -> TypeOptions {
+        TypeOptions::from_bits_truncate(unsafe { sys::SBTypeFormatGetOptions(self.raw) })
+    }
+
+    /// Set the options controlling which values this format applies to.
+    pub fn set_options(&self, options: TypeOptions) {
+        unsafe { sys::SBTypeFormatSetOptions(self.raw, options.bits()) };
+    }
+}
+
+impl Clone for SBTypeFormat {
+    fn clone(&self) -> SBTypeFormat {
+        SBTypeFormat {
+            raw: unsafe { sys::CloneSBTypeFormat(self.raw) },
+        }
+    }
+}
+
+impl fmt::Debug for SBTypeFormat {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let stream = SBStream::new();
+        unsafe { sys::SBTypeFormatGetDescription(self.raw, stream.raw, DescriptionLevel::Brief) };
+        write!(fmt, "SBTypeFormat {{ {} }}", stream.data())
+    }
+}
+
+impl Drop for SBTypeFormat {
+    fn drop(&mut self) {
+        unsafe { sys::DisposeSBTypeFormat(self.raw) };
+    }
+}
+
+unsafe impl Send for SBTypeFormat {}
+unsafe impl Sync for SBTypeFormat {}
@@ -15,19 +15,43 @@ pub struct SBTypeList {
 }
 
 impl SBTypeList {
+    /// Construct a new, empty `SBTypeList`.
+    pub fn new() -> SBTypeList {
+        SBTypeList::wrap(unsafe { sys::CreateSBTypeList() })
+    }
+
     /// Construct a new `SBTypeList`.
     pub(crate) fn wrap(raw: sys::SBTypeListRef) -> SBTypeList {
         SBTypeList { raw }
     }
 
-    #[allow(missing_docs)]
+    /// Check whether or not this is a valid `SBTypeList` value.
+    pub fn is_valid(&self) -> bool {
+        unsafe { sys::SBTypeListIsValid(self.raw) }
+    }
+
+    /// Append a type to this type list.
     pub fn append(&self, t: &SBType) {
         unsafe { sys::SBTypeListAppend(self.raw, t.raw) };
     }
 
+    /// Get the type at `idx`, if `idx` is within bounds.
+    pub fn get(&self, idx: usize) -> Option<SBType> {
+        if idx < self.len() {
+            SBType::maybe_wrap(unsafe { sys::SBTypeListGetTypeAtIndex(self.raw, idx as u32) })
+        } else {
+            None
+        }
+    }
+
+    /// The number of items in this type list.
+    pub fn len(&self) -> usize {
+        unsafe { sys::SBTypeListGetSize(self.raw) as usize }
+    }
+
     /// Is this type list empty?
     pub fn is_empty(&self) -> bool {
-        unsafe { sys::SBTypeListGetSize(self.raw) == 0 }
+        self.len() == 0
     }
 
     /// Iterate over this type list.
@@ -35,6 +59,7 @@ impl SBTypeList {
         SBTypeListIter {
             type_list: self,
             idx: 0,
+            end: self.len(),
         }
     }
 }
@@ -47,6 +72,12 @@ impl Clone for SBTypeList {
     }
 }
 
+impl Default for SBTypeList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Drop for SBTypeList {
     fn drop(&mut self) {
         unsafe { sys::DisposeSBTypeList(self.raw) };
@@ -61,6 +92,22 @@ impl<'d> IntoIterator for &'d SBTypeList {
     }
 }
 
+impl Extend<SBType> for SBTypeList {
+    fn extend<I: IntoIterator<Item = SBType>>(&mut self, iter: I) {
+        for t in iter {
+            self.append(&t);
+        }
+    }
+}
+
+impl FromIterator<SBType> for SBTypeList {
+    fn from_iter<I: IntoIterator<Item = SBType>>(iter: I) -> SBTypeList {
+        let mut type_list = SBTypeList::new();
+        type_list.extend(iter);
+        type_list
+    }
+}
+
 unsafe impl Send for SBTypeList {}
 unsafe impl Sync for SBTypeList {}
 
@@ -70,13 +117,14 @@ unsafe impl Sync for SBTypeList {}
 pub struct SBTypeListIter<'d> {
     type_list: &'d SBTypeList,
     idx: usize,
+    end: usize,
 }
 
 impl Iterator for SBTypeListIter<'_> {
     type Item = SBType;
 
     fn next(&mut self) -> Option<SBType> {
-        if self.idx < unsafe { sys::SBTypeListGetSize(self.type_list.raw) as usize } {
+        if self.idx < self.end {
             let r = SBType::wrap(unsafe {
                 sys::SBTypeListGetTypeAtIndex(self.type_list.raw, self.idx as u32)
             });
@@ -88,9 +136,23 @@ impl Iterator for SBTypeListIter<'_> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let sz = unsafe { sys::SBTypeListGetSize(self.type_list.raw) } as usize;
-        (sz - self.idx, Some(sz))
+        let sz = self.end - self.idx;
+        (sz, Some(sz))
     }
 }
 
 impl ExactSizeIterator for SBTypeListIter<'_> {}
+
+impl DoubleEndedIterator for SBTypeListIter<'_> {
+    fn next_back(&mut self) -> Option<SBType> {
+        if self.idx < self.end {
+            self.end -= 1;
+            let r = SBType::wrap(unsafe {
+                sys::SBTypeListGetTypeAtIndex(self.type_list.raw, self.end as u32)
+            });
+            Some(r)
+        } else {
+            None
+        }
+    }
+}
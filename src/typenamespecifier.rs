@@ -0,0 +1,102 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{sys, DescriptionLevel, SBStream, SBType};
+use std::ffi::{CStr, CString};
+use std::fmt;
+
+/// Specifies which types a data formatter applies to.
+///
+/// This is either a type name, which may be a regular expression,
+/// or a specific [`SBType`].
+///
+/// See also:
+/// - [`SBTypeCategory`](crate::SBTypeCategory)
+pub struct SBTypeNameSpecifier {
+    /// The underlying raw `SBTypeNameSpecifierRef`.
+    pub raw: sys::SBTypeNameSpecifierRef,
+}
+
+impl SBTypeNameSpecifier {
+    /// Construct a new `SBTypeNameSpecifier` matching a type name.
+    ///
+    /// If `is_regex` is `true`, `name` is a regular expression to
+    /// match against type names.
+    pub fn new(name: &str, is_regex: bool) -> SBTypeNameSpecifier {
+        let name = CString::new(name).unwrap();
+        SBTypeNameSpecifier::wrap(unsafe {
+            sys::CreateSBTypeNameSpecifier2(name.as_ptr(), is_regex)
+        })
+    }
+
+    /// Construct a new `SBTypeNameSpecifier` matching a type.
+    pub fn from_type(sbtype: &SBType) -> SBTypeNameSpecifier {
+        SBTypeNameSpecifier::wrap(unsafe { sys::CreateSBTypeNameSpecifier3(sbtype.raw) })
+    }
+
+    /// Construct a new `SBTypeNameSpecifier`.
+    pub(crate) fn wrap(raw: sys::SBTypeNameSpecifierRef) -> SBTypeNameSpecifier {
+        SBTypeNameSpecifier { raw }
+    }
+
+    /// Check whether or not this is a valid `SBTypeNameSpecifier` value.
+    pub fn is_valid(&self) -> bool {
+        unsafe { sys::SBTypeNameSpecifierIsValid(self.raw) }
+    }
+
+    /// The type name or regular expression.
+    pub fn name(&self) -> &str {
+        unsafe {
+            match CStr::from_ptr(sys::SBTypeNameSpecifierGetName(self.raw)).to_str() {
+                Ok(s) => s,
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// The type, if this specifier was created from one.
+    pub fn sbtype(&self) -> Option<SBType> {
+        SBType::maybe_wrap(unsafe { sys::SBTypeNameSpecifierGetType(self.raw) })
+    }
+
+    /// Is the [name](SBTypeNameSpecifier::name()) a regular expression?
+    pub fn is_regex(&self) -> bool {
+        unsafe { sys::SBTypeNameSpecifierIsRegex(self.raw) }
+    }
+}
+
+impl Clone for SBTypeNameSpecifier {
+    fn clone(&self) -> SBTypeNameSpecifier {
+        SBTypeNameSpecifier {
+            raw: unsafe { sys::CloneSBTypeNameSpecifier(self.raw) },
+        }
+    }
+}
+
+impl fmt::Debug for SBTypeNameSpecifier {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let stream = SBStream::new();
+        unsafe {
+            sys::SBTypeNameSpecifierGetDescription(self.raw, stream.raw, DescriptionLevel::Brief)
+        };
+        write!(fmt, "SBTypeNameSpecifier {{ {} }}", stream.data())
+    }
+}
+
+impl Drop for SBTypeNameSpecifier {
+    fn drop(&mut self) {
+        unsafe { sys::DisposeSBTypeNameSpecifier(self.raw) };
+    }
+}
+
+impl PartialEq for SBTypeNameSpecifier {
+    fn eq(&self, other: &SBTypeNameSpecifier) -> bool {
+        unsafe { sys::SBTypeNameSpecifierIsEqualTo(self.raw, other.raw) }
+    }
+}
+
+unsafe impl Send for SBTypeNameSpecifier {}
+unsafe impl Sync for SBTypeNameSpecifier {}
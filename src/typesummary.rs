@@ -0,0 +1,155 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{sys, DescriptionLevel, SBStream, TypeOptions};
+use std::ffi::{CStr, CString};
+use std::fmt;
+
+/// A data formatter which provides a one line summary of values
+/// of a type, as with `type summary add`.
+///
+/// The most common kind of summary is a summary string, which can
+/// refer to members of the value:
+///
+/// ```no_run
+/// use lldb::{SBTypeSummary, TypeOptions};
+///
+/// let summary = SBTypeSummary::with_summary_string("(${var.x}, ${var.y})", TypeOptions::CASCADE);
+/// ```
+///
+/// Summaries can also be implemented by Python functions or code, if
+/// LLDB was built with Python support.
+pub struct SBTypeSummary {
+    /// The underlying raw `SBTypeSummaryRef`.
+    pub raw: sys::SBTypeSummaryRef,
+}
+
+impl SBTypeSummary {
+    /// Construct a new `SBTypeSummary` from a summary string.
+    pub fn with_summary_string(summary: &str, options: TypeOptions) -> SBTypeSummary {
+        let summary = CString::new(summary).unwrap();
+        SBTypeSummary::wrap(unsafe {
+            sys::SBTypeSummaryCreateWithSummaryString(summary.as_ptr(), options.bits())
+        })
+    }
+
+    /// Construct a new `SBTypeSummary` which calls the named
+    /// Python function.
+    pub fn with_function_name(function_name: &str, options: TypeOptions) -> SBTypeSummary {
+        let function_name = CString::new(function_name).unwrap();
+        SBTypeSummary::wrap(unsafe {
+            sys::SBTypeSummaryCreateWithFunctionName(function_name.as_ptr(), options.bits())
+        })
+    }
+
+    /// Construct a new `SBTypeSummary` which runs Python code.
+    pub fn with_script_code(code: &str, options: TypeOptions) -> SBTypeSummary {
+        let code = CString::new(code).unwrap();
+        SBTypeSummary::wrap(unsafe {
+            sys::SBTypeSummaryCreateWithScriptCode(code.as_ptr(), options.bits())
+        })
+    }
+
+    /// Construct a new `SBTypeSummary`.
+    pub(crate) fn wrap(raw: sys::SBTypeSummaryRef) -> SBTypeSummary {
+        SBTypeSummary { raw }
+    }
+
+    /// Construct a new `Some(SBTypeSummary)` or `None`.
+    pub(crate) fn maybe_wrap(raw: sys::SBTypeSummaryRef) -> Option<SBTypeSummary> {
+        if unsafe { sys::SBTypeSummaryIsValid(raw) } {
+            Some(SBTypeSummary { raw })
+        } else {
+            None
+        }
+    }
+
+    /// Check whether or not this is a valid `SBTypeSummary` value.
+    pub fn is_valid(&self) -> bool {
+        unsafe { sys::SBTypeSummaryIsValid(self.raw) }
+    }
+
+    /// Is this summary implemented by Python code?
+    pub fn is_function_code(&self) -> bool {
+        unsafe { sys::SBTypeSummaryIsFunctionCode(self.raw) }
+    }
+
+    /// Is this summary implemented by a named Python function?
+    pub fn is_function_name(&self) -> bool {
+        unsafe { sys::SBTypeSummaryIsFunctionName(self.raw) }
+    }
+
+    /// Is this summary a summary string?
+    pub fn is_summary_string(&self) -> bool {
+        unsafe { sys::SBTypeSummaryIsSummaryString(self.raw) }
+    }
+
+    /// The summary string, function name or code, depending on the
+    /// kind of summary.
+    pub fn data(&self) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(sys::SBTypeSummaryGetData(self.raw).as_ref()?).to_str() {
+                Ok(s) => Some(s),
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// Make this a summary string summary.
+    pub fn set_summary_string(&self, summary: &str) {
+        let summary = CString::new(summary).unwrap();
+        unsafe { sys::SBTypeSummarySetSummaryString(self.raw, summary.as_ptr()) };
+    }
+
+    /// Make this a summary which calls the named Python function.
+    pub fn set_function_name(&self, function_name: &str) {
+        let function_name = CString::new(function_name).unwrap();
+        unsafe { sys::SBTypeSummarySetFunctionName(self.raw, function_name.as_ptr()) };
+    }
+
+    /// Make this a summary which runs Python code.
+    pub fn set_function_code(&self, code: &str) {
+        let code = CString::new(code).unwrap();
+        unsafe { sys::SBTypeSummarySetFunctionCode(self.raw, code.as_ptr()) };
+    }
+
+    /// The options controlling which values this summary applies to
+    /// and how they are displayed.
+    pub fn options(&self) -> TypeOptions {
+        TypeOptions::from_bits_truncate(unsafe { sys::SBTypeSummaryGetOptions(self.raw) })
+    }
+
+    /// Set the options controlling which values this summary applies to
+    /// and how they are displayed.
+    pub fn set_options(&self, options: TypeOptions) {
+        unsafe { sys::SBTypeSummarySetOptions(self.raw, options.bits()) };
+    }
+}
+
+impl Clone for SBTypeSummary {
+    fn clone(&self) -> SBTypeSummary {
+        SBTypeSummary {
+            raw: unsafe { sys::CloneSBTypeSummary(self.raw) },
+        }
+    }
+}
+
+impl fmt::Debug for SBTypeSummary {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let stream = SBStream::new();
+        unsafe { sys::SBTypeSummaryGetDescription(self.raw, stream.raw, DescriptionLevel::Brief) };
+        write!(fmt, "SBTypeSummary {{ {} }}", stream.data())
+    }
+}
+
+impl Drop for SBTypeSummary {
+    fn drop(&mut self) {
+        unsafe { sys::DisposeSBTypeSummary(self.raw) };
+    }
+}
+
+unsafe impl Send for SBTypeSummary {}
+unsafe impl Sync for SBTypeSummary {}
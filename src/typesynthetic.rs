@@ -0,0 +1,126 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{sys, DescriptionLevel, SBStream, TypeOptions};
+use std::ffi::{CStr, CString};
+use std::fmt;
+
+/// A data formatter which provides synthetic children for values of
+/// a type, as with `type synthetic add`.
+///
+/// Synthetic children providers are implemented by Python classes,
+/// so these require LLDB to have been built with Python support.
+pub struct SBTypeSynthetic {
+    /// The underlying raw `SBTypeSyntheticRef`.
+    pub raw: sys::SBTypeSyntheticRef,
+}
+
+impl SBTypeSynthetic {
+    /// Construct a new `SBTypeSynthetic` using the named Python class.
+    pub fn with_class_name(class_name: &str, options: TypeOptions) -> SBTypeSynthetic {
+        let class_name = CString::new(class_name).unwrap();
+        SBTypeSynthetic::wrap(unsafe {
+            sys::SBTypeSyntheticCreateWithClassName(class_name.as_ptr(), options.bits())
+        })
+    }
+
+    /// Construct a new `SBTypeSynthetic` from Python code defining
+    /// the provider class.
+    pub fn with_script_code(code: &str, options: TypeOptions) -> SBTypeSynthetic {
+        let code = CString::new(code).unwrap();
+        SBTypeSynthetic::wrap(unsafe {
+            sys::SBTypeSyntheticCreateWithScriptCode(code.as_ptr(), options.bits())
+        })
+    }
+
+    /// Construct a new `SBTypeSynthetic`.
+    pub(crate) fn wrap(raw: sys::SBTypeSyntheticRef) -> SBTypeSynthetic {
+        SBTypeSynthetic { raw }
+    }
+
+    /// Construct a new `Some(SBTypeSynthetic)` or `None`.
+    pub(crate) fn maybe_wrap(raw: sys::SBTypeSyntheticRef) -> Option<SBTypeSynthetic> {
+        if unsafe { sys::SBTypeSyntheticIsValid(raw) } {
+            Some(SBTypeSynthetic { raw })
+        } else {
+            None
+        }
+    }
+
+    /// Check whether or not this is a valid `SBTypeSynthetic` value.
+    pub fn is_valid(&self) -> bool {
+        unsafe { sys::SBTypeSyntheticIsValid(self.raw) }
+    }
+
+    /// Is this provider defined by Python code?
+    pub fn is_class_code(&self) -> bool {
+        unsafe { sys::SBTypeSyntheticIsClassCode(self.raw) }
+    }
+
+    /// Is this provider a named Python class?
+    pub fn is_class_name(&self) -> bool {
+        unsafe { sys::SBTypeSyntheticIsClassName(self.raw) }
+    }
+
+    /// The class name or code, depending on the kind of provider.
+    pub fn data(&self) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(sys::SBTypeSyntheticGetData(self.raw).as_ref()?).to_str() {
+                Ok(s) => Some(s),
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// Use the named Python class as the provider.
+    pub fn set_class_name(&self, class_name: &str) {
+        let class_name = CString::new(class_name).unwrap();
+        unsafe { sys::SBTypeSyntheticSetClassName(self.raw, class_name.as_ptr()) };
+    }
+
+    /// Use Python code defining the provider class.
+    pub fn set_class_code(&self, code: &str) {
+        let code = CString::new(code).unwrap();
+        unsafe { sys::SBTypeSyntheticSetClassCode(self.raw, code.as_ptr()) };
+    }
+
+    /// The options controlling which values this provider applies to.
+    pub fn options(&self) -> TypeOptions {
+        TypeOptions::from_bits_truncate(unsafe { sys::SBTypeSyntheticGetOptions(self.raw) })
+    }
+
+    /// Set the options controlling which values this provider applies to.
+    pub fn set_options(&self, options: TypeOptions) {
+        unsafe { sys::SBTypeSyntheticSetOptions(self.raw, options.bits()) };
+    }
+}
+
+impl Clone for SBTypeSynthetic {
+    fn clone(&self) -> SBTypeSynthetic {
+        SBTypeSynthetic {
+            raw: unsafe { sys::CloneSBTypeSynthetic(self.raw) },
+        }
+    }
+}
+
+impl fmt::Debug for SBTypeSynthetic {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let stream = SBStream::new();
+        unsafe {
+            sys::SBTypeSyntheticGetDescription(self.raw, stream.raw, DescriptionLevel::Brief)
+        };
+        write!(fmt, "SBTypeSynthetic {{ {} }}", stream.data())
+    }
+}
+
+impl Drop for SBTypeSynthetic {
+    fn drop(&mut self) {
+        unsafe { sys::DisposeSBTypeSynthetic(self.raw) };
+    }
+}
+
+unsafe impl Send for SBTypeSynthetic {}
+unsafe impl Sync for SBTypeSynthetic {}
@@ -0,0 +1,294 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{sys, LLDB_INVALID_SIGNAL_NUMBER};
+use std::ffi::{CStr, CString};
+use std::fmt;
+
+/// A common Unix signal.
+///
+/// Signal numbers differ between platforms: `SIGUSR1` is `10` on Linux
+/// but `30` on macOS. A `Signal` is identified by its name and is only
+/// turned into a number by the [`SBUnixSignals`] of a particular process,
+/// so that code debugging several platforms doesn't need to hardcode
+/// numbers.
+///
+/// ```no_run
+/// # use lldb::{SBProcess, Signal};
+/// # fn interrupt(process: &SBProcess) {
+/// process.send_signal(Signal::User1).unwrap();
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum Signal {
+    Hangup,
+    Interrupt,
+    Quit,
+    IllegalInstruction,
+    Trap,
+    Abort,
+    Bus,
+    FloatingPointException,
+    Kill,
+    User1,
+    SegmentationViolation,
+    User2,
+    Pipe,
+    Alarm,
+    Terminate,
+    Child,
+    Continue,
+    Stop,
+    TerminalStop,
+    TerminalInput,
+    TerminalOutput,
+    Urgent,
+    CpuTimeLimit,
+    FileSizeLimit,
+    VirtualAlarm,
+    ProfilingAlarm,
+    WindowChange,
+    Io,
+    BadSystemCall,
+}
+
+impl Signal {
+    const ALL: [Signal; 29] = [
+        Signal::Hangup,
+        Signal::Interrupt,
+        Signal::Quit,
+        Signal::IllegalInstruction,
+        Signal::Trap,
+        Signal::Abort,
+        Signal::Bus,
+        Signal::FloatingPointException,
+        Signal::Kill,
+        Signal::User1,
+        Signal::SegmentationViolation,
+        Signal::User2,
+        Signal::Pipe,
+        Signal::Alarm,
+        Signal::Terminate,
+        Signal::Child,
+        Signal::Continue,
+        Signal::Stop,
+        Signal::TerminalStop,
+        Signal::TerminalInput,
+        Signal::TerminalOutput,
+        Signal::Urgent,
+        Signal::CpuTimeLimit,
+        Signal::FileSizeLimit,
+        Signal::VirtualAlarm,
+        Signal::ProfilingAlarm,
+        Signal::WindowChange,
+        Signal::Io,
+        Signal::BadSystemCall,
+    ];
+
+    /// The name of the signal, such as `"SIGUSR1"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Signal::Hangup => "SIGHUP",
+            Signal::Interrupt => "SIGINT",
+            Signal::Quit => "SIGQUIT",
+            Signal::IllegalInstruction => "SIGILL",
+            Signal::Trap => "SIGTRAP",
+            Signal::Abort => "SIGABRT",
+            Signal::Bus => "SIGBUS",
+            Signal::FloatingPointException => "SIGFPE",
+            Signal::Kill => "SIGKILL",
+            Signal::User1 => "SIGUSR1",
+            Signal::SegmentationViolation => "SIGSEGV",
+            Signal::User2 => "SIGUSR2",
+            Signal::Pipe => "SIGPIPE",
+            Signal::Alarm => "SIGALRM",
+            Signal::Terminate => "SIGTERM",
+            Signal::Child => "SIGCHLD",
+            Signal::Continue => "SIGCONT",
+            Signal::Stop => "SIGSTOP",
+            Signal::TerminalStop => "SIGTSTP",
+            Signal::TerminalInput => "SIGTTIN",
+            Signal::TerminalOutput => "SIGTTOU",
+            Signal::Urgent => "SIGURG",
+            Signal::CpuTimeLimit => "SIGXCPU",
+            Signal::FileSizeLimit => "SIGXFSZ",
+            Signal::VirtualAlarm => "SIGVTALRM",
+            Signal::ProfilingAlarm => "SIGPROF",
+            Signal::WindowChange => "SIGWINCH",
+            Signal::Io => "SIGIO",
+            Signal::BadSystemCall => "SIGSYS",
+        }
+    }
+
+    /// Look up a signal by its name, such as `"SIGUSR1"`.
+    pub fn from_name(name: &str) -> Option<Signal> {
+        Signal::ALL.into_iter().find(|signal| signal.name() == name)
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The Unix signals of a platform, and how the debugger handles
+/// each of them.
+///
+/// This maps between signal names and the numbers used by the
+/// platform of a process. It is obtained from
+/// [`SBProcess::unix_signals()`].
+///
+/// [`SBProcess::unix_signals()`]: crate::SBProcess::unix_signals()
+pub struct SBUnixSignals {
+    /// The underlying raw `SBUnixSignalsRef`.
+    pub raw: sys::SBUnixSignalsRef,
+}
+
+impl SBUnixSignals {
+    /// Construct a new `SBUnixSignals`.
+    #[allow(dead_code)]
+    pub(crate) fn wrap(raw: sys::SBUnixSignalsRef) -> SBUnixSignals {
+        SBUnixSignals { raw }
+    }
+
+    /// Construct a new `Some(SBUnixSignals)` or `None`.
+    pub(crate) fn maybe_wrap(raw: sys::SBUnixSignalsRef) -> Option<SBUnixSignals> {
+        if unsafe { sys::SBUnixSignalsIsValid(raw) } {
+            Some(SBUnixSignals { raw })
+        } else {
+            None
+        }
+    }
+
+    /// Check whether or not this is a valid `SBUnixSignals` value.
+    pub fn is_valid(&self) -> bool {
+        unsafe { sys::SBUnixSignalsIsValid(self.raw) }
+    }
+
+    /// The name of the signal numbered `signo`, such as `"SIGUSR1"`.
+    pub fn signal_name(&self, signo: i32) -> Option<&str> {
+        unsafe {
+            match CStr::from_ptr(sys::SBUnixSignalsGetSignalAsCString(self.raw, signo).as_ref()?)
+                .to_str()
+            {
+                Ok(s) => Some(s),
+                _ => panic!("Invalid string?"),
+            }
+        }
+    }
+
+    /// The number of the signal named `name`, such as `"SIGUSR1"`.
+    pub fn signal_number_from_name(&self, name: &str) -> Option<i32> {
+        let name = CString::new(name).unwrap();
+        let signo = unsafe { sys::SBUnixSignalsGetSignalNumberFromName(self.raw, name.as_ptr()) };
+        if signo != LLDB_INVALID_SIGNAL_NUMBER {
+            Some(signo)
+        } else {
+            None
+        }
+    }
+
+    /// The number of `signal` on this platform, if it has that signal.
+    pub fn signal_number(&self, signal: Signal) -> Option<i32> {
+        self.signal_number_from_name(signal.name())
+    }
+
+    /// The [`Signal`] numbered `signo`, if it is one of the common ones.
+    pub fn signal(&self, signo: i32) -> Option<Signal> {
+        Signal::from_name(self.signal_name(signo)?)
+    }
+
+    /// The numbers of all of the signals known for this platform.
+    pub fn signal_numbers(&self) -> Vec<i32> {
+        let count = unsafe { sys::SBUnixSignalsGetNumSignals(self.raw) };
+        (0..count)
+            .map(|idx| unsafe { sys::SBUnixSignalsGetSignalAtIndex(self.raw, idx) })
+            .collect()
+    }
+
+    /// Whether the signal is kept from being delivered to the process.
+    pub fn should_suppress(&self, signo: i32) -> bool {
+        unsafe { sys::SBUnixSignalsGetShouldSuppress(self.raw, signo) }
+    }
+
+    /// Set whether the signal is kept from being delivered to the
+    /// process, returning whether the signal was found.
+    pub fn set_should_suppress(&self, signo: i32, value: bool) -> bool {
+        unsafe { sys::SBUnixSignalsSetShouldSuppress(self.raw, signo, value) }
+    }
+
+    /// Whether the process stops when it receives the signal.
+    pub fn should_stop(&self, signo: i32) -> bool {
+        unsafe { sys::SBUnixSignalsGetShouldStop(self.raw, signo) }
+    }
+
+    /// Set whether the process stops when it receives the signal,
+    /// returning whether the signal was found.
+    pub fn set_should_stop(&self, signo: i32, value: bool) -> bool {
+        unsafe { sys::SBUnixSignalsSetShouldStop(self.raw, signo, value) }
+    }
+
+    /// Whether the user is notified when the process receives the signal.
+    pub fn should_notify(&self, signo: i32) -> bool {
+        unsafe { sys::SBUnixSignalsGetShouldNotify(self.raw, signo) }
+    }
+
+    /// Set whether the user is notified when the process receives the
+    /// signal, returning whether the signal was found.
+    pub fn set_should_notify(&self, signo: i32, value: bool) -> bool {
+        unsafe { sys::SBUnixSignalsSetShouldNotify(self.raw, signo, value) }
+    }
+}
+
+impl Clone for SBUnixSignals {
+    fn clone(&self) -> SBUnixSignals {
+        SBUnixSignals {
+            raw: unsafe { sys::CloneSBUnixSignals(self.raw) },
+        }
+    }
+}
+
+impl fmt::Debug for SBUnixSignals {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("SBUnixSignals")
+            .field("num_signals", &self.signal_numbers().len())
+            .finish()
+    }
+}
+
+impl Drop for SBUnixSignals {
+    fn drop(&mut self) {
+        unsafe { sys::DisposeSBUnixSignals(self.raw) };
+    }
+}
+
+unsafe impl Send for SBUnixSignals {}
+unsafe impl Sync for SBUnixSignals {}
+
+#[cfg(test)]
+mod tests {
+    use super::Signal;
+
+    #[test]
+    fn test_signal_names_round_trip() {
+        for signal in Signal::ALL {
+            assert!(signal.name().starts_with("SIG"));
+            assert_eq!(Signal::from_name(signal.name()), Some(signal));
+        }
+    }
+
+    #[test]
+    fn test_signal_from_name() {
+        assert_eq!(Signal::from_name("SIGUSR1"), Some(Signal::User1));
+        assert_eq!(Signal::from_name("SIGSTOP"), Some(Signal::Stop));
+        assert_eq!(Signal::from_name("USR1"), None);
+        assert_eq!(Signal::from_name("sigusr1"), None);
+        assert_eq!(Signal::from_name("SIGRTMIN"), None);
+        assert_eq!(Signal::SegmentationViolation.to_string(), "SIGSEGV");
+    }
+}
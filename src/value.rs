@@ -5,13 +5,53 @@
 // except according to those terms.
 
 use crate::{
-    lldb_addr_t, lldb_user_id_t, sys, Format, SBAddress, SBData, SBError, SBFrame, SBProcess,
-    SBStream, SBTarget, SBThread, SBWatchpoint,
+    lldb_addr_t, lldb_user_id_t, sys, DynamicValueType, Format, SBAddress, SBData, SBError,
+    SBFrame, SBProcess, SBStream, SBTarget, SBThread, SBWatchpoint, ValueType,
+    LLDB_INVALID_ADDRESS,
 };
 use std::ffi::{CStr, CString};
 use std::fmt;
 use std::os::raw::c_char;
 
+/// Where an [`SBValue`] is stored, as returned by
+/// [`SBValue::location_kind()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValueLocation {
+    /// The value is in memory at this address. This is a load
+    /// address, or a file address when the target isn't running,
+    /// such as for a global variable before the process is launched.
+    Memory(lldb_addr_t),
+    /// The value is held in the named register.
+    Register(String),
+    /// The value is a constant and is not stored anywhere in the
+    /// process, such as a value computed by the compiler or the
+    /// result of an expression.
+    Constant,
+    /// The location of the value is not known.
+    Unknown,
+}
+
+impl ValueLocation {
+    /// Classify the location reported by `SBValue::GetLocation` for a
+    /// value without a load address.
+    fn from_location(location: Option<&str>, is_constant: impl FnOnce() -> bool) -> ValueLocation {
+        match location {
+            // A file address, when the target isn't running.
+            Some(location) if location.starts_with("0x") => {
+                match lldb_addr_t::from_str_radix(&location[2..], 16) {
+                    Ok(address) => ValueLocation::Memory(address),
+                    Err(_) => ValueLocation::Unknown,
+                }
+            }
+            // For a variable held in a register, the location is the
+            // register name rather than an address.
+            Some(location) if !location.is_empty() => ValueLocation::Register(location.to_string()),
+            _ if is_constant() => ValueLocation::Constant,
+            _ => ValueLocation::Unknown,
+        }
+    }
+}
+
 /// The value of a variable, register or expression.
 pub struct SBValue {
     /// The underlying raw `SBValueRef`.
@@ -93,6 +133,55 @@ impl SBValue {
         unsafe { self.check_null_ptr(sys::SBValueGetValue(self.raw)) }
     }
 
+    /// What kind of value this is, such as a local variable, a
+    /// register or the result of an expression.
+    pub fn value_type(&self) -> ValueType {
+        unsafe { sys::SBValueGetValueType(self.raw) }
+    }
+
+    /// The value rendered using `format`, such as [`Format::Hex`] or
+    /// [`Format::Binary`].
+    ///
+    /// The format of this value is only changed while it is rendered and
+    /// is restored afterwards, so this can be used to show several
+    /// renderings of the same value side by side.
+    ///
+    /// ```no_run
+    /// # use lldb::{Format, SBValue};
+    /// # fn show(value: &SBValue) {
+    /// let hex = value.value_as_string_with_format(Format::Hex);
+    /// let binary = value.value_as_string_with_format(Format::Binary);
+    /// println!("{:?} {:?} {:?}", value.value(), hex, binary);
+    /// # }
+    /// ```
+    pub fn value_as_string_with_format(&self, format: Format) -> Option<String> {
+        let previous = self.format();
+        self.set_format(format);
+        let value = self.value().map(|s| s.to_string());
+        self.set_format(previous);
+        value
+    }
+
+    /// A one line summary of this value, as shown by `frame variable`.
+    ///
+    /// This applies any [summary formatters](crate::SBTypeSummary) that
+    /// are enabled for the type of this value, as well as LLDB's
+    /// built-in summaries, such as the contents of a `char *` string.
+    /// Values without a summary return `None`.
+    pub fn summary(&self) -> Option<&str> {
+        unsafe { self.check_null_ptr(sys::SBValueGetSummary(self.raw)) }
+    }
+
+    /// A description of this value provided by the language runtime,
+    /// as shown by `po` (`expression --object-description`).
+    ///
+    /// For Objective-C objects, this calls the object's `description`
+    /// method in the inferior process, which requires the process to
+    /// be stopped.
+    pub fn object_description(&self) -> Option<&str> {
+        unsafe { self.check_null_ptr(sys::SBValueGetObjectDescription(self.raw)) }
+    }
+
     #[allow(missing_docs)]
     pub fn set_value_from_cstring(&self, val: &str) -> Result<(), SBError> {
         let error = SBError::default();
@@ -110,9 +199,19 @@ impl SBValue {
         SBValue::maybe_wrap(unsafe { sys::SBValueDereference(self.raw) })
     }
 
-    #[allow(missing_docs)]
+    /// A pointer to this value.
+    ///
+    /// This is `None` unless the value is stored in memory. Values held
+    /// in registers and constant values have no address to point to.
+    ///
+    /// See also:
+    /// - [`SBValue::location_kind()`]
     pub fn address_of(&self) -> Option<SBValue> {
-        SBValue::maybe_wrap(unsafe { sys::SBValueAddressOf(self.raw) })
+        if let ValueLocation::Memory(_) = self.location_kind() {
+            SBValue::maybe_wrap(unsafe { sys::SBValueAddressOf(self.raw) })
+        } else {
+            None
+        }
     }
 
     #[allow(missing_docs)]
@@ -140,6 +239,88 @@ impl SBValue {
         SBFrame::wrap(unsafe { sys::SBValueGetFrame(self.raw) })
     }
 
+    /// The number of child values of this value.
+    ///
+    /// Whether this includes synthetic children depends on whether
+    /// this value [prefers synthetic values].
+    ///
+    /// [prefers synthetic values]: SBValue::prefer_synthetic_value()
+    pub fn num_children(&self) -> u32 {
+        unsafe { sys::SBValueGetNumChildren(self.raw) }
+    }
+
+    /// The child value at `idx`.
+    pub fn child_at_index(&self, idx: u32) -> Option<SBValue> {
+        SBValue::maybe_wrap(unsafe { sys::SBValueGetChildAtIndex(self.raw, idx) })
+    }
+
+    /// The child value at `idx`, using dynamic types as specified by
+    /// `use_dynamic`.
+    ///
+    /// If `synthetic` is `true`, children of pointers and arrays may be
+    /// created beyond the declared bounds, as with `ptr[10]`, so `idx`
+    /// may be larger than [`SBValue::num_children()`].
+    pub fn child_at_index_with_options(
+        &self,
+        idx: u32,
+        use_dynamic: DynamicValueType,
+        synthetic: bool,
+    ) -> Option<SBValue> {
+        SBValue::maybe_wrap(unsafe {
+            sys::SBValueGetChildAtIndex2(self.raw, idx, use_dynamic, synthetic)
+        })
+    }
+
+    /// This value with its dynamic type, such as the most derived class
+    /// of a C++ object accessed through a base class pointer.
+    pub fn dynamic_value(&self, use_dynamic: DynamicValueType) -> Option<SBValue> {
+        SBValue::maybe_wrap(unsafe { sys::SBValueGetDynamicValue(self.raw, use_dynamic) })
+    }
+
+    /// This value with its static type, as declared in the program.
+    pub fn static_value(&self) -> Option<SBValue> {
+        SBValue::maybe_wrap(unsafe { sys::SBValueGetStaticValue(self.raw) })
+    }
+
+    /// This value without any synthetic children provider applied.
+    pub fn non_synthetic_value(&self) -> Option<SBValue> {
+        SBValue::maybe_wrap(unsafe { sys::SBValueGetNonSyntheticValue(self.raw) })
+    }
+
+    /// Does this value use its dynamic type?
+    pub fn is_dynamic(&self) -> bool {
+        unsafe { sys::SBValueIsDynamic(self.raw) }
+    }
+
+    /// Does this value have a synthetic children provider applied?
+    pub fn is_synthetic(&self) -> bool {
+        unsafe { sys::SBValueIsSynthetic(self.raw) }
+    }
+
+    /// Whether values derived from this one, such as its children,
+    /// use dynamic types.
+    pub fn prefer_dynamic_value(&self) -> DynamicValueType {
+        unsafe { sys::SBValueGetPreferDynamicValue(self.raw) }
+    }
+
+    /// Set whether values derived from this one, such as its children,
+    /// use dynamic types.
+    pub fn set_prefer_dynamic_value(&self, use_dynamic: DynamicValueType) {
+        unsafe { sys::SBValueSetPreferDynamicValue(self.raw, use_dynamic) };
+    }
+
+    /// Whether this value presents synthetic children, when a provider
+    /// is available.
+    pub fn prefer_synthetic_value(&self) -> bool {
+        unsafe { sys::SBValueGetPreferSyntheticValue(self.raw) }
+    }
+
+    /// Set whether this value presents synthetic children, when a
+    /// provider is available.
+    pub fn set_prefer_synthetic_value(&self, use_synthetic: bool) {
+        unsafe { sys::SBValueSetPreferSyntheticValue(self.raw, use_synthetic) };
+    }
+
     /// Get an iterator over the [child values] of this value.
     ///
     /// [child values]: SBValue
@@ -224,16 +405,44 @@ impl SBValue {
         }
     }
 
-    #[allow(missing_docs)]
+    /// The address in memory where this value is stored.
+    ///
+    /// This is `None` for values which aren't stored in memory, such as
+    /// those held in registers and constant values.
+    ///
+    /// See also:
+    /// - [`SBValue::location_kind()`]
     pub fn load_address(&self) -> Option<lldb_addr_t> {
-        let load_address = unsafe { sys::SBValueGetLoadAddress(self.raw) };
-        if load_address != u64::MAX {
-            Some(load_address)
+        if let ValueLocation::Memory(addr) = self.location_kind() {
+            Some(addr)
         } else {
             None
         }
     }
 
+    /// Where this value is stored.
+    ///
+    /// Not every value lives in memory: registers, variables which
+    /// the compiler has placed in a register, and the results of
+    /// expressions may not have an address.
+    pub fn location_kind(&self) -> ValueLocation {
+        let value_type = self.value_type();
+        if let ValueType::Register | ValueType::RegisterSet = value_type {
+            return match self.name() {
+                Some(name) => ValueLocation::Register(name.to_string()),
+                None => ValueLocation::Unknown,
+            };
+        }
+        let load_address = unsafe { sys::SBValueGetLoadAddress(self.raw) };
+        if load_address != LLDB_INVALID_ADDRESS {
+            return ValueLocation::Memory(load_address);
+        }
+        let location = unsafe { self.check_null_ptr(sys::SBValueGetLocation(self.raw)) };
+        ValueLocation::from_location(location, || {
+            value_type == ValueType::ConstResult || self.value().is_some()
+        })
+    }
+
     #[allow(missing_docs)]
     pub fn address(&self) -> Option<SBAddress> {
         SBAddress::maybe_wrap(unsafe { sys::SBValueGetAddress(self.raw) })
@@ -271,6 +480,19 @@ impl SBValue {
             Err(error)
         }
     }
+
+    /// Describe this value.
+    ///
+    /// LLDB only provides a single description of a value, so unlike
+    /// the `describe` methods of some other objects, this doesn't take
+    /// a [`DescriptionLevel`](crate::DescriptionLevel).
+    ///
+    /// This is also available via the `Display` implementation.
+    pub fn describe(&self) -> String {
+        let stream = SBStream::new();
+        unsafe { sys::SBValueGetDescription(self.raw, stream.raw) };
+        stream.data().to_string()
+    }
 }
 
 impl Clone for SBValue {
@@ -283,9 +505,13 @@ impl Clone for SBValue {
 
 impl fmt::Debug for SBValue {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let stream = SBStream::new();
-        unsafe { sys::SBValueGetDescription(self.raw, stream.raw) };
-        write!(fmt, "SBValue {{ {} }}", stream.data())
+        write!(fmt, "SBValue {{ {} }}", self.describe())
+    }
+}
+
+impl fmt::Display for SBValue {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}", self.describe())
     }
 }
 
@@ -359,3 +585,32 @@ impl SBValue {
         self.is_in_scope()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ValueLocation;
+
+    #[test]
+    fn test_location_kind() {
+        assert_eq!(
+            ValueLocation::from_location(Some("0x0000000100008000"), || true),
+            ValueLocation::Memory(0x1_0000_8000)
+        );
+        assert_eq!(
+            ValueLocation::from_location(Some("0xzz"), || true),
+            ValueLocation::Unknown
+        );
+        assert_eq!(
+            ValueLocation::from_location(Some("rax"), || true),
+            ValueLocation::Register("rax".to_string())
+        );
+        assert_eq!(
+            ValueLocation::from_location(Some(""), || true),
+            ValueLocation::Constant
+        );
+        assert_eq!(
+            ValueLocation::from_location(None, || false),
+            ValueLocation::Unknown
+        );
+    }
+}
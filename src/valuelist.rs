@@ -6,17 +6,45 @@
 
 use crate::{lldb_user_id_t, sys, SBValue};
 use std::ffi::CString;
+use std::fmt;
+
+/// Options controlling how an [`SBValueList`] is rendered as text.
+///
+/// See [`SBValueList::render_table()`].
+#[derive(Clone, Debug)]
+pub struct FormatOptions {
+    /// Whether or not to include a column with the type of each value.
+    pub show_types: bool,
+    /// Truncate values that are longer than this many characters.
+    pub max_value_width: Option<usize>,
+    /// The text to use for values that could not be read.
+    pub unavailable: String,
+}
+
+impl Default for FormatOptions {
+    fn default() -> FormatOptions {
+        FormatOptions {
+            show_types: true,
+            max_value_width: None,
+            unavailable: "<unavailable>".to_string(),
+        }
+    }
+}
 
 /// A list of [values].
 ///
 /// [values]: SBValue
-#[derive(Debug)]
 pub struct SBValueList {
     /// The underlying raw `SBValueListRef`.
     pub raw: sys::SBValueListRef,
 }
 
 impl SBValueList {
+    /// Construct a new, empty `SBValueList`.
+    pub fn new() -> SBValueList {
+        SBValueList::wrap(unsafe { sys::CreateSBValueList() })
+    }
+
     /// Construct a new `SBValueList`.
     pub(crate) fn wrap(raw: sys::SBValueListRef) -> SBValueList {
         SBValueList { raw }
@@ -47,9 +75,14 @@ impl SBValueList {
         unsafe { sys::SBValueListAppendList(self.raw, values.raw) };
     }
 
+    /// The number of items in this value list.
+    pub fn len(&self) -> usize {
+        unsafe { sys::SBValueListGetSize(self.raw) as usize }
+    }
+
     /// Is this value list empty?
     pub fn is_empty(&self) -> bool {
-        unsafe { sys::SBValueListGetSize(self.raw) == 0 }
+        self.len() == 0
     }
 
     /// Clear this value list.
@@ -73,7 +106,53 @@ impl SBValueList {
         SBValueListIter {
             value_list: self,
             idx: 0,
+            end: self.len(),
+        }
+    }
+
+    /// Render the values in this list as a table with aligned name,
+    /// type and value columns.
+    ///
+    /// ```no_run
+    /// # use lldb::{FormatOptions, SBFrame};
+    /// # fn dump_variables(frame: &SBFrame) {
+    /// println!("{}", frame.all_variables().render_table(&FormatOptions::default()));
+    /// # }
+    /// ```
+    pub fn render_table(&self, options: &FormatOptions) -> String {
+        let rows: Vec<[String; 3]> = self
+            .iter()
+            .map(|v| {
+                let name = v.name().unwrap_or_default().to_string();
+                let type_name = v.display_type_name().unwrap_or_default().to_string();
+                let mut value = match v.value() {
+                    Some(value) => value.to_string(),
+                    None => options.unavailable.clone(),
+                };
+                if let Some(max) = options.max_value_width {
+                    if value.chars().count() > max {
+                        value = value.chars().take(max).collect::<String>() + "...";
+                    }
+                }
+                [name, type_name, value]
+            })
+            .collect();
+        let width = |col: usize| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap_or(0)
+        };
+        let (name_width, type_width) = (width(0), width(1));
+        let mut table = String::new();
+        for [name, type_name, value] in &rows {
+            table += &format!("{name:<name_width$}  ");
+            if options.show_types {
+                table += &format!("{type_name:<type_width$}  ");
+            }
+            table += &format!("{value}\n");
         }
+        table
     }
 }
 
@@ -85,6 +164,25 @@ impl Clone for SBValueList {
     }
 }
 
+impl Default for SBValueList {
+    fn default() -> SBValueList {
+        SBValueList::new()
+    }
+}
+
+impl fmt::Debug for SBValueList {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "SBValueList ")?;
+        fmt.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl fmt::Display for SBValueList {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}", self.render_table(&FormatOptions::default()))
+    }
+}
+
 impl Drop for SBValueList {
     fn drop(&mut self) {
         unsafe { sys::DisposeSBValueList(self.raw) };
@@ -108,13 +206,14 @@ unsafe impl Sync for SBValueList {}
 pub struct SBValueListIter<'d> {
     value_list: &'d SBValueList,
     idx: usize,
+    end: usize,
 }
 
 impl Iterator for SBValueListIter<'_> {
     type Item = SBValue;
 
     fn next(&mut self) -> Option<SBValue> {
-        if self.idx < unsafe { sys::SBValueListGetSize(self.value_list.raw) as usize } {
+        if self.idx < self.end {
             let r = SBValue::wrap(unsafe {
                 sys::SBValueListGetValueAtIndex(self.value_list.raw, self.idx as u32)
             });
@@ -126,9 +225,23 @@ impl Iterator for SBValueListIter<'_> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let sz = unsafe { sys::SBValueListGetSize(self.value_list.raw) } as usize;
-        (sz - self.idx, Some(sz))
+        let sz = self.end - self.idx;
+        (sz, Some(sz))
     }
 }
 
 impl ExactSizeIterator for SBValueListIter<'_> {}
+
+impl DoubleEndedIterator for SBValueListIter<'_> {
+    fn next_back(&mut self) -> Option<SBValue> {
+        if self.idx < self.end {
+            self.end -= 1;
+            let r = SBValue::wrap(unsafe {
+                sys::SBValueListGetValueAtIndex(self.value_list.raw, self.end as u32)
+            });
+            Some(r)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,154 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{SBEvent, SBExpressionOptions, SBFrame, SBProcess, SBValue};
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+/// What a [`ValueTracker`] should evaluate at each stop.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TrackedValue {
+    /// An expression, evaluated in the context of the selected frame.
+    ///
+    /// Evaluating expressions may run code in the target.
+    Expression(String),
+    /// A variable path such as `foo.bar[3]`, looked up without
+    /// running code in the target.
+    VariablePath(String),
+}
+
+impl TrackedValue {
+    fn evaluate(&self, frame: &SBFrame) -> Option<SBValue> {
+        match self {
+            TrackedValue::Expression(expr) => {
+                Some(frame.evaluate_expression(expr, &SBExpressionOptions::new()))
+            }
+            TrackedValue::VariablePath(path) => frame.value_for_variable_path(path),
+        }
+    }
+}
+
+/// A change in a tracked value, reported by a [`ValueTracker`].
+#[derive(Clone, Debug)]
+pub struct ValueChange {
+    /// The value which changed.
+    pub tracked: TrackedValue,
+    /// The stop ID at which the change was noticed.
+    pub stop_id: u32,
+    /// The previously rendered value, if there was one.
+    pub old_value: Option<String>,
+    /// The newly rendered value, if the value could be read.
+    pub new_value: Option<String>,
+}
+
+/// Tracks the values of expressions and variables across stops
+/// of a process.
+///
+/// Each time the process stops, the tracked values are recomputed
+/// and a [`ValueChange`] is sent for each value whose rendering
+/// differs from the previous stop. This is the basis for a "watch"
+/// view that highlights changed values.
+///
+/// ```no_run
+/// # use lldb::{SBEvent, SBListener, TrackedValue, ValueTracker};
+/// # fn watch(listener: &SBListener) {
+/// let (mut tracker, changes) = ValueTracker::new();
+/// tracker.track(TrackedValue::VariablePath("counter".to_string()));
+///
+/// let event = SBEvent::new();
+/// while listener.wait_for_event(1, &event) {
+///     tracker.handle_event(&event);
+///     for change in changes.try_iter() {
+///         println!("{:?} is now {:?}", change.tracked, change.new_value);
+///     }
+/// }
+/// # }
+/// ```
+pub struct ValueTracker {
+    entries: Vec<(TrackedValue, Option<String>)>,
+    last_stop_id: Option<u32>,
+    sender: Sender<ValueChange>,
+}
+
+impl ValueTracker {
+    /// Create a new tracker, along with the receiving end of the channel
+    /// on which changes are reported.
+    pub fn new() -> (ValueTracker, Receiver<ValueChange>) {
+        let (sender, receiver) = channel();
+        let tracker = ValueTracker {
+            entries: Vec::new(),
+            last_stop_id: None,
+            sender,
+        };
+        (tracker, receiver)
+    }
+
+    /// Start tracking a value.
+    ///
+    /// The value will be computed at the next stop. Tracking the same
+    /// value twice has no effect.
+    pub fn track(&mut self, tracked: TrackedValue) {
+        if !self.entries.iter().any(|(t, _)| *t == tracked) {
+            self.entries.push((tracked, None));
+        }
+    }
+
+    /// Stop tracking a value.
+    pub fn untrack(&mut self, tracked: &TrackedValue) {
+        self.entries.retain(|(t, _)| t != tracked);
+    }
+
+    /// The most recently rendered value for each tracked value.
+    pub fn values(&self) -> impl Iterator<Item = (&TrackedValue, Option<&str>)> {
+        self.entries.iter().map(|(t, v)| (t, v.as_deref()))
+    }
+
+    /// Recompute the tracked values if `event` reports that a process
+    /// has stopped.
+    ///
+    /// The values are computed in the selected frame of the selected
+    /// thread. Returns the number of values that changed.
+    pub fn handle_event(&mut self, event: &SBEvent) -> usize {
+        match SBProcess::from_stop_event(event) {
+            Some(process) => {
+                let frame = process.selected_thread().selected_frame();
+                self.update(&process, &frame)
+            }
+            None => 0,
+        }
+    }
+
+    /// Recompute the tracked values in `frame`, unless they have already
+    /// been computed for the current stop of `process`.
+    ///
+    /// Returns the number of values that changed.
+    pub fn update(&mut self, process: &SBProcess, frame: &SBFrame) -> usize {
+        let stop_id = process.stop_id(false);
+        if self.last_stop_id == Some(stop_id) {
+            return 0;
+        }
+        self.last_stop_id = Some(stop_id);
+
+        let mut changed = 0;
+        for (tracked, last) in &mut self.entries {
+            let new_value = tracked
+                .evaluate(frame)
+                .and_then(|v| v.value().map(str::to_string));
+            if new_value != *last {
+                changed += 1;
+                let old_value = std::mem::replace(last, new_value.clone());
+                // The receiver may have gone away, but the tracked values
+                // are still kept current.
+                let _ = self.sender.send(ValueChange {
+                    tracked: tracked.clone(),
+                    stop_id,
+                    old_value,
+                    new_value,
+                });
+            }
+        }
+        changed
+    }
+}
@@ -0,0 +1,300 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{lldb_addr_t, lldb_tid_t, SBFrame, SBValue, SBVariablesOptions};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A variable as last read by a [`VariableCache`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CachedVariable {
+    /// The path of this variable, such as `foo.bar[3]`.
+    ///
+    /// When several variables or children share a name, such as a
+    /// variable shadowed in a nested block, the second is given the
+    /// path `name#1`, the third `name#2` and so on, in the order in
+    /// which LLDB lists them.
+    pub path: String,
+    /// The name of this variable or child.
+    pub name: String,
+    /// The name of the type of this variable.
+    pub type_name: Option<String>,
+    /// The value of this variable, if it has one.
+    pub value: Option<String>,
+    /// The summary of this variable, if it has one.
+    pub summary: Option<String>,
+    /// The number of children that this variable has.
+    pub num_children: u32,
+    /// The generation in which the value or summary of this
+    /// variable last changed.
+    pub changed_generation: u64,
+}
+
+impl CachedVariable {
+    fn read(path: String, value: &SBValue, generation: u64) -> CachedVariable {
+        CachedVariable {
+            path,
+            name: value.name().unwrap_or_default().to_string(),
+            type_name: value.type_name().map(|s| s.to_string()),
+            value: value.value().map(|s| s.to_string()),
+            summary: value.summary().map(|s| s.to_string()),
+            num_children: value.num_children(),
+            changed_generation: generation,
+        }
+    }
+
+    fn same_contents(&self, other: &CachedVariable) -> bool {
+        self.type_name == other.type_name
+            && self.value == other.value
+            && self.summary == other.summary
+            && self.num_children == other.num_children
+    }
+}
+
+/// The changes found by a call to [`VariableCache::update`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VariableDelta {
+    /// The generation of the cache after the update.
+    pub generation: u64,
+    /// Paths of variables whose contents changed.
+    pub changed: Vec<String>,
+    /// Paths of variables which were not present before.
+    pub added: Vec<String>,
+    /// Paths of variables which are no longer present.
+    pub removed: Vec<String>,
+}
+
+impl VariableDelta {
+    /// Whether or not this update found no changes at all.
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty() && self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Caches the variables of a frame so that a watch or locals view
+/// only needs to redraw what changed.
+///
+/// Only the top level variables and the children of paths that have
+/// been [expanded] are read, so large structures which are collapsed
+/// in the UI cost nothing beyond their top level value.
+///
+/// Each call to [`update`] that reads the frame starts a new
+/// generation. Calling `update` again for the same frame at the same
+/// stop does not read anything and returns an empty delta.
+///
+/// ```no_run
+/// # use lldb::{SBFrame, VariableCache, VariablesOptionsBuilder};
+/// # fn refresh(frame: &SBFrame) {
+/// let options = VariablesOptionsBuilder::new()
+///     .locals(true)
+///     .arguments(true)
+///     .build();
+/// let mut cache = VariableCache::new(&options);
+/// cache.expand("point");
+///
+/// let delta = cache.update(frame);
+/// for path in &delta.changed {
+///     if let Some(var) = cache.get(path) {
+///         println!("{} = {:?}", var.path, var.value);
+///     }
+/// }
+/// # }
+/// ```
+///
+/// [expanded]: VariableCache::expand
+/// [`update`]: VariableCache::update
+pub struct VariableCache {
+    options: SBVariablesOptions,
+    expanded: BTreeSet<String>,
+    values: BTreeMap<String, CachedVariable>,
+    generation: u64,
+    last_frame: Option<(lldb_tid_t, Option<lldb_addr_t>)>,
+    last_stop_id: Option<u32>,
+}
+
+impl VariableCache {
+    /// Create an empty cache which reads variables using `options`.
+    pub fn new(options: &SBVariablesOptions) -> VariableCache {
+        VariableCache {
+            options: options.clone(),
+            expanded: BTreeSet::new(),
+            values: BTreeMap::new(),
+            generation: 0,
+            last_frame: None,
+            last_stop_id: None,
+        }
+    }
+
+    /// Read the children of `path` on subsequent updates.
+    pub fn expand(&mut self, path: &str) {
+        if self.expanded.insert(path.to_string()) {
+            // Force the next update to read the new children.
+            self.last_stop_id = None;
+        }
+    }
+
+    /// Stop reading the children of `path` and of anything below it.
+    pub fn collapse(&mut self, path: &str) {
+        let before = self.expanded.len();
+        self.expanded
+            .retain(|p| p != path && !Self::is_descendant(p, path));
+        if self.expanded.len() != before {
+            // Force the next update to drop the children.
+            self.last_stop_id = None;
+        }
+    }
+
+    /// Whether or not the children of `path` are being read.
+    pub fn is_expanded(&self, path: &str) -> bool {
+        self.expanded.contains(path)
+    }
+
+    /// The current generation of the cache.
+    ///
+    /// This starts at `0` and increases each time the frame is read.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Look up a cached variable by its path.
+    pub fn get(&self, path: &str) -> Option<&CachedVariable> {
+        self.values.get(path)
+    }
+
+    /// Iterate over all cached variables in path order.
+    pub fn iter(&self) -> impl Iterator<Item = &CachedVariable> {
+        self.values.values()
+    }
+
+    /// Iterate over the variables which changed after `generation`.
+    pub fn changed_since(&self, generation: u64) -> impl Iterator<Item = &CachedVariable> {
+        self.values
+            .values()
+            .filter(move |v| v.changed_generation > generation)
+    }
+
+    /// Drop all cached values so that the next update reports
+    /// everything as added.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.last_stop_id = None;
+    }
+
+    /// Re-read the variables of `frame` and report what changed.
+    ///
+    /// If `frame` is a different frame from the one previously read,
+    /// the cache is cleared first.
+    pub fn update(&mut self, frame: &SBFrame) -> VariableDelta {
+        let thread = frame.thread();
+        let stop_id = thread.process().stop_id(false);
+        let identity = (thread.thread_id(), frame.cfa());
+        if self.last_frame != Some(identity) {
+            self.values.clear();
+        } else if self.last_stop_id == Some(stop_id) {
+            return VariableDelta {
+                generation: self.generation,
+                ..Default::default()
+            };
+        }
+        self.last_frame = Some(identity);
+        self.last_stop_id = Some(stop_id);
+        self.generation += 1;
+
+        let mut current = BTreeMap::new();
+        let mut seen = BTreeMap::new();
+        for value in frame.variables(&self.options).iter() {
+            let path = Self::unique_name(value.name().unwrap_or_default(), &mut seen);
+            self.read_value(path, &value, &mut current);
+        }
+
+        let mut delta = VariableDelta {
+            generation: self.generation,
+            ..Default::default()
+        };
+        for (path, var) in current.iter_mut() {
+            match self.values.get(path) {
+                Some(old) if old.same_contents(var) => {
+                    var.changed_generation = old.changed_generation;
+                }
+                Some(_) => delta.changed.push(path.clone()),
+                None => delta.added.push(path.clone()),
+            }
+        }
+        delta.removed = self
+            .values
+            .keys()
+            .filter(|path| !current.contains_key(*path))
+            .cloned()
+            .collect();
+        self.values = current;
+        delta
+    }
+
+    fn read_value(
+        &self,
+        path: String,
+        value: &SBValue,
+        current: &mut BTreeMap<String, CachedVariable>,
+    ) {
+        let var = CachedVariable::read(path.clone(), value, self.generation);
+        if self.expanded.contains(&path) {
+            let mut seen = BTreeMap::new();
+            for child in value.children() {
+                let name = Self::unique_name(child.name().unwrap_or_default(), &mut seen);
+                let child_path = if name.starts_with('[') {
+                    format!("{path}{name}")
+                } else {
+                    format!("{path}.{name}")
+                };
+                self.read_value(child_path, &child, current);
+            }
+        }
+        current.insert(path, var);
+    }
+
+    /// `name`, with a `#n` suffix if it is the `n`th repeat of `name`
+    /// among its siblings, as counted in `seen`.
+    fn unique_name(name: &str, seen: &mut BTreeMap<String, usize>) -> String {
+        let count = seen.entry(name.to_string()).or_insert(0);
+        let unique = if *count == 0 {
+            name.to_string()
+        } else {
+            format!("{name}#{count}")
+        };
+        *count += 1;
+        unique
+    }
+
+    fn is_descendant(path: &str, ancestor: &str) -> bool {
+        path.strip_prefix(ancestor)
+            .is_some_and(|rest| rest.starts_with('.') || rest.starts_with('['))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VariableCache;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_unique_name() {
+        let mut seen = BTreeMap::new();
+        assert_eq!(VariableCache::unique_name("x", &mut seen), "x");
+        assert_eq!(VariableCache::unique_name("y", &mut seen), "y");
+        assert_eq!(VariableCache::unique_name("x", &mut seen), "x#1");
+        assert_eq!(VariableCache::unique_name("x", &mut seen), "x#2");
+        assert!(!VariableCache::is_descendant("x#1", "x"));
+    }
+
+    #[test]
+    fn test_is_descendant() {
+        assert!(VariableCache::is_descendant("point.x", "point"));
+        assert!(VariableCache::is_descendant("points[0]", "points"));
+        assert!(VariableCache::is_descendant("points[0].x", "points"));
+        assert!(!VariableCache::is_descendant("point", "point"));
+        assert!(!VariableCache::is_descendant("pointer", "point"));
+        assert!(!VariableCache::is_descendant("x", "point"));
+    }
+}
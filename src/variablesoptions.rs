@@ -4,9 +4,14 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::{sys, DynamicValueType};
+use crate::{sys, DynamicValueType, SBTarget};
 
-#[allow(missing_docs)]
+/// Which variables of a frame to get with [`SBFrame::variables()`].
+///
+/// [`VariablesOptionsBuilder`] is a more convenient way to construct
+/// these.
+///
+/// [`SBFrame::variables()`]: crate::SBFrame::variables()
 #[derive(Debug)]
 pub struct SBVariablesOptions {
     /// The underlying raw `SBVariablesOptionsRef`.
@@ -39,62 +44,88 @@ impl SBVariablesOptions {
         unsafe { sys::SBVariablesOptionsIsValid(self.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// Are the arguments of the function included?
     pub fn include_arguments(&self) -> bool {
         unsafe { sys::SBVariablesOptionsGetIncludeArguments(self.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// Set whether the arguments of the function are included.
     pub fn set_include_arguments(&self, arguments: bool) {
         unsafe { sys::SBVariablesOptionsSetIncludeArguments(self.raw, arguments) };
     }
 
-    #[allow(missing_docs)]
+    /// Are arguments recognized by a frame recognizer included?
+    ///
+    /// Frame recognizers can provide arguments for functions without
+    /// debug information, such as well known system functions. Unless
+    /// [set], this follows the `target.display-recognized-arguments`
+    /// setting of `target`.
+    ///
+    /// [set]: SBVariablesOptions::set_include_recognized_arguments()
+    pub fn include_recognized_arguments(&self, target: &SBTarget) -> bool {
+        unsafe { sys::SBVariablesOptionsGetIncludeRecognizedArguments(self.raw, target.raw) }
+    }
+
+    /// Set whether arguments recognized by a frame recognizer are
+    /// included.
+    pub fn set_include_recognized_arguments(&self, include: bool) {
+        unsafe { sys::SBVariablesOptionsSetIncludeRecognizedArguments(self.raw, include) };
+    }
+
+    /// Are local variables included?
     pub fn include_locals(&self) -> bool {
         unsafe { sys::SBVariablesOptionsGetIncludeLocals(self.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// Set whether local variables are included.
     pub fn set_include_locals(&self, locals: bool) {
         unsafe { sys::SBVariablesOptionsSetIncludeLocals(self.raw, locals) };
     }
 
-    #[allow(missing_docs)]
+    /// Are static variables, including globals, included?
     pub fn include_statics(&self) -> bool {
         unsafe { sys::SBVariablesOptionsGetIncludeStatics(self.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// Set whether static variables, including globals, are included.
     pub fn set_include_statics(&self, statics: bool) {
         unsafe { sys::SBVariablesOptionsSetIncludeStatics(self.raw, statics) };
     }
 
-    #[allow(missing_docs)]
+    /// Are only the variables which are in scope at the frame's
+    /// current location included?
     pub fn in_scope_only(&self) -> bool {
         unsafe { sys::SBVariablesOptionsGetInScopeOnly(self.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// Set whether only the variables which are in scope at the frame's
+    /// current location are included.
     pub fn set_in_scope_only(&self, in_scope_only: bool) {
         unsafe { sys::SBVariablesOptionsSetInScopeOnly(self.raw, in_scope_only) };
     }
 
-    #[allow(missing_docs)]
+    /// Are values which only exist to support the language runtime
+    /// included?
     pub fn include_runtime_support_values(&self) -> bool {
         unsafe { sys::SBVariablesOptionsGetIncludeRuntimeSupportValues(self.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// Set whether values which only exist to support the language
+    /// runtime, such as the hidden variables of Swift or Objective-C,
+    /// are included.
+    ///
+    /// These are usually clutter in a variables view.
     pub fn set_include_runtime_support_values(&self, include: bool) {
         unsafe { sys::SBVariablesOptionsSetIncludeRuntimeSupportValues(self.raw, include) };
     }
 
-    #[allow(missing_docs)]
+    /// Whether the dynamic types of values are used.
     pub fn use_dynamic(&self) -> DynamicValueType {
         unsafe { sys::SBVariablesOptionsGetUseDynamic(self.raw) }
     }
 
-    #[allow(missing_docs)]
+    /// Set whether the dynamic types of values are used, such as the
+    /// concrete class behind a pointer to a base class.
     pub fn set_use_dynamic(&self, use_dynamic: DynamicValueType) {
         unsafe { sys::SBVariablesOptionsSetUseDynamic(self.raw, use_dynamic) };
     }
@@ -122,3 +153,121 @@ impl Drop for SBVariablesOptions {
 
 unsafe impl Send for SBVariablesOptions {}
 unsafe impl Sync for SBVariablesOptions {}
+
+/// A builder for [`SBVariablesOptions`].
+///
+/// The defaults are the same as those of [`SBVariablesOptions::new()`],
+/// which include no variables at all, so at least one of
+/// [`arguments()`], [`locals()`] or [`statics()`] should be enabled.
+///
+/// [`arguments()`]: VariablesOptionsBuilder::arguments()
+/// [`locals()`]: VariablesOptionsBuilder::locals()
+/// [`statics()`]: VariablesOptionsBuilder::statics()
+///
+/// ```no_run
+/// use lldb::{DynamicValueType, SBFrame, VariablesOptionsBuilder};
+///
+/// fn show_locals(frame: &SBFrame) {
+///     let options = VariablesOptionsBuilder::new()
+///         .locals(true)
+///         .in_scope_only(true)
+///         .use_dynamic(DynamicValueType::DynamicDontRunTarget)
+///         .build();
+///     for value in frame.variables(&options).iter() {
+///         println!("{:?}", value);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct VariablesOptionsBuilder {
+    arguments: bool,
+    recognized_arguments: Option<bool>,
+    locals: bool,
+    statics: bool,
+    in_scope_only: bool,
+    runtime_support_values: bool,
+    use_dynamic: DynamicValueType,
+}
+
+impl VariablesOptionsBuilder {
+    /// Construct a new `VariablesOptionsBuilder` with the default
+    /// options.
+    pub fn new() -> VariablesOptionsBuilder {
+        VariablesOptionsBuilder {
+            arguments: false,
+            recognized_arguments: None,
+            locals: false,
+            statics: false,
+            in_scope_only: false,
+            runtime_support_values: false,
+            use_dynamic: DynamicValueType::NoDynamicValues,
+        }
+    }
+
+    /// Include the arguments of the function.
+    pub fn arguments(mut self, include: bool) -> Self {
+        self.arguments = include;
+        self
+    }
+
+    /// Include the arguments recognized by a frame recognizer, rather
+    /// than following the target's setting.
+    ///
+    /// See [`SBVariablesOptions::include_recognized_arguments()`].
+    pub fn recognized_arguments(mut self, include: bool) -> Self {
+        self.recognized_arguments = Some(include);
+        self
+    }
+
+    /// Include local variables.
+    pub fn locals(mut self, include: bool) -> Self {
+        self.locals = include;
+        self
+    }
+
+    /// Include static variables, including globals.
+    pub fn statics(mut self, include: bool) -> Self {
+        self.statics = include;
+        self
+    }
+
+    /// Only include the variables which are in scope at the frame's
+    /// current location.
+    pub fn in_scope_only(mut self, in_scope_only: bool) -> Self {
+        self.in_scope_only = in_scope_only;
+        self
+    }
+
+    /// Include values which only exist to support the language runtime.
+    pub fn runtime_support_values(mut self, include: bool) -> Self {
+        self.runtime_support_values = include;
+        self
+    }
+
+    /// Whether to use the dynamic types of values.
+    pub fn use_dynamic(mut self, use_dynamic: DynamicValueType) -> Self {
+        self.use_dynamic = use_dynamic;
+        self
+    }
+
+    /// Construct the `SBVariablesOptions`.
+    pub fn build(&self) -> SBVariablesOptions {
+        let options = SBVariablesOptions::new();
+        options.set_include_arguments(self.arguments);
+        if let Some(include) = self.recognized_arguments {
+            options.set_include_recognized_arguments(include);
+        }
+        options.set_include_locals(self.locals);
+        options.set_include_statics(self.statics);
+        options.set_in_scope_only(self.in_scope_only);
+        options.set_include_runtime_support_values(self.runtime_support_values);
+        options.set_use_dynamic(self.use_dynamic);
+        options
+    }
+}
+
+impl Default for VariablesOptionsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -4,7 +4,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::{lldb_addr_t, sys, DescriptionLevel, SBError, SBStream};
+use crate::{lldb_addr_t, sys, DescriptionLevel, SBError, SBEvent, SBStream, WatchpointEventType};
 use std::fmt;
 
 /// An instance of a watch point for a specific target program.
@@ -106,6 +106,37 @@ impl SBWatchpoint {
     pub fn set_ignore_count(&self, count: u32) {
         unsafe { sys::SBWatchpointSetIgnoreCount(self.raw, count) }
     }
+
+    /// If the given event is a watchpoint event, return it as an
+    /// `SBWatchpointEvent`. Otherwise, return `None`.
+    ///
+    /// Watchpoint events are broadcast by the [target] with
+    /// [`SBTargetEvent::BROADCAST_BIT_WATCHPOINT_CHANGED`] when
+    /// watchpoints are added, removed or modified.
+    ///
+    /// [target]: crate::SBTarget::broadcaster()
+    /// [`SBTargetEvent::BROADCAST_BIT_WATCHPOINT_CHANGED`]: crate::SBTargetEvent::BROADCAST_BIT_WATCHPOINT_CHANGED
+    pub fn event_as_watchpoint_event(event: &SBEvent) -> Option<SBWatchpointEvent<'_>> {
+        if unsafe { sys::SBWatchpointEventIsWatchpointEvent(event.raw) } {
+            Some(SBWatchpointEvent::new(event))
+        } else {
+            None
+        }
+    }
+
+    /// Describe this watchpoint at the given level of detail.
+    ///
+    /// At [`DescriptionLevel::Verbose`], this includes the watched
+    /// expression or variable and any condition.
+    ///
+    /// This is also available via the `Display` implementation, which
+    /// uses [`DescriptionLevel::Brief`], or [`DescriptionLevel::Verbose`]
+    /// when formatted with `{:#}`.
+    pub fn describe(&self, level: DescriptionLevel) -> String {
+        let stream = SBStream::new();
+        unsafe { sys::SBWatchpointGetDescription(self.raw, stream.raw, level) };
+        stream.data().to_string()
+    }
 }
 
 impl Clone for SBWatchpoint {
@@ -118,9 +149,22 @@ impl Clone for SBWatchpoint {
 
 impl fmt::Debug for SBWatchpoint {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let stream = SBStream::new();
-        unsafe { sys::SBWatchpointGetDescription(self.raw, stream.raw, DescriptionLevel::Brief) };
-        write!(fmt, "SBWatchpoint {{ {} }}", stream.data())
+        write!(
+            fmt,
+            "SBWatchpoint {{ {} }}",
+            self.describe(DescriptionLevel::Brief)
+        )
+    }
+}
+
+impl fmt::Display for SBWatchpoint {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let level = if fmt.alternate() {
+            DescriptionLevel::Verbose
+        } else {
+            DescriptionLevel::Brief
+        };
+        write!(fmt, "{}", self.describe(level))
     }
 }
 
@@ -133,6 +177,28 @@ impl Drop for SBWatchpoint {
 unsafe impl Send for SBWatchpoint {}
 unsafe impl Sync for SBWatchpoint {}
 
+/// A watchpoint event.
+pub struct SBWatchpointEvent<'e> {
+    event: &'e SBEvent,
+}
+
+impl<'e> SBWatchpointEvent<'e> {
+    /// Construct a new `SBWatchpointEvent`.
+    pub fn new(event: &'e SBEvent) -> Self {
+        SBWatchpointEvent { event }
+    }
+
+    /// What happened to the watchpoint.
+    pub fn event_type(&self) -> WatchpointEventType {
+        unsafe { sys::SBWatchpointGetWatchpointEventTypeFromEvent(self.event.raw) }
+    }
+
+    /// The watchpoint that this event is about.
+    pub fn watchpoint(&self) -> Option<SBWatchpoint> {
+        SBWatchpoint::maybe_wrap(unsafe { sys::SBWatchpointGetWatchpointFromEvent(self.event.raw) })
+    }
+}
+
 #[cfg(feature = "graphql")]
 #[juniper::graphql_object]
 impl SBWatchpoint {